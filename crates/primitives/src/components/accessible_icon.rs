@@ -0,0 +1,24 @@
+use leptos::*;
+
+use crate::{primitive::Primitive, visually_hidden::VisuallyHidden};
+
+#[component]
+pub fn AccessibleIcon(
+  #[prop(into)] label: MaybeSignal<String>,
+  children: ChildrenFn,
+) -> impl IntoView {
+  let label = Signal::derive(move || label.get());
+
+  // the icon itself is hidden from assistive technology, which reads the label instead
+  view! {
+    <Primitive
+      attr:aria-hidden="true"
+      attr:focusable="false"
+      element=html::span
+      as_child=true
+    >
+      {children()}
+    </Primitive>
+    <VisuallyHidden>{move || label.get()}</VisuallyHidden>
+  }
+}
@@ -8,6 +8,7 @@ use wasm_bindgen::JsCast;
 use crate::{
   collapsible::{CollapsibleContent, CollapsibleRoot, CollapsibleTrigger},
   collection::{use_collection_context, use_collection_item_ref},
+  direction::use_local_direction,
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
@@ -65,7 +66,7 @@ pub fn AccordionRoot(
   kind: AccordionKind,
 
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
   #[prop(default=Orientation::Vertical.into(), into)] orientation: MaybeSignal<Orientation>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
@@ -74,6 +75,8 @@ pub fn AccordionRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   provide_context(
     CollectionContextValue::<AccordionCollectionItem, AnyElement> {
       collection_ref: node_ref,
@@ -0,0 +1,244 @@
+use leptos::{html::AnyElement, *};
+use web_sys::{CustomEvent, Event, KeyboardEvent, MouseEvent};
+
+use crate::{
+  dialog::{
+    use_dialog_is_present, DialogClose, DialogContentImpl, DialogDescription, DialogOverlay,
+    DialogPortal, DialogRoot, DialogTitle, DialogTrigger,
+  },
+  presence::create_presence,
+  util::Attributes,
+};
+
+#[derive(Clone)]
+struct AlertDialogContentContextValue {
+  cancel_ref: NodeRef<AnyElement>,
+}
+
+#[component]
+pub fn AlertDialogRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <DialogRoot
+      open=open
+      default_open=default_open
+      modal=true
+      on_open_change=on_open_change
+    >
+      {children()}
+    </DialogRoot>
+  }
+}
+
+#[component]
+pub fn AlertDialogTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogTrigger
+      on_click=on_click
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogTrigger>
+  }
+}
+
+#[component]
+pub fn AlertDialogPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <DialogPortal container=container force_mount=force_mount>
+      {children()}
+    </DialogPortal>
+  }
+}
+
+#[component]
+pub fn AlertDialogOverlay(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let children = StoredValue::new(children);
+
+  view! {
+    <DialogOverlay
+      force_mount=force_mount
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </DialogOverlay>
+  }
+}
+
+#[component]
+pub fn AlertDialogContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let cancel_ref = NodeRef::<AnyElement>::new();
+
+  provide_context(AlertDialogContentContextValue { cancel_ref });
+
+  let is_present = use_dialog_is_present(force_mount);
+  let presence = create_presence(is_present, node_ref);
+
+  let children = StoredValue::new(children);
+
+  // alert dialogs force a choice, so interacting outside of them never dismisses them
+  view! {
+    <Show when=move || presence.get()>
+      <DialogContentImpl
+        role="alertdialog"
+        on_open_auto_focus=move |ev: Event| {
+          on_open_auto_focus.call(ev.clone());
+
+          if !ev.default_prevented() {
+            ev.prevent_default();
+
+            if let Some(cancel) = cancel_ref.get_untracked() {
+              _ = cancel.focus();
+            }
+          }
+        }
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=|ev: CustomEvent| ev.prevent_default()
+        on_focus_outside=|_| {}
+        on_interact_outside=|ev: CustomEvent| ev.prevent_default()
+        node_ref=node_ref
+        attrs=attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </DialogContentImpl>
+    </Show>
+  }
+}
+
+#[component]
+pub fn AlertDialogTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogTitle
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogTitle>
+  }
+}
+
+#[component]
+pub fn AlertDialogDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogDescription
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogDescription>
+  }
+}
+
+#[component]
+pub fn AlertDialogAction(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogClose
+      on_click=on_click
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogClose>
+  }
+}
+
+#[component]
+pub fn AlertDialogCancel(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let AlertDialogContentContextValue { cancel_ref } =
+    use_context().expect("AlertDialogCancel must be used in an AlertDialogContent component");
+
+  // the content focuses the cancel button as soon as it mounts, so the cancel button has to own the
+  // context's node ref rather than loading it from an effect
+  Effect::new(move |_| {
+    if let Some(node) = cancel_ref.get() {
+      node_ref.load(&node);
+    }
+  });
+
+  view! {
+    <DialogClose
+      on_click=on_click
+      node_ref=cancel_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogClose>
+  }
+}
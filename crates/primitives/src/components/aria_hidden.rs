@@ -0,0 +1,147 @@
+use std::cell::RefCell;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+
+struct HiddenElement {
+  element: web_sys::Element,
+  attribute: &'static str,
+  count: usize,
+  was_already_set: bool,
+}
+
+thread_local! {
+  static HIDDEN_ELEMENTS: RefCell<Vec<HiddenElement>> = const { RefCell::new(vec![]) };
+}
+
+pub fn hide_others(target: &web_sys::Element) -> impl FnOnce() {
+  apply_to_others(target, "aria-hidden")
+}
+
+pub fn inert_others(target: &web_sys::Element) -> impl FnOnce() {
+  apply_to_others(target, "inert")
+}
+
+pub(crate) fn use_hide_others(node_ref: NodeRef<AnyElement>, enabled: Signal<bool>) {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    if enabled.get() {
+      on_cleanup(hide_others(&node));
+    }
+  });
+}
+
+// everything outside of the target's subtree is marked. elements are reference counted so nested
+// modals only restore them once all of them have been closed
+fn apply_to_others(target: &web_sys::Element, attribute: &'static str) -> impl FnOnce() {
+  let mut marked = vec![];
+
+  if let Some(body) = document().body() {
+    let mut targets = vec![target.clone()];
+
+    // live regions stay reachable so announcements, e.g. from toasts, aren't silenced
+    if let Ok(live_regions) = body.query_selector_all("[aria-live]") {
+      targets.extend((0..live_regions.length()).filter_map(|index| {
+        live_regions
+          .item(index)?
+          .dyn_into::<web_sys::Element>()
+          .ok()
+      }));
+    }
+
+    let mut ancestors = vec![];
+
+    for target in &targets {
+      let mut current = target.parent_element();
+
+      while let Some(element) = current {
+        current = element.parent_element();
+        ancestors.push(element);
+      }
+    }
+
+    mark_others(&body, &targets, &ancestors, attribute, &mut marked);
+  }
+
+  move || {
+    for element in marked {
+      unmark(&element, attribute);
+    }
+  }
+}
+
+fn mark_others(
+  parent: &web_sys::Element,
+  targets: &[web_sys::Element],
+  ancestors: &[web_sys::Element],
+  attribute: &'static str,
+  marked: &mut Vec<web_sys::Element>,
+) {
+  let children = parent.children();
+
+  for index in 0..children.length() {
+    let Some(child) = children.item(index) else {
+      continue;
+    };
+
+    if targets.contains(&child) {
+      continue;
+    }
+
+    if ancestors.contains(&child) {
+      mark_others(&child, targets, ancestors, attribute, marked);
+    } else if !matches!(child.tag_name().as_str(), "SCRIPT" | "STYLE" | "TEMPLATE") {
+      mark(&child, attribute);
+      marked.push(child);
+    }
+  }
+}
+
+fn mark(element: &web_sys::Element, attribute: &'static str) {
+  HIDDEN_ELEMENTS.with_borrow_mut(|hidden_elements| {
+    if let Some(hidden_element) = hidden_elements.iter_mut().find(|hidden_element| {
+      hidden_element.element == *element && hidden_element.attribute == attribute
+    }) {
+      hidden_element.count += 1;
+      return;
+    }
+
+    let was_already_set = element.has_attribute(attribute);
+
+    if !was_already_set {
+      _ = element.set_attribute(attribute, if attribute == "inert" { "" } else { "true" });
+    }
+
+    hidden_elements.push(HiddenElement {
+      element: element.clone(),
+      attribute,
+      count: 1,
+      was_already_set,
+    });
+  });
+}
+
+fn unmark(element: &web_sys::Element, attribute: &'static str) {
+  HIDDEN_ELEMENTS.with_borrow_mut(|hidden_elements| {
+    let Some(index) = hidden_elements.iter().position(|hidden_element| {
+      hidden_element.element == *element && hidden_element.attribute == attribute
+    }) else {
+      return;
+    };
+
+    hidden_elements[index].count -= 1;
+
+    if hidden_elements[index].count > 0 {
+      return;
+    }
+
+    let hidden_element = hidden_elements.remove(index);
+
+    if !hidden_element.was_already_set {
+      _ = element.remove_attribute(attribute);
+    }
+  });
+}
@@ -0,0 +1,1252 @@
+use std::{
+  fmt,
+  rc::Rc,
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsValue;
+use web_sys::{
+  js_sys::{Array, Date, Intl::DateTimeFormat, Object, Reflect},
+  KeyboardEvent, MouseEvent, PointerEvent,
+};
+
+use crate::{
+  direction::use_local_direction,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  Direction,
+};
+
+const MONTH_NAMES: [&str; 12] = [
+  "January",
+  "February",
+  "March",
+  "April",
+  "May",
+  "June",
+  "July",
+  "August",
+  "September",
+  "October",
+  "November",
+  "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+  "Sunday",
+  "Monday",
+  "Tuesday",
+  "Wednesday",
+  "Thursday",
+  "Friday",
+  "Saturday",
+];
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum Weekday {
+  #[default]
+  Sunday,
+  Monday,
+  Tuesday,
+  Wednesday,
+  Thursday,
+  Friday,
+  Saturday,
+}
+
+impl Weekday {
+  fn from_index(index: i64) -> Self {
+    match index.rem_euclid(7) {
+      0 => Self::Sunday,
+      1 => Self::Monday,
+      2 => Self::Tuesday,
+      3 => Self::Wednesday,
+      4 => Self::Thursday,
+      5 => Self::Friday,
+      _ => Self::Saturday,
+    }
+  }
+
+  fn index(self) -> i64 {
+    self as i64
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct CalendarDate {
+  pub year: i32,
+  pub month: u32,
+  pub day: u32,
+}
+
+impl CalendarDate {
+  pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+    ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day)).then_some(Self {
+      year,
+      month,
+      day,
+    })
+  }
+
+  pub fn today() -> Self {
+    // the browser knows the user's local date, servers fall back to the date in UTC
+    if cfg!(target_arch = "wasm32") {
+      let now = Date::new_0();
+
+      Self {
+        year: now.get_full_year() as i32,
+        month: now.get_month() + 1,
+        day: now.get_date(),
+      }
+    } else {
+      let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() / 86_400)
+        .unwrap_or_default();
+
+      Self::from_days(days as i64)
+    }
+  }
+
+  pub fn from_iso(value: &str) -> Option<Self> {
+    let mut parts = value.trim().splitn(3, '-');
+
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    Self::new(year, month, day)
+  }
+
+  // days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+  fn to_days(self) -> i64 {
+    let month = self.month as i64;
+    let year = self.year as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year =
+      (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+  }
+
+  fn from_days(days: i64) -> Self {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+      (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+      shifted_month + 3
+    } else {
+      shifted_month - 9
+    };
+
+    Self {
+      year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+      month: month as u32,
+      day: day as u32,
+    }
+  }
+
+  pub fn weekday(self) -> Weekday {
+    // 1970-01-01 was a thursday
+    Weekday::from_index(self.to_days() + 4)
+  }
+
+  pub fn add_days(self, days: i64) -> Self {
+    Self::from_days(self.to_days() + days)
+  }
+
+  pub fn add_months(self, months: i32) -> Self {
+    let months = self.year * 12 + self.month as i32 - 1 + months;
+    let year = months.div_euclid(12);
+    let month = months.rem_euclid(12) as u32 + 1;
+
+    Self {
+      year,
+      month,
+      day: self.day.min(days_in_month(year, month)),
+    }
+  }
+
+  pub fn add_years(self, years: i32) -> Self {
+    self.add_months(years * 12)
+  }
+
+  pub fn start_of_month(self) -> Self {
+    Self { day: 1, ..self }
+  }
+
+  pub fn end_of_month(self) -> Self {
+    Self {
+      day: days_in_month(self.year, self.month),
+      ..self
+    }
+  }
+
+  pub fn start_of_week(self, week_start: Weekday) -> Self {
+    self.add_days(-(self.weekday().index() - week_start.index()).rem_euclid(7))
+  }
+
+  pub fn is_same_month(self, other: Self) -> bool {
+    self.year == other.year && self.month == other.month
+  }
+
+  pub fn clamp_to(self, min: Option<Self>, max: Option<Self>) -> Self {
+    let date = min.map_or(self, |min| self.max(min));
+    max.map_or(date, |max| date.min(max))
+  }
+}
+
+impl fmt::Display for CalendarDate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DateRange {
+  pub start: CalendarDate,
+  pub end: CalendarDate,
+}
+
+impl DateRange {
+  pub fn new(a: CalendarDate, b: CalendarDate) -> Self {
+    Self {
+      start: a.min(b),
+      end: a.max(b),
+    }
+  }
+
+  pub fn contains(&self, date: CalendarDate) -> bool {
+    (self.start..=self.end).contains(&date)
+  }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+  year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+  match month {
+    2 if is_leap_year(year) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+fn get_weeks(
+  month: CalendarDate,
+  week_start: Weekday,
+  fixed_weeks: bool,
+) -> Vec<Vec<CalendarDate>> {
+  let start = month.start_of_month().start_of_week(week_start);
+  let days = month.end_of_month().to_days() - start.to_days() + 1;
+  let weeks = if fixed_weeks { 6 } else { (days + 6) / 7 };
+
+  (0..weeks)
+    .map(|week| (0..7).map(|day| start.add_days(week * 7 + day)).collect())
+    .collect()
+}
+
+fn get_keyboard_target(
+  date: CalendarDate,
+  key: &str,
+  shift_key: bool,
+  direction: Direction,
+  week_start: Weekday,
+) -> Option<CalendarDate> {
+  let (previous_key, next_key) = match direction {
+    Direction::LeftToRight => ("ArrowLeft", "ArrowRight"),
+    Direction::RightToLeft => ("ArrowRight", "ArrowLeft"),
+  };
+
+  Some(match key {
+    key if key == previous_key => date.add_days(-1),
+    key if key == next_key => date.add_days(1),
+    "ArrowUp" => date.add_days(-7),
+    "ArrowDown" => date.add_days(7),
+    "Home" => date.start_of_week(week_start),
+    "End" => date.start_of_week(week_start).add_days(6),
+    "PageUp" if shift_key => date.add_years(-1),
+    "PageUp" => date.add_months(-1),
+    "PageDown" if shift_key => date.add_years(1),
+    "PageDown" => date.add_months(1),
+    _ => return None,
+  })
+}
+
+// labels are only localized in the browser, so servers and the first client render agree on them
+pub(crate) fn format_date(
+  date: CalendarDate,
+  locale: Option<&str>,
+  options: &[(&str, &str)],
+) -> Option<String> {
+  let formatter = create_date_formatter(locale, options)?;
+
+  formatter
+    .format()
+    .call1(&JsValue::UNDEFINED, &to_js_date(date))
+    .ok()?
+    .as_string()
+}
+
+pub(crate) fn create_date_formatter(
+  locale: Option<&str>,
+  options: &[(&str, &str)],
+) -> Option<DateTimeFormat> {
+  let locales = Array::new();
+
+  if let Some(locale) = locale {
+    locales.push(&JsValue::from_str(locale));
+  }
+
+  let js_options = Object::new();
+
+  for (key, value) in options.iter().chain(&[("timeZone", "UTC")]) {
+    Reflect::set(
+      &js_options,
+      &JsValue::from_str(key),
+      &JsValue::from_str(value),
+    )
+    .ok()?;
+  }
+
+  Some(DateTimeFormat::new(&locales, &js_options))
+}
+
+pub(crate) fn to_js_date(date: CalendarDate) -> Date {
+  let js_date = Date::new_0();
+  js_date.set_utc_full_year_with_month_date(
+    date.year as u32,
+    date.month as i32 - 1,
+    date.day as i32,
+  );
+  js_date.set_utc_hours(12);
+
+  js_date
+}
+
+fn get_month_label(date: CalendarDate, locale: Option<&str>, is_localized: bool) -> String {
+  is_localized
+    .then(|| format_date(date, locale, &[("month", "long"), ("year", "numeric")]))
+    .flatten()
+    .unwrap_or_else(|| format!("{} {}", MONTH_NAMES[date.month as usize - 1], date.year))
+}
+
+fn get_date_label(date: CalendarDate, locale: Option<&str>, is_localized: bool) -> String {
+  is_localized
+    .then(|| format_date(date, locale, &[("dateStyle", "full")]))
+    .flatten()
+    .unwrap_or_else(|| {
+      format!(
+        "{}, {} {}, {}",
+        WEEKDAY_NAMES[date.weekday().index() as usize],
+        MONTH_NAMES[date.month as usize - 1],
+        date.day,
+        date.year
+      )
+    })
+}
+
+fn get_weekday_label(
+  date: CalendarDate,
+  locale: Option<&str>,
+  is_localized: bool,
+  format: &str,
+) -> String {
+  is_localized
+    .then(|| format_date(date, locale, &[("weekday", format)]))
+    .flatten()
+    .unwrap_or_else(|| {
+      let name = WEEKDAY_NAMES[date.weekday().index() as usize];
+
+      match format {
+        "long" => name.to_string(),
+        _ => name.chars().take(2).collect(),
+      }
+    })
+}
+
+pub enum CalendarKind {
+  Single {
+    value: MaybeProp<CalendarDate>,
+    default_value: MaybeProp<CalendarDate>,
+    on_value_change: Option<Callback<CalendarDate>>,
+  },
+  Multiple {
+    value: MaybeProp<Vec<CalendarDate>>,
+    default_value: MaybeProp<Vec<CalendarDate>>,
+    on_value_change: Option<Callback<Vec<CalendarDate>>>,
+  },
+  Range {
+    value: MaybeProp<DateRange>,
+    default_value: MaybeProp<DateRange>,
+    on_value_change: Option<Callback<DateRange>>,
+  },
+}
+
+pub struct CalendarSingle;
+pub struct CalendarMultiple;
+pub struct CalendarRange;
+
+impl CalendarSingle {
+  pub fn none() -> Option<CalendarDate> {
+    None
+  }
+}
+
+impl CalendarMultiple {
+  pub fn none() -> Option<Vec<CalendarDate>> {
+    None
+  }
+}
+
+impl CalendarRange {
+  pub fn none() -> Option<DateRange> {
+    None
+  }
+}
+
+#[derive(Clone)]
+struct CalendarContextValue {
+  heading_id: Signal<String>,
+  selected: Signal<Vec<CalendarDate>>,
+  range: Signal<Option<DateRange>>,
+  is_multiselectable: bool,
+  placeholder: Signal<CalendarDate>,
+  range_anchor: RwSignal<Option<CalendarDate>>,
+  hovered: RwSignal<Option<CalendarDate>>,
+  min: Signal<Option<CalendarDate>>,
+  max: Signal<Option<CalendarDate>>,
+  is_date_disabled: Callback<CalendarDate, bool>,
+  week_start: Signal<Weekday>,
+  fixed_weeks: Signal<bool>,
+  locale: Signal<Option<String>>,
+  is_localized: Signal<bool>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+  direction: Signal<Direction>,
+  today: Signal<CalendarDate>,
+  should_focus: StoredValue<bool>,
+  on_placeholder_change: Callback<CalendarDate>,
+  on_date_select: Callback<CalendarDate>,
+}
+
+impl CalendarContextValue {
+  fn is_unavailable(&self, date: CalendarDate) -> bool {
+    self.min.get().is_some_and(|min| date < min)
+      || self.max.get().is_some_and(|max| date > max)
+      || self.is_date_disabled.call(date)
+  }
+
+  // a range that's being picked is previewed up to the hovered date
+  fn highlighted_range(&self) -> Option<DateRange> {
+    match self.range_anchor.get() {
+      Some(anchor) => Some(DateRange::new(anchor, self.hovered.get().unwrap_or(anchor))),
+      None => self.range.get(),
+    }
+  }
+}
+
+#[derive(Clone)]
+struct CalendarCellContextValue {
+  date: CalendarDate,
+}
+
+#[component]
+pub fn CalendarRoot(
+  kind: CalendarKind,
+
+  #[prop(optional, into)] placeholder: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] default_placeholder: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] min: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] max: MaybeProp<CalendarDate>,
+  #[prop(default=(|_|false).into(), into)] is_date_disabled: Callback<CalendarDate, bool>,
+  #[prop(optional, into)] week_start: MaybeSignal<Weekday>,
+  #[prop(optional, into)] fixed_weeks: MaybeSignal<bool>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] auto_focus: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_placeholder_change: Callback<CalendarDate>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+  let range_anchor = RwSignal::new(None);
+
+  let (selected, range, is_multiselectable, on_value_select) = match kind {
+    CalendarKind::Single {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().into_iter().collect()),
+        Signal::derive(|| None),
+        false,
+        Callback::new(move |date| set_value.set(date)),
+      )
+    }
+    CalendarKind::Multiple {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().unwrap_or_default()),
+        Signal::derive(|| None),
+        true,
+        Callback::new(move |date: CalendarDate| {
+          set_value.update(|dates| {
+            let dates = dates.get_or_insert_with(Vec::new);
+
+            match dates.iter().position(|selected| *selected == date) {
+              Some(index) => {
+                dates.remove(index);
+              }
+              None => {
+                dates.push(date);
+                dates.sort();
+              }
+            }
+          });
+        }),
+      )
+    }
+    CalendarKind::Range {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || {
+          value
+            .get()
+            .map(|range| vec![range.start, range.end])
+            .unwrap_or_default()
+        }),
+        Signal::derive(move || value.get()),
+        true,
+        // the first date anchors the range, the second one completes it
+        Callback::new(move |date| match range_anchor.get_untracked() {
+          Some(anchor) => {
+            range_anchor.set(None);
+            set_value.set(DateRange::new(anchor, date));
+          }
+          None => range_anchor.set(Some(date)),
+        }),
+      )
+    }
+  };
+
+  let min = Signal::derive(move || min.get());
+  let max = Signal::derive(move || max.get());
+
+  let (placeholder, set_placeholder) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || placeholder.get()),
+    default_value: Signal::derive(move || {
+      Some(
+        default_placeholder
+          .get()
+          .or_else(|| selected.get().first().copied())
+          .unwrap_or_else(CalendarDate::today)
+          .clamp_to(min.get(), max.get()),
+      )
+    }),
+    on_change: on_placeholder_change,
+  });
+
+  let placeholder = Signal::derive(move || placeholder.get().unwrap_or_else(CalendarDate::today));
+
+  let (is_localized, set_is_localized) = create_signal(false);
+  let (today, set_today) = create_signal(CalendarDate::today());
+
+  Effect::new(move |_| {
+    set_is_localized.set(true);
+    set_today.set(CalendarDate::today());
+  });
+
+  let heading_id = create_id();
+  let locale = Signal::derive(move || locale.get());
+  let disabled = Signal::derive(move || disabled.get());
+  let read_only = Signal::derive(move || read_only.get());
+
+  provide_context(CalendarContextValue {
+    heading_id,
+    selected,
+    range,
+    is_multiselectable,
+    placeholder,
+    range_anchor,
+    hovered: RwSignal::new(None),
+    min,
+    max,
+    is_date_disabled,
+    week_start: Signal::derive(move || week_start.get()),
+    fixed_weeks: Signal::derive(move || fixed_weeks.get()),
+    locale,
+    is_localized: is_localized.into(),
+    disabled,
+    read_only,
+    direction,
+    today: today.into(),
+    should_focus: StoredValue::new(auto_focus.get_untracked()),
+    on_placeholder_change: Callback::new(move |date| set_placeholder.set(date)),
+    on_date_select: Callback::new(move |date| {
+      if !disabled.get_untracked() && !read_only.get_untracked() {
+        on_value_select.call(date);
+      }
+    }),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="application"
+      attr:aria-label=move || {
+        get_month_label(placeholder.get(), locale.get().as_deref(), is_localized.get())
+      }
+      attr:dir=move || direction.get().to_string()
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:data-readonly=move || read_only.get().then_some("")
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarHeader(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarHeading(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CalendarContextValue {
+    heading_id,
+    placeholder,
+    locale,
+    is_localized,
+    ..
+  } = use_context().expect("CalendarHeading must be used in a CalendarRoot component");
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=heading_id
+      attr:aria-live="polite"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {move || children.with_value(|children| match children {
+        Some(children) => children().into_view(),
+        None => {
+          get_month_label(placeholder.get(), locale.get().as_deref(), is_localized.get())
+            .into_view()
+        }
+      })}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarPrev(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <CalendarNavigationButton
+      attrs=attrs
+      months=-1
+      label="Previous month"
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "‹".into_view())}
+    </CalendarNavigationButton>
+  }
+}
+
+#[component]
+pub fn CalendarNext(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <CalendarNavigationButton
+      attrs=attrs
+      months=1
+      label="Next month"
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "›".into_view())}
+    </CalendarNavigationButton>
+  }
+}
+
+#[component]
+fn CalendarNavigationButton(
+  months: i32,
+  label: &'static str,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CalendarContextValue {
+    placeholder,
+    min,
+    max,
+    disabled,
+    on_placeholder_change,
+    ..
+  } =
+    use_context().expect("CalendarPrev and CalendarNext must be used in a CalendarRoot component");
+
+  let is_disabled = Signal::derive(move || {
+    let month = placeholder.get().add_months(months);
+
+    disabled.get()
+      || min.get().is_some_and(|min| month.end_of_month() < min)
+      || max.get().is_some_and(|max| month.start_of_month() > max)
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      attr:aria-label=label
+      attr:aria-disabled=move || is_disabled.get().then_some("true")
+      attr:data-disabled=move || is_disabled.get().then_some("")
+      attr:disabled=is_disabled
+      element=html::button
+      on:click=move |_: MouseEvent| {
+        if !is_disabled.get_untracked() {
+          on_placeholder_change.call(
+            placeholder
+              .get_untracked()
+              .add_months(months)
+              .clamp_to(min.get_untracked(), max.get_untracked()),
+          );
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarGrid(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CalendarContextValue {
+    heading_id,
+    is_multiselectable,
+    disabled,
+    read_only,
+    ..
+  } = use_context().expect("CalendarGrid must be used in a CalendarRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="grid"
+      attr:tabindex="-1"
+      attr:aria-labelledby=heading_id
+      attr:aria-multiselectable=is_multiselectable.then_some("true")
+      attr:aria-readonly=move || read_only.get().then_some("true")
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:style="border-collapse: collapse;"
+      element=html::table
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarGridHead(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CalendarContextValue {
+    placeholder,
+    week_start,
+    locale,
+    is_localized,
+    ..
+  } = use_context().expect("CalendarGridHead must be used in a CalendarRoot component");
+
+  let weekdays = Signal::derive(move || {
+    let week = placeholder.get().start_of_week(week_start.get());
+    let locale = locale.get();
+
+    (0..7)
+      .map(|day| {
+        let date = week.add_days(day);
+
+        (
+          get_weekday_label(date, locale.as_deref(), is_localized.get(), "short"),
+          get_weekday_label(date, locale.as_deref(), is_localized.get(), "long"),
+        )
+      })
+      .collect::<Vec<_>>()
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:aria-hidden="true"
+      element=html::thead
+      node_ref=node_ref
+      as_child=as_child
+    >
+      <tr>
+        {move || {
+          weekdays
+            .get()
+            .into_iter()
+            .map(|(short, long)| view! {
+              <th scope="col" abbr=long>
+                {short}
+              </th>
+            })
+            .collect_view()
+        }}
+      </tr>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarGridBody<F, IV>(children: F) -> impl IntoView
+where
+  F: Fn(CalendarDate) -> IV + 'static,
+  IV: IntoView + 'static,
+{
+  let CalendarContextValue {
+    placeholder,
+    week_start,
+    fixed_weeks,
+    ..
+  } = use_context().expect("CalendarGridBody must be used in a CalendarRoot component");
+
+  let weeks = create_memo(move |_| {
+    get_weeks(
+      placeholder.get().start_of_month(),
+      week_start.get(),
+      fixed_weeks.get(),
+    )
+  });
+
+  let children = Rc::new(children);
+
+  view! {
+    <tbody>
+      {move || {
+        weeks
+          .get()
+          .into_iter()
+          .map(|week| {
+            let children = children.clone();
+
+            view! {
+              <tr>
+                {week.into_iter().map(|date| children(date)).collect_view()}
+              </tr>
+            }
+          })
+          .collect_view()
+      }}
+    </tbody>
+  }
+}
+
+#[component]
+pub fn CalendarCell(
+  date: CalendarDate,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CalendarContextValue>()
+    .expect("CalendarCell must be used in a CalendarRoot component");
+
+  provide_context(CalendarCellContextValue { date });
+
+  let is_selected = {
+    let context = context.clone();
+
+    Signal::derive(move || {
+      context.selected.get().contains(&date)
+        || context
+          .highlighted_range()
+          .is_some_and(|range| range.contains(date))
+    })
+  };
+
+  let is_disabled = Signal::derive(move || context.disabled.get() || context.is_unavailable(date));
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="gridcell"
+      attr:aria-selected=move || is_selected.get().then_some("true")
+      attr:aria-disabled=move || is_disabled.get().then_some("true")
+      attr:data-disabled=move || is_disabled.get().then_some("")
+      element=html::td
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CalendarCellTrigger(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CalendarContextValue>()
+    .expect("CalendarCellTrigger must be used in a CalendarRoot component");
+  let CalendarCellContextValue { date } =
+    use_context().expect("CalendarCellTrigger must be used in a CalendarCell component");
+
+  let CalendarContextValue {
+    selected,
+    placeholder,
+    range_anchor,
+    hovered,
+    min,
+    max,
+    week_start,
+    locale,
+    is_localized,
+    disabled,
+    direction,
+    today,
+    should_focus,
+    on_placeholder_change,
+    on_date_select,
+    ..
+  } = context.clone();
+
+  let is_outside_month = Signal::derive(move || !placeholder.get().is_same_month(date));
+  let is_focused = Signal::derive(move || placeholder.get() == date);
+
+  let is_unavailable = {
+    let context = context.clone();
+    Signal::derive(move || context.is_unavailable(date))
+  };
+
+  let highlighted_range = Signal::derive(move || context.highlighted_range());
+  let is_disabled = Signal::derive(move || disabled.get() || is_unavailable.get());
+  let is_selected = Signal::derive(move || selected.get().contains(&date));
+
+  // moving focus with the keyboard can change the month, so the new cell focuses itself once it's rendered
+  Effect::new(move |_| {
+    if !is_focused.get() || is_outside_month.get() || !should_focus.get_value() {
+      return;
+    }
+
+    if let Some(node) = node_ref.get() {
+      should_focus.set_value(false);
+      _ = node.focus();
+    }
+  });
+
+  let select = move || {
+    if is_disabled.get_untracked() {
+      return;
+    }
+
+    on_date_select.call(date);
+
+    if is_outside_month.get_untracked() {
+      should_focus.set_value(true);
+      on_placeholder_change.call(date);
+    }
+  };
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="button"
+      attr:tabindex=move || if is_focused.get() && !is_outside_month.get() { "0" } else { "-1" }
+      attr:aria-label=move || get_date_label(date, locale.get().as_deref(), is_localized.get())
+      attr:aria-disabled=move || is_disabled.get().then_some("true")
+      attr:data-value=date.to_string()
+      attr:data-selected=move || is_selected.get().then_some("")
+      attr:data-today=move || (today.get() == date).then_some("")
+      attr:data-outside-month=move || is_outside_month.get().then_some("")
+      attr:data-disabled=move || is_disabled.get().then_some("")
+      attr:data-unavailable=move || is_unavailable.get().then_some("")
+      attr:data-focused=move || is_focused.get().then_some("")
+      attr:data-highlighted=move || {
+        highlighted_range.get().is_some_and(|range| range.contains(date)).then_some("")
+      }
+      attr:data-selection-start=move || {
+        highlighted_range.get().is_some_and(|range| range.start == date).then_some("")
+      }
+      attr:data-selection-end=move || {
+        highlighted_range.get().is_some_and(|range| range.end == date).then_some("")
+      }
+      element=html::div
+      on:click=move |_: MouseEvent| select()
+      on:pointerenter=move |_: PointerEvent| {
+        if range_anchor.get_untracked().is_some() && !is_disabled.get_untracked() {
+          hovered.set(Some(date));
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        let key = ev.key();
+
+        if key == "Enter" || key == " " {
+          ev.prevent_default();
+          select();
+
+          return;
+        }
+
+        let Some(target) = get_keyboard_target(
+          date,
+          &key,
+          ev.shift_key(),
+          direction.get_untracked(),
+          week_start.get_untracked(),
+        ) else {
+          return;
+        };
+
+        ev.prevent_default();
+
+        let target = target.clamp_to(min.get_untracked(), max.get_untracked());
+
+        if range_anchor.get_untracked().is_some() {
+          hovered.set(Some(target));
+        }
+
+        should_focus.set_value(true);
+        on_placeholder_change.call(target);
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.with_value(|children| match children {
+        Some(children) => children().into_view(),
+        None => date.day.into_view(),
+      })}
+    </Primitive>
+  }
+}
+
+#[test]
+fn converts_dates_to_days_and_back() {
+  for (date, days) in [
+    (CalendarDate::new(1970, 1, 1), 0),
+    (CalendarDate::new(2000, 2, 29), 11_016),
+    (CalendarDate::new(1969, 12, 31), -1),
+    (CalendarDate::new(2024, 12, 31), 20_088),
+  ] {
+    let date = date.unwrap();
+
+    assert_eq!(date.to_days(), days);
+    assert_eq!(CalendarDate::from_days(days), date);
+  }
+}
+
+#[test]
+fn validates_dates_and_leap_years() {
+  assert!(CalendarDate::new(2024, 2, 29).is_some());
+  assert!(CalendarDate::new(2023, 2, 29).is_none());
+  assert!(CalendarDate::new(1900, 2, 29).is_none());
+  assert!(CalendarDate::new(2000, 2, 29).is_some());
+  assert!(CalendarDate::new(2024, 13, 1).is_none());
+  assert!(CalendarDate::new(2024, 4, 31).is_none());
+}
+
+#[test]
+fn adds_months_clamping_the_day() {
+  let date = CalendarDate::new(2024, 1, 31).unwrap();
+
+  assert_eq!(date.add_months(1), CalendarDate::new(2024, 2, 29).unwrap());
+  assert_eq!(
+    date.add_months(-2),
+    CalendarDate::new(2023, 11, 30).unwrap()
+  );
+  assert_eq!(
+    date.add_years(1).add_months(1),
+    CalendarDate::new(2025, 2, 28).unwrap()
+  );
+  assert_eq!(date.add_days(1), CalendarDate::new(2024, 2, 1).unwrap());
+}
+
+#[test]
+fn finds_weekdays_and_week_starts() {
+  let date = CalendarDate::new(2024, 5, 15).unwrap();
+
+  assert_eq!(date.weekday(), Weekday::Wednesday);
+  assert_eq!(
+    date.start_of_week(Weekday::Sunday),
+    CalendarDate::new(2024, 5, 12).unwrap()
+  );
+  assert_eq!(
+    date.start_of_week(Weekday::Monday),
+    CalendarDate::new(2024, 5, 13).unwrap()
+  );
+}
+
+#[test]
+fn builds_month_grids() {
+  let month = CalendarDate::new(2024, 6, 1).unwrap();
+  let weeks = get_weeks(month, Weekday::Sunday, false);
+
+  assert_eq!(weeks.len(), 6);
+  assert_eq!(weeks[0][0], CalendarDate::new(2024, 5, 26).unwrap());
+  assert_eq!(weeks[5][6], CalendarDate::new(2024, 7, 6).unwrap());
+
+  let month = CalendarDate::new(2026, 2, 1).unwrap();
+
+  assert_eq!(get_weeks(month, Weekday::Sunday, false).len(), 4);
+  assert_eq!(get_weeks(month, Weekday::Sunday, true).len(), 6);
+  assert_eq!(get_weeks(month, Weekday::Monday, false).len(), 5);
+}
+
+#[test]
+fn moves_focus_with_the_keyboard() {
+  let date = CalendarDate::new(2024, 5, 15).unwrap();
+  let target = |key, shift_key, direction| {
+    get_keyboard_target(date, key, shift_key, direction, Weekday::Monday)
+  };
+
+  assert_eq!(
+    target("ArrowRight", false, Direction::LeftToRight),
+    CalendarDate::new(2024, 5, 16)
+  );
+  assert_eq!(
+    target("ArrowRight", false, Direction::RightToLeft),
+    CalendarDate::new(2024, 5, 14)
+  );
+  assert_eq!(
+    target("ArrowUp", false, Direction::LeftToRight),
+    CalendarDate::new(2024, 5, 8)
+  );
+  assert_eq!(
+    target("End", false, Direction::LeftToRight),
+    CalendarDate::new(2024, 5, 19)
+  );
+  assert_eq!(
+    target("PageDown", true, Direction::LeftToRight),
+    CalendarDate::new(2025, 5, 15)
+  );
+  assert_eq!(target("a", false, Direction::LeftToRight), None);
+}
+
+#[test]
+fn parses_and_formats_iso_dates() {
+  let date = CalendarDate::new(2024, 3, 9).unwrap();
+
+  assert_eq!(date.to_string(), "2024-03-09");
+  assert_eq!(CalendarDate::from_iso("2024-03-09"), Some(date));
+  assert_eq!(CalendarDate::from_iso("2024-02-30"), None);
+  assert_eq!(CalendarDate::from_iso("March 9th"), None);
+}
@@ -18,6 +18,7 @@ use crate::{
     create_previous::create_previous,
     Attributes,
   },
+  visually_hidden::VisuallyHidden,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -38,6 +39,8 @@ pub fn CheckboxRoot(
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(optional, into)] checked: MaybeProp<CheckedState>,
   #[prop(optional, into)] default_checked: MaybeProp<CheckedState>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] name: MaybeProp<String>,
 
   #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
   #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
@@ -107,6 +110,9 @@ pub fn CheckboxRoot(
     disabled: Signal::derive(move || disabled.get()),
   });
 
+  let name = Signal::derive(move || name.get());
+  let value = Signal::derive(move || value.get());
+
   let bubble_ref = NodeRef::<Input>::new();
 
   Effect::new(move |_| {
@@ -175,6 +181,10 @@ pub fn CheckboxRoot(
       <BubbleInput
         checked=Signal::derive(move || checked.get().unwrap_or(CheckedState::Checked(false)))
         bubbles=Signal::derive(move || false)
+        name=name
+        value=value
+        disabled=Signal::derive(move || disabled.get())
+        required=Signal::derive(move || required.get())
         control=node_ref
         node_ref=bubble_ref
       />
@@ -232,6 +242,10 @@ fn BubbleInput(
   checked: Signal<CheckedState>,
   control: NodeRef<AnyElement>,
   bubbles: Signal<bool>,
+  #[prop(into)] name: MaybeProp<String>,
+  #[prop(into)] value: MaybeProp<String>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
 
   node_ref: NodeRef<Input>,
   #[prop(attrs)] attrs: Attributes,
@@ -284,20 +298,33 @@ fn BubbleInput(
     })();
   });
 
+  let attrs = StoredValue::new(attrs);
+  let name = Signal::derive(move || name.get());
+  let value = Signal::derive(move || value.get().unwrap_or("on".into()));
+
   view! {
-    <input
-      {..attrs}
-      type="checkbox"
-      aria-hidden="true"
-      checked=(move || match checked.get() { CheckedState::Checked(checked) => checked, CheckedState::Indeterminate => false }).into_attribute()
-      tabindex=(-1).into_attribute()
-      node_ref=node_ref
-      style:position="absolute"
-      style:pointer-events="none"
-      style:opacity="0"
-      style:margin="0"
-      style:width=move || format!("{}px", width.get())
-      style:height=move || format!("{}px", height.get())
-    />
+    <VisuallyHidden
+      as_child=true
+      attr:style=move || {
+        format!(
+          "pointer-events: none; opacity: 0; margin: 0; width: {}px; height: {}px;",
+          width.get(),
+          height.get()
+        )
+      }
+    >
+      <input
+        {..attrs.get_value()}
+        type="checkbox"
+        aria-hidden="true"
+        checked=(move || match checked.get() { CheckedState::Checked(checked) => checked, CheckedState::Indeterminate => false }).into_attribute()
+        tabindex=(-1).into_attribute()
+        node_ref=node_ref
+        name=name
+        value=value
+        disabled=Signal::derive(move || disabled.get()).into_attribute()
+        required=Signal::derive(move || required.get()).into_attribute()
+      />
+    </VisuallyHidden>
   }
 }
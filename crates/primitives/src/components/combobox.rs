@@ -0,0 +1,1469 @@
+use std::{collections::HashMap, future::Future, rc::Rc};
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  js_sys::{JsString, Reflect},
+  CustomEvent, Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent, ScrollIntoViewOptions,
+  ScrollLogicalPosition,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
+  dismissable_layer::{get_original_event, DismissableLayer},
+  popper::{PopperAnchor, PopperContent, PopperRoot},
+  portal::Portal,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+  Align, Direction, Side, Sticky,
+};
+
+pub enum ComboboxKind {
+  Single {
+    value: MaybeProp<String>,
+    default_value: MaybeProp<String>,
+    on_value_change: Option<Callback<String>>,
+  },
+  Multiple {
+    value: MaybeProp<Vec<String>>,
+    default_value: MaybeProp<Vec<String>>,
+    on_value_change: Option<Callback<Vec<String>>>,
+  },
+}
+
+pub struct ComboboxSingle;
+pub struct ComboboxMultiple;
+
+impl ComboboxSingle {
+  pub fn none() -> Option<String> {
+    None
+  }
+}
+
+impl ComboboxMultiple {
+  pub fn none() -> Option<Vec<String>> {
+    None
+  }
+}
+
+type CustomFilter = Rc<dyn Fn(&str, &str) -> bool>;
+
+#[derive(Clone, Default)]
+pub enum ComboboxFilter {
+  #[default]
+  Contains,
+  StartsWith,
+  // items are shown as they're rendered, e.g. when a resource has already filtered them
+  Manual,
+  Custom(CustomFilter),
+}
+
+impl ComboboxFilter {
+  pub fn custom(filter: impl Fn(&str, &str) -> bool + 'static) -> Self {
+    Self::Custom(Rc::new(filter))
+  }
+
+  // custom filters get the search exactly as it was typed, they may care about case or spacing
+  fn matches(&self, text_value: &str, search: &str) -> bool {
+    let normalize = || search.trim().to_lowercase();
+
+    match self {
+      Self::Contains => text_value.to_lowercase().contains(&normalize()),
+      Self::StartsWith => text_value.to_lowercase().starts_with(&normalize()),
+      Self::Manual => true,
+      Self::Custom(filter) => filter(text_value, search),
+    }
+  }
+}
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+struct ComboboxItemData {
+  value: String,
+  id: Signal<String>,
+  disabled: Signal<bool>,
+}
+
+impl Ord for ComboboxItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for ComboboxItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct ComboboxContextValue {
+  input_ref: NodeRef<AnyElement>,
+  trigger_ref: NodeRef<AnyElement>,
+  anchor_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  value: Signal<Vec<String>>,
+  multiple: bool,
+  input_value: Signal<String>,
+  search: RwSignal<String>,
+  highlighted: RwSignal<Option<String>>,
+  labels: RwSignal<HashMap<String, String>>,
+  open: Signal<bool>,
+  filter: Signal<ComboboxFilter>,
+  allow_custom_value: Signal<bool>,
+  loading: Signal<bool>,
+  resource_loading: RwSignal<bool>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
+  direction: Signal<Direction>,
+  has_custom_anchor: ReadSignal<bool>,
+  set_has_custom_anchor: WriteSignal<bool>,
+  on_input_value_change: Callback<String>,
+  on_open_change: Callback<bool>,
+  on_item_select: Callback<String>,
+  on_item_remove: Callback<String>,
+}
+
+#[derive(Clone)]
+struct ComboboxItemContextValue {
+  is_selected: Signal<bool>,
+}
+
+#[derive(Clone)]
+struct ComboboxChipContextValue {
+  value: Signal<String>,
+}
+
+#[derive(Clone)]
+struct ComboboxGroupContextValue {
+  id: Signal<String>,
+}
+
+fn get_label(labels: RwSignal<HashMap<String, String>>, value: &str) -> String {
+  labels
+    .with(|labels| labels.get(value).cloned())
+    .unwrap_or_else(|| value.to_string())
+}
+
+fn find_value_by_label(labels: &HashMap<String, String>, text: &str) -> Option<String> {
+  let text = text.trim().to_lowercase();
+
+  labels
+    .iter()
+    .find(|(_, label)| label.to_lowercase() == text)
+    .map(|(value, _)| value.clone())
+}
+
+fn get_next_index(current_index: Option<usize>, len: usize, forward: bool) -> usize {
+  match (current_index, forward) {
+    (Some(index), true) => (index + 1) % len,
+    (Some(index), false) => (index + len - 1) % len,
+    (None, true) => 0,
+    (None, false) => len - 1,
+  }
+}
+
+#[component]
+pub fn ComboboxRoot(
+  kind: ComboboxKind,
+
+  #[prop(optional, into)] input_value: MaybeProp<String>,
+  #[prop(optional, into)] default_input_value: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] filter: MaybeSignal<ComboboxFilter>,
+  #[prop(optional, into)] allow_custom_value: MaybeSignal<bool>,
+  #[prop(optional, into)] loading: MaybeSignal<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] form: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_input_value_change: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let (input_value, set_input_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || input_value.get()),
+    default_value: Signal::derive(move || default_input_value.get()),
+    on_change: on_input_value_change,
+  });
+
+  let (value, multiple, on_value_select, on_value_remove) = match kind {
+    ComboboxKind::Single {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      let set_removed_value = set_value.clone();
+
+      (
+        Signal::derive(move || {
+          value
+            .get()
+            .into_iter()
+            .filter(|value| !value.is_empty())
+            .collect()
+        }),
+        false,
+        Callback::new(move |value| set_value.set(value)),
+        // an empty value clears the selection, the same way a single accordion collapses
+        Callback::new(move |_| set_removed_value.set(String::new())),
+      )
+    }
+    ComboboxKind::Multiple {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      let set_removed_value = set_value.clone();
+
+      (
+        Signal::derive(move || value.get().unwrap_or_default()),
+        true,
+        Callback::new(move |item: String| {
+          set_value.update(|values| {
+            let values = values.get_or_insert_with(Vec::new);
+
+            match values.iter().position(|value| *value == item) {
+              Some(index) => {
+                values.remove(index);
+              }
+              None => values.push(item),
+            }
+          });
+        }),
+        Callback::new(move |item: String| {
+          set_removed_value.update(|values| {
+            if let Some(values) = values {
+              values.retain(|value| *value != item);
+            }
+          });
+        }),
+      )
+    }
+  };
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let input_value = Signal::derive(move || input_value.get().unwrap_or_default());
+  let on_input_value_change = Callback::new(move |value| set_input_value.set(value));
+  let on_open_change = Callback::new(move |open| set_open.set(open));
+
+  let input_ref = NodeRef::<AnyElement>::new();
+  let search = RwSignal::new(String::new());
+  let labels = RwSignal::new(HashMap::<String, String>::new());
+  let resource_loading = RwSignal::new(false);
+  let (has_custom_anchor, set_has_custom_anchor) = create_signal(false);
+
+  let on_item_select = Callback::new(move |item: String| {
+    if multiple {
+      on_value_select.call(item);
+      on_input_value_change.call(String::new());
+    } else {
+      on_input_value_change.call(get_label(labels, &item));
+      on_value_select.call(item);
+      on_open_change.call(false);
+    }
+
+    search.set(String::new());
+  });
+
+  // the input shows the selected item's text, which is only known once its item has rendered
+  let selected_label = create_memo(move |_| {
+    if multiple {
+      return None;
+    }
+
+    value.with(|value| value.first().map(|value| get_label(labels, value)))
+  });
+
+  Effect::new(move |_| {
+    if let Some(label) = selected_label.get() {
+      on_input_value_change.call(label);
+    }
+  });
+
+  provide_context(CollectionContextValue::<ComboboxItemData, AnyElement> {
+    collection_ref: NodeRef::new(),
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  provide_context(ComboboxContextValue {
+    input_ref,
+    trigger_ref: NodeRef::new(),
+    anchor_ref: NodeRef::new(),
+    content_id: create_id(),
+    value,
+    multiple,
+    input_value,
+    search,
+    highlighted: RwSignal::new(None),
+    labels,
+    open,
+    filter: Signal::derive(move || filter.get()),
+    allow_custom_value: Signal::derive(move || allow_custom_value.get()),
+    loading: Signal::derive(move || loading.get() || resource_loading.get()),
+    resource_loading,
+    disabled: Signal::derive(move || disabled.get()),
+    required: Signal::derive(move || required.get()),
+    direction,
+    has_custom_anchor,
+    set_has_custom_anchor,
+    on_input_value_change,
+    on_open_change,
+    on_item_select,
+    on_item_remove: on_value_remove,
+  });
+
+  let (is_form_control, set_is_form_control) = create_signal(true);
+
+  let name = Signal::derive(move || name.get());
+  let form = Signal::derive(move || form.get());
+
+  Effect::new(move |_| {
+    set_is_form_control.set(if let Some(input) = input_ref.get() {
+      form.get().is_some() || input.closest("form").ok().flatten().is_some()
+    } else {
+      true
+    });
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+
+      <Show when=move || is_form_control.get()>
+        <BubbleInputs
+          value=value
+          input_ref=input_ref
+          name=name
+          form=form
+          disabled=Signal::derive(move || disabled.get())
+          required=Signal::derive(move || required.get())
+        />
+      </Show>
+    </PopperRoot>
+  }
+}
+
+pub fn use_combobox_resource<T, Fut>(
+  fetcher: impl Fn(String) -> Fut + 'static,
+) -> Resource<String, T>
+where
+  T: 'static,
+  Fut: Future<Output = T> + 'static,
+{
+  let ComboboxContextValue {
+    search,
+    resource_loading,
+    ..
+  } = use_context().expect("use_combobox_resource must be used in a ComboboxRoot component");
+
+  let resource = create_local_resource(move || search.get(), fetcher);
+
+  Effect::new(move |_| resource_loading.set(resource.loading().get()));
+
+  on_cleanup(move || {
+    _ = resource_loading.try_set(false);
+  });
+
+  resource
+}
+
+#[component]
+pub fn ComboboxAnchor(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    anchor_ref,
+    set_has_custom_anchor,
+    ..
+  } = use_context().expect("ComboboxAnchor must be used in a ComboboxRoot component");
+
+  // set while rendering so the input doesn't briefly register itself as the anchor
+  set_has_custom_anchor.set(true);
+
+  on_cleanup(move || {
+    _ = set_has_custom_anchor.try_set(false);
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      anchor_ref.load(&node);
+    }
+  });
+
+  view! {
+    <PopperAnchor
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn ComboboxInput(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    input_ref,
+    content_id,
+    value,
+    multiple,
+    input_value,
+    search,
+    highlighted,
+    labels,
+    open,
+    allow_custom_value,
+    disabled,
+    required,
+    direction,
+    has_custom_anchor,
+    on_input_value_change,
+    on_open_change,
+    on_item_select,
+    on_item_remove,
+    ..
+  } = use_context().expect("ComboboxInput must be used in a ComboboxRoot component");
+
+  let get_items = use_collection_context::<ComboboxItemData, AnyElement>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      input_ref.load(&node);
+    }
+  });
+
+  // the value is set as a property since the attribute only holds the input's initial value
+  Effect::new(move |_| {
+    let input_value = input_value.get();
+
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let current_value = Reflect::get(&node, &JsString::from("value"))
+      .ok()
+      .and_then(|value| value.as_string());
+
+    if current_value.as_deref() != Some(input_value.as_str()) {
+      _ = Reflect::set(
+        &node,
+        &JsString::from("value"),
+        &JsValue::from_str(&input_value),
+      );
+    }
+  });
+
+  let active_descendant = Signal::derive(move || {
+    if !open.get() {
+      return None;
+    }
+
+    let highlighted = highlighted.get()?;
+
+    get_items
+      .get()
+      .into_iter()
+      .find(|(_, item)| item.value == highlighted)
+      .map(|(_, item)| item.id.get())
+  });
+
+  let move_highlight = move |forward: bool| {
+    let items = get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, item)| !item.disabled.get_untracked())
+      .map(|(_, item)| item.value)
+      .collect::<Vec<_>>();
+
+    if items.is_empty() {
+      return;
+    }
+
+    let current_index = highlighted
+      .get_untracked()
+      .and_then(|highlighted| items.iter().position(|item| *item == highlighted));
+
+    highlighted.set(Some(
+      items[get_next_index(current_index, items.len(), forward)].clone(),
+    ));
+  };
+
+  let commit_custom_value = move || {
+    let text = input_value.get_untracked().trim().to_string();
+
+    if text.is_empty() {
+      return;
+    }
+
+    if multiple && value.with_untracked(|value| value.contains(&text)) {
+      on_input_value_change.call(String::new());
+      search.set(String::new());
+    } else {
+      on_item_select.call(text);
+    }
+  };
+
+  // strict comboboxes only keep text that belongs to an item, free text is kept as the value
+  let handle_blur = move || {
+    on_open_change.call(false);
+
+    let text = input_value.get_untracked();
+    let selected_value = value.with_untracked(|value| value.first().cloned());
+
+    if text.trim().is_empty() {
+      if let Some(selected_value) = selected_value.filter(|_| !multiple) {
+        on_item_remove.call(selected_value);
+      }
+    } else if let Some(matching_value) = labels
+      .with_untracked(|labels| find_value_by_label(labels, &text))
+      .filter(|_| !multiple)
+    {
+      if selected_value.as_ref() != Some(&matching_value) {
+        on_item_select.call(matching_value);
+      }
+    } else if allow_custom_value.get_untracked() {
+      if !multiple {
+        commit_custom_value();
+      }
+    } else {
+      on_input_value_change.call(match selected_value.filter(|_| !multiple) {
+        Some(selected_value) => get_label(labels, &selected_value),
+        None => String::new(),
+      });
+    }
+
+    search.set(String::new());
+  };
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  let input = move || {
+    view! {
+      <Primitive
+        {..attrs.get_value()}
+        attr:type="text"
+        attr:role="combobox"
+        attr:autocomplete="off"
+        attr:aria-autocomplete="list"
+        attr:aria-controls=content_id
+        attr:aria-expanded=open
+        attr:aria-required=required
+        attr:aria-activedescendant=active_descendant
+        attr:dir=move || direction.get().to_string()
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-disabled=move || disabled.get().then_some("")
+        attr:disabled=disabled
+        attr:value=move || input_value.get_untracked()
+        element=html::input
+        on:input=move |ev: Event| {
+          let value = event_target_value(&ev);
+
+          search.set(value.clone());
+          on_input_value_change.call(value);
+
+          if !open.get_untracked() {
+            on_open_change.call(true);
+          }
+        }
+        on:blur=move |_: FocusEvent| handle_blur()
+        on:keydown=move |ev: KeyboardEvent| {
+          match ev.key().as_str() {
+            key @ ("ArrowDown" | "ArrowUp") => {
+              if open.get_untracked() {
+                move_highlight(key == "ArrowDown");
+              } else {
+                on_open_change.call(true);
+              }
+
+              // keeps the caret from jumping to either end of the text
+              ev.prevent_default();
+            }
+            "Enter" => {
+              if let Some(item) = open
+                .get_untracked()
+                .then(|| highlighted.get_untracked())
+                .flatten()
+              {
+                on_item_select.call(item);
+              } else if allow_custom_value.get_untracked()
+                && !input_value.get_untracked().trim().is_empty()
+              {
+                commit_custom_value();
+              } else {
+                return;
+              }
+
+              // keeps the surrounding form from submitting
+              ev.prevent_default();
+            }
+            "Escape" if !open.get_untracked() => {
+              on_input_value_change.call(String::new());
+              search.set(String::new());
+            }
+            "Backspace" if multiple && input_value.get_untracked().is_empty() => {
+              if let Some(last_value) = value.with_untracked(|value| value.last().cloned()) {
+                on_item_remove.call(last_value);
+              }
+            }
+            _ => {}
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    }
+  };
+
+  // the input doubles as the anchor unless a ComboboxAnchor was rendered
+  view! {
+    <Show
+      when=move || has_custom_anchor.get()
+      fallback=move || view! {
+        <PopperAnchor as_child=true>
+          {input()}
+        </PopperAnchor>
+      }
+    >
+      {input()}
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxTrigger(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    input_ref,
+    trigger_ref,
+    content_id,
+    open,
+    disabled,
+    on_open_change,
+    ..
+  } = use_context().expect("ComboboxTrigger must be used in a ComboboxRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      attr:tabindex="-1"
+      attr:aria-controls=content_id
+      attr:aria-expanded=open
+      attr:data-state=move || if open.get() { "open" } else { "closed" }
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:disabled=disabled
+      element=html::button
+      // keeps focus in the input
+      on:pointerdown=move |ev: PointerEvent| ev.prevent_default()
+      on:click=move |_: MouseEvent| {
+        on_open_change.call(!open.get_untracked());
+
+        if let Some(input) = input_ref.get_untracked() {
+          _ = input.focus();
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "▼".into_view())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxChips<F, IV>(children: F) -> impl IntoView
+where
+  F: Fn(String) -> IV + 'static,
+  IV: IntoView + 'static,
+{
+  let ComboboxContextValue { value, .. } =
+    use_context().expect("ComboboxChips must be used in a ComboboxRoot component");
+
+  view! {
+    <For
+      each=move || value.get()
+      key=|value| value.clone()
+      children=children
+    />
+  }
+}
+
+#[component]
+pub fn ComboboxChip(
+  #[prop(into)] value: MaybeSignal<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    labels, disabled, ..
+  } = use_context().expect("ComboboxChip must be used in a ComboboxRoot component");
+
+  let value = Signal::derive(move || value.get());
+
+  provide_context(ComboboxChipContextValue { value });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:data-disabled=move || disabled.get().then_some("")
+      element=html::span
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {move || children.with_value(|children| match children {
+        Some(children) => children().into_view(),
+        None => get_label(labels, &value.get()).into_view(),
+      })}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxChipRemove(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    labels,
+    disabled,
+    on_item_remove,
+    ..
+  } = use_context().expect("ComboboxChipRemove must be used in a ComboboxRoot component");
+  let ComboboxChipContextValue { value } =
+    use_context().expect("ComboboxChipRemove must be used in a ComboboxChip component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      attr:tabindex="-1"
+      attr:aria-label=move || format!("Remove {}", get_label(labels, &value.get()))
+      attr:disabled=disabled
+      element=html::button
+      // keeps focus in the input
+      on:pointerdown=move |ev: PointerEvent| ev.prevent_default()
+      on:click=move |_: MouseEvent| on_item_remove.call(value.get_untracked())
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "×".into_view())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+#[component]
+pub fn ComboboxContent(
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue { open, .. } =
+    use_context().expect("ComboboxContent must be used in a ComboboxRoot component");
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show
+      when=move || open.get()
+      fallback=move || view! {
+        <ComboboxContentFragment>
+          {children.with_value(|children| children())}
+        </ComboboxContentFragment>
+      }
+    >
+      <ComboboxContentImpl
+        {..attrs.get_value()}
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </ComboboxContentImpl>
+    </Show>
+  }
+}
+
+// the items are still rendered while the content is closed, just outside of the document, so the
+// selected items' text is available for the input and the chips before the content is first opened
+#[component]
+fn ComboboxContentFragment(children: ChildrenFn) -> impl IntoView {
+  let CollectionContextValue { collection_ref, .. } =
+    use_context::<CollectionContextValue<ComboboxItemData, AnyElement>>()
+      .expect("ComboboxContentFragment must be used in a ComboboxRoot component");
+
+  let (fragment, set_fragment) = create_signal::<Option<web_sys::Element>>(None);
+  let fragment_ref = NodeRef::<AnyElement>::new();
+
+  Effect::new(move |_| {
+    set_fragment.set(document().create_element("div").ok());
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = fragment_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    {move || fragment.get().map(|fragment| view! {
+      <Portal container=fragment>
+        <Primitive
+          element=html::div
+          node_ref=fragment_ref
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </Portal>
+    })}
+  }
+}
+
+#[component]
+fn ComboboxContentImpl(
+  #[prop(into)] side: MaybeSignal<Side>,
+  #[prop(into)] side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  #[prop(into)] align_offset: MaybeSignal<f64>,
+  #[prop(into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(into)] collision_padding: MaybeSignal<f64>,
+  #[prop(into)] sticky: MaybeSignal<Sticky>,
+  #[prop(into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(into)] on_pointer_down_outside: Callback<CustomEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    input_ref,
+    trigger_ref,
+    anchor_ref,
+    content_id,
+    value,
+    multiple,
+    highlighted,
+    open,
+    allow_custom_value,
+    loading,
+    direction,
+    on_open_change,
+    ..
+  } = use_context().expect("ComboboxContentImpl must be used in a ComboboxRoot component");
+  let CollectionContextValue { collection_ref, .. } =
+    use_context::<CollectionContextValue<ComboboxItemData, AnyElement>>()
+      .expect("ComboboxContentImpl must be used in a ComboboxRoot component");
+
+  let get_items = use_collection_context::<ComboboxItemData, AnyElement>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-combobox-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-combobox-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-combobox-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-combobox-anchor-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-combobox-anchor-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  // the highlight follows the filtered items, free text comboboxes don't highlight anything until
+  // the user navigates so enter keeps the typed text
+  Effect::new(move |_| {
+    let items = get_items
+      .get()
+      .into_iter()
+      .filter(|(_, item)| !item.disabled.get_untracked())
+      .map(|(_, item)| item.value)
+      .collect::<Vec<_>>();
+
+    if highlighted
+      .get_untracked()
+      .is_some_and(|highlighted| items.contains(&highlighted))
+    {
+      return;
+    }
+
+    highlighted.set(if allow_custom_value.get_untracked() {
+      None
+    } else {
+      value.with_untracked(|value| {
+        items
+          .iter()
+          .find(|item| value.contains(item))
+          .or(items.first())
+          .cloned()
+      })
+    });
+  });
+
+  on_cleanup(move || {
+    _ = highlighted.try_set(None);
+  });
+
+  let is_anchor_event = move |ev: &CustomEvent| {
+    let Some(target) = get_original_event::<Event>(ev)
+      .and_then(|original_event| original_event.target())
+      .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+    else {
+      return false;
+    };
+
+    [input_ref, trigger_ref, anchor_ref]
+      .into_iter()
+      .any(|node_ref| {
+        node_ref
+          .get_untracked()
+          .is_some_and(|node| node.contains(Some(&target)))
+      })
+  };
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperContent
+      as_child=true
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      node_ref=node_ref
+    >
+      <DismissableLayer
+        as_child=true
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_interact_outside=move |ev: CustomEvent| {
+          // the input and trigger open and close the content themselves
+          if is_anchor_event(&ev) {
+            ev.prevent_default();
+          }
+        }
+        on_dismiss=move |_| on_open_change.call(false)
+      >
+        <Primitive
+          {..attrs.get_value()}
+          attr:role="listbox"
+          attr:id=content_id
+          attr:aria-multiselectable=multiple.then_some("true")
+          attr:aria-busy=move || loading.get().then_some("true")
+          attr:data-loading=move || loading.get().then_some("")
+          attr:data-state=move || if open.get() { "open" } else { "closed" }
+          attr:dir=move || direction.get().to_string()
+          element=html::div
+          // keeps focus in the input
+          on:pointerdown=move |ev: PointerEvent| ev.prevent_default()
+          node_ref=node_ref
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </DismissableLayer>
+    </PopperContent>
+  }
+}
+
+#[component]
+pub fn ComboboxItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    search,
+    labels,
+    filter,
+    ..
+  } = use_context().expect("ComboboxItem must be used in a ComboboxRoot component");
+
+  let value = Signal::derive(move || value.get());
+  let text_value = Signal::derive(move || text_value.get().unwrap_or_else(|| value.get()));
+
+  // labels outlive their items so chips and the input keep their text while items are filtered out
+  Effect::new(move |_| {
+    let (value, text_value) = (value.get(), text_value.get());
+
+    if labels.with_untracked(|labels| labels.get(&value) != Some(&text_value)) {
+      labels.update(|labels| {
+        labels.insert(value, text_value);
+      });
+    }
+  });
+
+  let is_visible =
+    Signal::derive(move || filter.with(|filter| filter.matches(&text_value.get(), &search.get())));
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_visible.get()>
+      <ComboboxItemImpl
+        {..attrs.get_value()}
+        value=value
+        disabled=Signal::derive(move || disabled.get())
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </ComboboxItemImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn ComboboxItemImpl(
+  value: Signal<String>,
+  disabled: Signal<bool>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    value: selected_value,
+    highlighted,
+    open,
+    on_item_select,
+    ..
+  } = use_context().expect("ComboboxItem must be used in a ComboboxRoot component");
+
+  let id = create_id();
+  let is_selected = Signal::derive(move || {
+    selected_value.with(|selected_value| selected_value.contains(&value.get()))
+  });
+  let is_highlighted = Signal::derive(move || highlighted.get() == Some(value.get()));
+
+  use_collection_item_ref::<AnyElement, ComboboxItemData>(
+    node_ref,
+    ComboboxItemData {
+      value: value.get_untracked(),
+      id,
+      disabled,
+    },
+  );
+
+  provide_context(ComboboxItemContextValue { is_selected });
+
+  // focus stays in the input, so the highlighted item has to be scrolled into view manually
+  Effect::new(move |_| {
+    if !is_highlighted.get() || !open.get_untracked() {
+      return;
+    }
+
+    if let Some(node) = node_ref.get_untracked() {
+      let options = ScrollIntoViewOptions::new();
+      options.set_block(ScrollLogicalPosition::Nearest);
+
+      node.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=id
+      attr:role="option"
+      attr:aria-selected=move || is_selected.get().to_string()
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:data-highlighted=move || is_highlighted.get().then_some("")
+      attr:data-state=move || if is_selected.get() { "checked" } else { "unchecked" }
+      attr:data-disabled=move || disabled.get().then_some("")
+      element=html::div
+      on:pointermove=move |_: PointerEvent| {
+        if !disabled.get_untracked() && !is_highlighted.get_untracked() {
+          highlighted.set(Some(value.get_untracked()));
+        }
+      }
+      on:click=move |_: MouseEvent| {
+        if !disabled.get_untracked() {
+          on_item_select.call(value.get_untracked());
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxItemIndicator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxItemContextValue { is_selected } =
+    use_context().expect("ComboboxItemIndicator must be used in a ComboboxItem component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_selected.get()>
+      <Primitive
+        {..attrs.get_value()}
+        attr:aria-hidden="true"
+        element=html::span
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxEmpty(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue { loading, .. } =
+    use_context().expect("ComboboxEmpty must be used in a ComboboxRoot component");
+
+  let get_items = use_collection_context::<ComboboxItemData, AnyElement>();
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || !loading.get() && get_items.with(|items| items.is_empty())>
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="presentation"
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxLoading(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue { loading, .. } =
+    use_context().expect("ComboboxLoading must be used in a ComboboxRoot component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || loading.get()>
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="presentation"
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let id = create_id();
+
+  provide_context(ComboboxGroupContextValue { id });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      attr:aria-labelledby=id
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let group = use_context::<ComboboxGroupContextValue>();
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=move || group.as_ref().map(|group| group.id.get())
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+// every selected value is submitted under the combobox's name, and the first input carries the
+// required constraint so an empty combobox fails validation like a native control
+#[component]
+fn BubbleInputs(
+  value: Signal<Vec<String>>,
+  input_ref: NodeRef<AnyElement>,
+  name: Signal<Option<String>>,
+  form: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
+) -> impl IntoView {
+  move || {
+    let values = value.get();
+    let is_empty = values.is_empty();
+
+    let values = if is_empty {
+      vec![String::new()]
+    } else {
+      values
+    };
+
+    values
+      .into_iter()
+      .enumerate()
+      .map(|(index, value)| {
+        view! {
+          <VisuallyHidden as_child=true>
+            <input
+              aria-hidden="true"
+              tabindex=(-1).into_attribute()
+              name=name
+              form=form
+              value=value.clone()
+              disabled=disabled
+              required=move || index == 0 && is_empty && required.get()
+              // browsers focus the first invalid control when a form fails validation
+              on:focus=move |_| {
+                if let Some(input) = input_ref.get_untracked() {
+                  _ = input.focus();
+                }
+              }
+            />
+          </VisuallyHidden>
+        }
+      })
+      .collect_view()
+  }
+}
+
+#[test]
+fn filters_match_text_values_case_insensitively() {
+  assert!(ComboboxFilter::Contains.matches("Acme Corporation", " corp"));
+  assert!(!ComboboxFilter::Contains.matches("Acme Corporation", "globex"));
+  assert!(ComboboxFilter::StartsWith.matches("Acme Corporation", "ACME"));
+  assert!(!ComboboxFilter::StartsWith.matches("Acme Corporation", "corp"));
+  assert!(ComboboxFilter::Manual.matches("Acme Corporation", "globex"));
+  assert!(ComboboxFilter::custom(|text, search| text.len() > search.len()).matches("Acme", "ac"));
+  assert!(ComboboxFilter::custom(|text, search| text.starts_with(search)).matches("Acme", "Ac"));
+  assert!(!ComboboxFilter::custom(|text, search| text.starts_with(search)).matches("Acme", " ac"));
+}
+
+#[test]
+fn highlight_wraps_around_the_items() {
+  assert_eq!(get_next_index(None, 3, true), 0);
+  assert_eq!(get_next_index(None, 3, false), 2);
+  assert_eq!(get_next_index(Some(2), 3, true), 0);
+  assert_eq!(get_next_index(Some(0), 3, false), 2);
+  assert_eq!(get_next_index(Some(1), 3, true), 2);
+}
+
+#[test]
+fn typed_text_matches_item_labels() {
+  let labels = HashMap::from([
+    (String::from("sku-1"), String::from("Blue Widget")),
+    (String::from("sku-2"), String::from("Red Widget")),
+  ]);
+
+  assert_eq!(
+    find_value_by_label(&labels, " blue widget "),
+    Some(String::from("sku-1"))
+  );
+  assert_eq!(find_value_by_label(&labels, "widget"), None);
+}
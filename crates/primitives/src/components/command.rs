@@ -0,0 +1,936 @@
+use std::collections::HashMap;
+
+use leptos::{ev::keydown, html::AnyElement, *};
+use leptos_use::{use_document, use_event_listener};
+use wasm_bindgen::JsValue;
+use web_sys::{
+  js_sys::{JsString, Reflect},
+  Event, KeyboardEvent, MouseEvent, PointerEvent, ScrollIntoViewOptions, ScrollLogicalPosition,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dialog::{DialogContent, DialogOverlay, DialogPortal, DialogRoot},
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+};
+
+type CommandFilter = Callback<(String, String, Vec<String>), f64>;
+
+const SCORE_CONTINUE_MATCH: f64 = 1.0;
+const SCORE_SPACE_WORD_JUMP: f64 = 0.9;
+const SCORE_NON_SPACE_WORD_JUMP: f64 = 0.8;
+const SCORE_CHARACTER_JUMP: f64 = 0.17;
+const PENALTY_SKIPPED: f64 = 0.999;
+const PENALTY_CASE_MISMATCH: f64 = 0.9999;
+const PENALTY_NOT_COMPLETE: f64 = 0.99;
+
+fn is_space(char: char) -> bool {
+  char.is_whitespace() || char == '-'
+}
+
+fn is_word_separator(char: char) -> bool {
+  matches!(char, '_' | '/' | '.' | ',' | ':' | '+' | '(' | '[' | '#')
+}
+
+// scores every way the search's characters can be found in order within the value, preferring
+// consecutive characters and matches at the start of words
+fn score_from(
+  value: &[char],
+  search: &[char],
+  value_index: usize,
+  search_index: usize,
+  memo: &mut HashMap<(usize, usize), f64>,
+) -> f64 {
+  if search_index == search.len() {
+    return if value_index == value.len() {
+      SCORE_CONTINUE_MATCH
+    } else {
+      PENALTY_NOT_COMPLETE
+    };
+  }
+
+  if let Some(score) = memo.get(&(value_index, search_index)) {
+    return *score;
+  }
+
+  let search_char = search[search_index];
+  let mut high_score = 0.0;
+
+  for index in value_index..value.len() {
+    if !value[index].to_lowercase().eq(search_char.to_lowercase()) {
+      continue;
+    }
+
+    let mut score = score_from(value, search, index + 1, search_index + 1, memo);
+
+    if score <= high_score {
+      continue;
+    }
+
+    let skipped = (index - value_index) as i32;
+
+    if index == value_index {
+      score *= SCORE_CONTINUE_MATCH;
+    } else if is_space(value[index - 1]) {
+      score *= SCORE_SPACE_WORD_JUMP * PENALTY_SKIPPED.powi(skipped);
+    } else if is_word_separator(value[index - 1]) {
+      score *= SCORE_NON_SPACE_WORD_JUMP * PENALTY_SKIPPED.powi(skipped);
+    } else {
+      score *= SCORE_CHARACTER_JUMP;
+
+      if value_index > 0 {
+        score *= PENALTY_SKIPPED.powi(skipped);
+      }
+    }
+
+    if value[index] != search_char {
+      score *= PENALTY_CASE_MISMATCH;
+    }
+
+    if score > high_score {
+      high_score = score;
+    }
+  }
+
+  memo.insert((value_index, search_index), high_score);
+
+  high_score
+}
+
+pub fn command_score(value: &str, search: &str, keywords: &[String]) -> f64 {
+  let search = search.trim().chars().collect::<Vec<_>>();
+
+  if search.is_empty() {
+    return 1.0;
+  }
+
+  let value = std::iter::once(value)
+    .chain(keywords.iter().map(String::as_str))
+    .collect::<Vec<_>>()
+    .join(" ")
+    .chars()
+    .collect::<Vec<_>>();
+
+  score_from(&value, &search, 0, 0, &mut HashMap::new())
+}
+
+// orders the matching items by score, items that score the same keep their order in the document
+fn rank_items(scores: Vec<(String, f64)>) -> Vec<String> {
+  let mut scores = scores
+    .into_iter()
+    .filter(|(_, score)| *score > 0.0)
+    .collect::<Vec<_>>();
+
+  scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+  scores.into_iter().map(|(value, _)| value).collect()
+}
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+struct CommandItemData {
+  value: String,
+  id: Signal<String>,
+  group_id: Option<Signal<String>>,
+  disabled: Signal<bool>,
+  score: Signal<OrderedScore>,
+}
+
+impl Ord for CommandItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for CommandItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+// scores are only ever compared by the collection's derives, never sorted through them
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct OrderedScore(f64);
+
+impl Eq for OrderedScore {}
+
+#[derive(Clone)]
+struct CommandContextValue {
+  input_id: Signal<String>,
+  label_id: Signal<String>,
+  list_id: Signal<String>,
+  label: Signal<Option<String>>,
+  search: RwSignal<String>,
+  value: Signal<Option<String>>,
+  should_filter: Signal<bool>,
+  filter: MaybeProp<CommandFilter>,
+  ranks: Memo<HashMap<String, usize>>,
+  items: Memo<Vec<CommandItemData>>,
+  on_value_change: Callback<String>,
+}
+
+#[derive(Clone)]
+struct CommandGroupContextValue {
+  id: Signal<String>,
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn CommandRoot(
+  #[prop(optional, into)] label: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(default=true.into(), into)] should_filter: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] filter: MaybeProp<CommandFilter>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let collection_ref = NodeRef::<AnyElement>::new();
+
+  provide_context(CollectionContextValue::<CommandItemData, AnyElement> {
+    collection_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let get_items = use_collection_context::<CommandItemData, AnyElement>();
+
+  let search = RwSignal::new(String::new());
+  let items = create_memo(move |_| {
+    get_items
+      .get()
+      .into_iter()
+      .map(|(_, item)| item)
+      .collect::<Vec<_>>()
+  });
+
+  let ordered_items = create_memo(move |_| {
+    rank_items(items.with(|items| {
+      items
+        .iter()
+        .map(|item| (item.value.clone(), item.score.get().0))
+        .collect()
+    }))
+  });
+
+  let ranks = create_memo(move |_| {
+    ordered_items.with(|ordered_items| {
+      ordered_items
+        .iter()
+        .enumerate()
+        .map(|(rank, value)| (value.clone(), rank))
+        .collect::<HashMap<_, _>>()
+    })
+  });
+
+  let on_value_change = Callback::new(move |value| set_value.set(value));
+
+  // the first item is highlighted as the search changes, or once the highlighted item is gone
+  Effect::new(move |previous_search: Option<String>| {
+    let search = search.get();
+
+    ordered_items.with(|ordered_items| {
+      let is_value_visible = value
+        .get_untracked()
+        .is_some_and(|value| ordered_items.contains(&value));
+
+      if previous_search.as_ref() != Some(&search) || !is_value_visible {
+        let first_enabled = ordered_items.iter().find(|value| {
+          items.with_untracked(|items| {
+            items
+              .iter()
+              .any(|item| item.value == **value && !item.disabled.get_untracked())
+          })
+        });
+
+        if let Some(first_enabled) = first_enabled {
+          on_value_change.call(first_enabled.clone());
+        }
+      }
+    });
+
+    search
+  });
+
+  let enabled_items = move || {
+    let mut enabled_items = items.with_untracked(|items| {
+      items
+        .iter()
+        .filter(|item| item.score.get_untracked().0 > 0.0 && !item.disabled.get_untracked())
+        .cloned()
+        .collect::<Vec<_>>()
+    });
+
+    ranks.with_untracked(|ranks| {
+      enabled_items.sort_by_key(|item| ranks.get(&item.value).copied().unwrap_or(usize::MAX))
+    });
+
+    enabled_items
+  };
+
+  let move_highlight = move |offset: isize| {
+    let enabled_items = enabled_items();
+
+    if enabled_items.is_empty() {
+      return;
+    }
+
+    let len = enabled_items.len() as isize;
+    let current_index = value
+      .get_untracked()
+      .and_then(|value| enabled_items.iter().position(|item| item.value == value))
+      .map(|index| index as isize);
+
+    let next_index = match current_index {
+      Some(index) if should_loop.get_untracked() => (index + offset).rem_euclid(len),
+      Some(index) => (index + offset).clamp(0, len - 1),
+      None if offset > 0 => 0,
+      None => len - 1,
+    };
+
+    on_value_change.call(enabled_items[next_index as usize].value.clone());
+  };
+
+  let move_to_edge = move |last: bool| {
+    let enabled_items = enabled_items();
+    let item = if last {
+      enabled_items.last()
+    } else {
+      enabled_items.first()
+    };
+
+    if let Some(item) = item {
+      on_value_change.call(item.value.clone());
+    }
+  };
+
+  let input_id = create_id();
+  let label_id = create_id();
+  let label = Signal::derive(move || label.get());
+
+  provide_context(CommandContextValue {
+    input_id,
+    label_id,
+    list_id: create_id(),
+    label,
+    search,
+    value,
+    should_filter: Signal::derive(move || should_filter.get()),
+    filter,
+    ranks,
+    items,
+    on_value_change,
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      on:keydown=move |ev: KeyboardEvent| {
+        // keys that finish composing text with an input method aren't for navigating
+        if ev.is_composing() || ev.key_code() == 229 {
+          return;
+        }
+
+        let is_vim_key = |key: &str| ev.ctrl_key() && ev.key() == key;
+
+        match ev.key().as_str() {
+          "ArrowDown" => move_highlight(1),
+          "ArrowUp" => move_highlight(-1),
+          "n" | "j" if is_vim_key("n") || is_vim_key("j") => move_highlight(1),
+          "p" | "k" if is_vim_key("p") || is_vim_key("k") => move_highlight(-1),
+          "Home" => move_to_edge(false),
+          "End" => move_to_edge(true),
+          "Enter" => {
+            let Some(selected_value) = value.get_untracked() else {
+              return;
+            };
+
+            // items handle their own selection, the same as when they're clicked
+            if let Some(node) = get_items
+              .get_untracked()
+              .into_iter()
+              .find(|(_, item)| item.value == selected_value && !item.disabled.get_untracked())
+              .and_then(|(node, _)| node.get_untracked())
+            {
+              node.click();
+            }
+          }
+          _ => return,
+        }
+
+        ev.prevent_default();
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      <VisuallyHidden as_child=true>
+        <label id=label_id for=input_id>
+          {move || label.get()}
+        </label>
+      </VisuallyHidden>
+
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandInput(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue {
+    input_id,
+    label_id,
+    list_id,
+    search,
+    value: selected_value,
+    items,
+    ..
+  } = use_context().expect("CommandInput must be used in a CommandRoot component");
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+
+  Effect::new(move |_| search.set(value.get()));
+
+  // the value is set as a property since the attribute only holds the input's initial value
+  Effect::new(move |_| {
+    let value = value.get();
+
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let current_value = Reflect::get(&node, &JsString::from("value"))
+      .ok()
+      .and_then(|value| value.as_string());
+
+    if current_value.as_deref() != Some(value.as_str()) {
+      _ = Reflect::set(&node, &JsString::from("value"), &JsValue::from_str(&value));
+    }
+  });
+
+  let active_descendant = Signal::derive(move || {
+    let selected_value = selected_value.get()?;
+
+    items.with(|items| {
+      items
+        .iter()
+        .find(|item| item.value == selected_value)
+        .map(|item| item.id.get())
+    })
+  });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=input_id
+      attr:type="text"
+      attr:role="combobox"
+      attr:autocomplete="off"
+      attr:autocorrect="off"
+      attr:spellcheck="false"
+      attr:aria-autocomplete="list"
+      attr:aria-expanded="true"
+      attr:aria-controls=list_id
+      attr:aria-labelledby=label_id
+      attr:aria-activedescendant=active_descendant
+      attr:value=move || value.get_untracked()
+      element=html::input
+      on:input=move |ev: Event| set_value.set(event_target_value(&ev))
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandList(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue { list_id, label, .. } =
+    use_context().expect("CommandList must be used in a CommandRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=list_id
+      attr:role="listbox"
+      attr:tabindex="-1"
+      attr:aria-label=label
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      // ranked items are reordered visually, their position in the document doesn't change
+      <div style="display: flex; flex-direction: column;">
+        {children()}
+      </div>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] keywords: MaybeSignal<Vec<String>>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue {
+    search,
+    should_filter,
+    filter,
+    ..
+  } = use_context().expect("CommandItem must be used in a CommandRoot component");
+  let group = use_context::<CommandGroupContextValue>();
+
+  let value = Signal::derive(move || value.get());
+  let force_mount = Signal::derive(move || {
+    force_mount.get() || group.as_ref().is_some_and(|group| group.force_mount.get())
+  });
+
+  let score = Signal::derive(move || {
+    let search = search.get();
+
+    if !should_filter.get() || search.trim().is_empty() {
+      return OrderedScore(1.0);
+    }
+
+    OrderedScore(match filter.get() {
+      Some(filter) => filter.call((value.get(), search, keywords.get())),
+      None => keywords.with(|keywords| command_score(&value.get(), &search, keywords)),
+    })
+  });
+
+  let is_matching = Signal::derive(move || score.get().0 > 0.0);
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || force_mount.get() || is_matching.get()>
+      <CommandItemImpl
+        {..attrs.get_value()}
+        value=value
+        disabled=Signal::derive(move || disabled.get())
+        score=score
+        on_select=on_select
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </CommandItemImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn CommandItemImpl(
+  value: Signal<String>,
+  disabled: Signal<bool>,
+  score: Signal<OrderedScore>,
+
+  on_select: Callback<String>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue {
+    value: selected_value,
+    ranks,
+    on_value_change,
+    ..
+  } = use_context().expect("CommandItem must be used in a CommandRoot component");
+  let group = use_context::<CommandGroupContextValue>();
+
+  let id = create_id();
+  let is_selected = Signal::derive(move || selected_value.get() == Some(value.get()));
+
+  use_collection_item_ref::<AnyElement, CommandItemData>(
+    node_ref,
+    CommandItemData {
+      value: value.get_untracked(),
+      id,
+      group_id: group.map(|group| group.id),
+      disabled,
+      score,
+    },
+  );
+
+  // focus stays in the input, so the selected item has to be scrolled into view manually
+  Effect::new(move |_| {
+    if !is_selected.get() {
+      return;
+    }
+
+    if let Some(node) = node_ref.get_untracked() {
+      let options = ScrollIntoViewOptions::new();
+      options.set_block(ScrollLogicalPosition::Nearest);
+
+      node.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=id
+      attr:role="option"
+      attr:aria-selected=move || is_selected.get().to_string()
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:data-selected=move || is_selected.get().then_some("")
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:data-value=value
+      attr:style=move || {
+        ranks.with(|ranks| ranks.get(&value.get()).map(|rank| format!("order: {rank};")))
+      }
+      element=html::div
+      on:pointermove=move |_: PointerEvent| {
+        if !disabled.get_untracked() && !is_selected.get_untracked() {
+          on_value_change.call(value.get_untracked());
+        }
+      }
+      on:click=move |_: MouseEvent| {
+        if !disabled.get_untracked() {
+          on_select.call(value.get_untracked());
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandGroup(
+  #[prop(optional, into)] heading: MaybeProp<String>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue { ranks, items, .. } =
+    use_context().expect("CommandGroup must be used in a CommandRoot component");
+
+  let id = create_id();
+  let heading_id = create_id();
+  let force_mount = Signal::derive(move || force_mount.get());
+
+  provide_context(CommandGroupContextValue { id, force_mount });
+
+  // groups are ranked by their best item and hidden while none of their items match
+  let rank = Signal::derive(move || {
+    items.with(|items| {
+      ranks.with(|ranks| {
+        items
+          .iter()
+          .filter(|item| {
+            item
+              .group_id
+              .is_some_and(|group_id| group_id.get() == id.get())
+          })
+          .filter_map(|item| ranks.get(&item.value).copied())
+          .min()
+      })
+    })
+  });
+
+  let heading = Signal::derive(move || heading.get());
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="presentation"
+      attr:hidden=move || (!force_mount.get() && rank.get().is_none()).then_some("")
+      attr:style=move || rank.get().map(|rank| format!("order: {rank};"))
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      <Show when=move || heading.get().is_some()>
+        <div aria-hidden="true" id=heading_id>
+          {move || heading.get()}
+        </div>
+      </Show>
+
+      <div
+        role="group"
+        aria-labelledby=move || heading.get().map(|_| heading_id.get())
+        style="display: flex; flex-direction: column;"
+      >
+        {children()}
+      </div>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandSeparator(
+  #[prop(optional, into)] always_render: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue { search, .. } =
+    use_context().expect("CommandSeparator must be used in a CommandRoot component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  // separators only make sense between groups in their original order, not between ranked items
+  view! {
+    <Show when=move || always_render.get() || search.with(|search| search.trim().is_empty())>
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="separator"
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn CommandEmpty(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue { ranks, .. } =
+    use_context().expect("CommandEmpty must be used in a CommandRoot component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || ranks.with(|ranks| ranks.is_empty())>
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="presentation"
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn CommandLoading(
+  #[prop(optional, into)] progress: MaybeProp<f64>,
+  #[prop(default="Loading...".into(), into)] label: MaybeSignal<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="progressbar"
+      attr:aria-valuenow=move || progress.get()
+      attr:aria-valuemin="0"
+      attr:aria-valuemax="100"
+      attr:aria-label=label
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      <div aria-hidden="true">
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </div>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandDialog(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] shortcut: MaybeProp<String>,
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] label: MaybeProp<String>,
+  #[prop(optional, into)] overlay_class: MaybeProp<String>,
+  #[prop(optional, into)] content_class: MaybeProp<String>,
+  #[prop(default=true.into(), into)] should_filter: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] filter: MaybeProp<CommandFilter>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+
+  // e.g. a shortcut of "k" toggles the palette with ctrl+k, or cmd+k on macOS
+  _ = use_event_listener(use_document(), keydown, move |ev: KeyboardEvent| {
+    let Some(shortcut) = shortcut.get_untracked() else {
+      return;
+    };
+
+    if (ev.ctrl_key() || ev.meta_key()) && ev.key().eq_ignore_ascii_case(&shortcut) {
+      ev.prevent_default();
+      set_open.set(!open.get_untracked());
+    }
+  });
+
+  let container = Signal::derive(move || container.get());
+  let label = Signal::derive(move || label.get());
+  let overlay_class = Signal::derive(move || overlay_class.get());
+  let content_class = Signal::derive(move || content_class.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <DialogRoot
+      open=open
+      on_open_change=move |open| set_open.set(open)
+    >
+      <DialogPortal container=container>
+        <DialogOverlay attr:class=overlay_class />
+        <DialogContent
+          attr:class=content_class
+          attr:aria-label=label
+        >
+          <CommandRoot
+            {..attrs.get_value()}
+            label=label
+            should_filter=should_filter
+            should_loop=should_loop
+            filter=filter
+            node_ref=node_ref
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+          </CommandRoot>
+        </DialogContent>
+      </DialogPortal>
+    </DialogRoot>
+  }
+}
+
+#[test]
+fn scores_exact_matches_above_prefixes_and_fuzzy_matches() {
+  let exact = command_score("Settings", "settings", &[]);
+  let prefix = command_score("Settings", "set", &[]);
+  let word_start = command_score("Open settings", "set", &[]);
+  let fuzzy = command_score("Reset theme", "stt", &[]);
+
+  assert!(exact > prefix);
+  assert!(prefix > word_start);
+  assert!(word_start > fuzzy);
+  assert!(fuzzy > 0.0);
+}
+
+#[test]
+fn scores_zero_when_the_search_is_not_contained_in_order() {
+  assert_eq!(command_score("Settings", "tes", &[]), 0.0);
+  assert_eq!(command_score("Settings", "xyz", &[]), 0.0);
+  assert_eq!(command_score("Settings", "", &[]), 1.0);
+}
+
+#[test]
+fn matches_keywords_and_ignores_case() {
+  let keywords = [String::from("preferences"), String::from("config")];
+
+  assert!(command_score("Settings", "config", &keywords) > 0.0);
+  assert!(command_score("Settings", "SET", &[]) < command_score("Settings", "Set", &[]));
+}
+
+#[test]
+fn ranks_matching_items_by_score_keeping_ties_in_order() {
+  let ranked = rank_items(vec![
+    (String::from("a"), 0.5),
+    (String::from("b"), 0.0),
+    (String::from("c"), 0.9),
+    (String::from("d"), 0.5),
+  ]);
+
+  assert_eq!(ranked, ["c", "a", "d"]);
+}
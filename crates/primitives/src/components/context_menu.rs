@@ -0,0 +1,659 @@
+use std::time::Duration;
+
+use leptos::{html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use web_sys::{CustomEvent, Event, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  direction::use_local_direction,
+  menu::{
+    MenuArrow, MenuCheckboxItem, MenuContent, MenuGroup, MenuItem, MenuItemIndicator, MenuLabel,
+    MenuPortal, MenuRadioGroup, MenuRadioItem, MenuRoot, MenuSeparator, MenuSub, MenuSubContent,
+    MenuSubTrigger, MenuVirtualAnchor,
+  },
+  popper::Rect,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  Align, Direction, Side, Sticky,
+};
+
+#[derive(Clone)]
+struct ContextMenuContextValue {
+  open: Signal<bool>,
+  modal: Signal<bool>,
+  on_open_change: Callback<bool>,
+}
+
+#[component]
+pub fn ContextMenuRoot(
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (open, set_open) = create_signal(false);
+
+  let on_open_change = Callback::new(move |value| {
+    set_open.set(value);
+    on_open_change.call(value);
+  });
+
+  provide_context(ContextMenuContextValue {
+    open: open.into(),
+    modal: Signal::derive(move || modal.get()),
+    on_open_change,
+  });
+
+  view! {
+    <MenuRoot
+      open=open
+      direction=direction
+      modal=modal
+      on_open_change=on_open_change
+    >
+      {children()}
+    </MenuRoot>
+  }
+}
+
+#[component]
+pub fn ContextMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ContextMenuContextValue {
+    open,
+    on_open_change,
+    ..
+  } = use_context().expect("ContextMenuTrigger must be used in a ContextMenuRoot component");
+
+  let (point, set_point) = create_signal(Rect::default());
+  let long_press_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let clear_long_press = move || {
+    if let Some(handle) = long_press_timer.get_value() {
+      handle.clear();
+      long_press_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = long_press_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  let handle_open = move |x: i32, y: i32| {
+    set_point.set(Rect {
+      x: x as f64,
+      y: y as f64,
+      width: 0.0,
+      height: 0.0,
+    });
+
+    on_open_change.call(true);
+  };
+
+  // clears the long press when the pointer moves, is released, or is cancelled on touch devices
+  let when_touch = move |ev: PointerEvent| {
+    if ev.pointer_type() != "mouse" {
+      clear_long_press();
+    }
+  };
+
+  Effect::new(move |_| {
+    if disabled.get() {
+      clear_long_press();
+    }
+  });
+
+  view! {
+    <MenuVirtualAnchor rect=point/>
+    <Primitive
+      {..attrs}
+      attr:data-state=move || if open.get() { "open" } else { "closed" }
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:style="-webkit-touch-callout: none;"
+      element=html::span
+      on:contextmenu=move |ev: MouseEvent| {
+        if disabled.get_untracked() {
+          return;
+        }
+
+        // long presses fire their own contextmenu event on some touch devices
+        clear_long_press();
+        handle_open(ev.client_x(), ev.client_y());
+        ev.prevent_default();
+      }
+      on:pointerdown=move |ev: PointerEvent| {
+        if disabled.get_untracked() || ev.pointer_type() == "mouse" {
+          return;
+        }
+
+        clear_long_press();
+
+        let (x, y) = (ev.client_x(), ev.client_y());
+
+        let Ok(handle) = set_timeout_with_handle(
+          move || {
+            long_press_timer.set_value(None);
+            handle_open(x, y);
+          },
+          Duration::from_millis(700),
+        ) else {
+          return;
+        };
+
+        long_press_timer.set_value(Some(handle));
+      }
+      on:pointermove=move |ev: PointerEvent| {
+        if !disabled.get_untracked() {
+          when_touch(ev);
+        }
+      }
+      on:pointercancel=move |ev: PointerEvent| {
+        if !disabled.get_untracked() {
+          when_touch(ev);
+        }
+      }
+      on:pointerup=move |ev: PointerEvent| {
+        if !disabled.get_untracked() {
+          when_touch(ev);
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ContextMenuPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <MenuPortal container=container force_mount=force_mount>
+      {children()}
+    </MenuPortal>
+  }
+}
+
+#[component]
+pub fn ContextMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ContextMenuContextValue { modal, .. } =
+    use_context().expect("ContextMenuContent must be used in a ContextMenuRoot component");
+
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-context-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  // the menu opens at the pointer, so it's placed to the bottom right of it
+  view! {
+    <MenuContent
+      {..attrs}
+      force_mount=force_mount
+      should_loop=should_loop
+      side=Side::Right
+      side_offset=2.0
+      align=Align::Start
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if !ev.default_prevented() && has_interacted_outside.get_value() {
+          ev.prevent_default();
+        }
+
+        has_interacted_outside.set_value(false);
+      }
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_interact_outside=move |ev: CustomEvent| {
+        on_interact_outside.call(ev.clone());
+
+        if !ev.default_prevented() && !modal.get_untracked() {
+          has_interacted_outside.set_value(true);
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn ContextMenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn ContextMenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn ContextMenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuCheckboxItem(
+  #[prop(default=CheckedState::Checked(false).into(), into)] checked: MaybeSignal<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn ContextMenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children()}
+      </MenuItemIndicator>
+    },
+    None => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn ContextMenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn ContextMenuArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </MenuArrow>
+    },
+    None => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn ContextMenuSub(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=move |value| set_open.set(value)
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn ContextMenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSubTrigger
+      disabled=disabled
+      text_value=text_value
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn ContextMenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-context-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  view! {
+    <MenuSubContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_interact_outside=on_interact_outside
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
@@ -0,0 +1,738 @@
+use std::fmt;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  js_sys::{Array, Intl::DateTimeFormat, Reflect},
+  Element, FocusEvent, HtmlElement, KeyboardEvent, MouseEvent,
+};
+
+use crate::{
+  calendar::{create_date_formatter, days_in_month, to_js_date, CalendarDate},
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{
+      create_controllable_signal, CreateControllableSignalProps, WriteControllableSignal,
+    },
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+};
+
+// the pieces date and time fields have in common, each segment is a number within a range
+pub(crate) trait SegmentValues: Copy + PartialEq + 'static {
+  type Segment: Copy + PartialEq + fmt::Display + 'static;
+
+  fn get(&self, segment: Self::Segment) -> Option<u32>;
+  fn set(&mut self, segment: Self::Segment, value: Option<u32>);
+  fn range(&self, segment: Self::Segment) -> (u32, u32);
+  fn format(segment: Self::Segment, value: Option<u32>) -> String;
+  fn label(segment: Self::Segment) -> &'static str;
+  fn large_step(segment: Self::Segment) -> i32;
+  fn from_part_type(kind: &str) -> Option<Self::Segment>;
+
+  fn is_numeric(_segment: Self::Segment) -> bool {
+    true
+  }
+
+  fn parse_key(_segment: Self::Segment, _key: &str) -> Option<u32> {
+    None
+  }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum FieldPart<T> {
+  Segment(T),
+  Literal(String),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, strum_macros::Display)]
+pub enum DateSegment {
+  #[strum(to_string = "year")]
+  Year,
+  #[strum(to_string = "month")]
+  Month,
+  #[strum(to_string = "day")]
+  Day,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+struct DateSegments {
+  year: Option<u32>,
+  month: Option<u32>,
+  day: Option<u32>,
+}
+
+impl DateSegments {
+  fn from_date(date: Option<CalendarDate>) -> Self {
+    date.map_or_else(Self::default, |date| Self {
+      year: Some(date.year as u32),
+      month: Some(date.month),
+      day: Some(date.day),
+    })
+  }
+
+  // a day that no longer fits the month is pulled back instead of invalidating the whole date
+  fn to_date(self) -> Option<CalendarDate> {
+    let year = self.year? as i32;
+    let month = self.month?;
+
+    CalendarDate::new(
+      year,
+      month,
+      self.day?.min(days_in_month(year, month.clamp(1, 12))),
+    )
+  }
+}
+
+impl SegmentValues for DateSegments {
+  type Segment = DateSegment;
+
+  fn get(&self, segment: DateSegment) -> Option<u32> {
+    match segment {
+      DateSegment::Year => self.year,
+      DateSegment::Month => self.month,
+      DateSegment::Day => self.day,
+    }
+  }
+
+  fn set(&mut self, segment: DateSegment, value: Option<u32>) {
+    match segment {
+      DateSegment::Year => self.year = value,
+      DateSegment::Month => self.month = value,
+      DateSegment::Day => self.day = value,
+    }
+  }
+
+  fn range(&self, segment: DateSegment) -> (u32, u32) {
+    match segment {
+      DateSegment::Year => (1, 9999),
+      DateSegment::Month => (1, 12),
+      DateSegment::Day => (
+        1,
+        self.month.map_or(31, |month| {
+          // without a year february is allowed to have its leap day
+          days_in_month(
+            self.year.map_or(2000, |year| year as i32),
+            month.clamp(1, 12),
+          )
+        }),
+      ),
+    }
+  }
+
+  fn format(segment: DateSegment, value: Option<u32>) -> String {
+    match (segment, value) {
+      (DateSegment::Year, Some(value)) => format!("{value:04}"),
+      (_, Some(value)) => format!("{value:02}"),
+      (DateSegment::Year, None) => "yyyy".into(),
+      (DateSegment::Month, None) => "mm".into(),
+      (DateSegment::Day, None) => "dd".into(),
+    }
+  }
+
+  fn label(segment: DateSegment) -> &'static str {
+    match segment {
+      DateSegment::Year => "year",
+      DateSegment::Month => "month",
+      DateSegment::Day => "day",
+    }
+  }
+
+  fn large_step(segment: DateSegment) -> i32 {
+    match segment {
+      DateSegment::Year => 5,
+      DateSegment::Month => 2,
+      DateSegment::Day => 7,
+    }
+  }
+
+  fn from_part_type(kind: &str) -> Option<DateSegment> {
+    match kind {
+      "year" => Some(DateSegment::Year),
+      "month" => Some(DateSegment::Month),
+      "day" => Some(DateSegment::Day),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Clone)]
+struct DateFieldContextValue {
+  segments: RwSignal<DateSegments>,
+  typing_segment: RwSignal<Option<DateSegment>>,
+  placeholder: Signal<DateSegments>,
+  locale: Signal<Option<String>>,
+  is_localized: Signal<bool>,
+  is_invalid: Signal<bool>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+}
+
+#[component]
+pub fn DateFieldRoot(
+  #[prop(optional, into)] value: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] default_value: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] placeholder: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] min: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] max: MaybeProp<CalendarDate>,
+  #[prop(default=(|_|false).into(), into)] is_date_disabled: Callback<CalendarDate, bool>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] form: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<CalendarDate>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (segments, typing_segment) = create_field_segments(
+    value,
+    set_value,
+    DateSegments::from_date(value.get_untracked()),
+    |_, date| DateSegments::from_date(Some(date)),
+    DateSegments::to_date,
+  );
+
+  let is_invalid = Signal::derive(move || {
+    value.get().is_some_and(|date| {
+      min.get().is_some_and(|min| date < min)
+        || max.get().is_some_and(|max| date > max)
+        || is_date_disabled.call(date)
+    })
+  });
+
+  let (is_localized, set_is_localized) = create_signal(false);
+
+  Effect::new(move |_| set_is_localized.set(true));
+
+  let disabled = Signal::derive(move || disabled.get());
+
+  provide_context(DateFieldContextValue {
+    segments,
+    typing_segment,
+    placeholder: Signal::derive(move || {
+      DateSegments::from_date(Some(
+        placeholder
+          .get()
+          .unwrap_or_else(CalendarDate::today)
+          .clamp_to(min.get(), max.get()),
+      ))
+    }),
+    locale: Signal::derive(move || locale.get()),
+    is_localized: is_localized.into(),
+    is_invalid,
+    disabled,
+    read_only: Signal::derive(move || read_only.get()),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      attr:aria-invalid=move || is_invalid.get().then_some("true")
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:data-invalid=move || is_invalid.get().then_some("")
+      attr:data-disabled=move || disabled.get().then_some("")
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+
+    <BubbleInput
+      value=Signal::derive(move || value.get().map(|date| date.to_string()))
+      field_ref=node_ref
+      name=Signal::derive(move || name.get())
+      form=Signal::derive(move || form.get())
+      disabled=disabled
+      required=Signal::derive(move || required.get())
+    />
+  }
+}
+
+#[component]
+pub fn DateFieldInput(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DateFieldContextValue {
+    segments,
+    typing_segment,
+    placeholder,
+    locale,
+    is_localized,
+    is_invalid,
+    disabled,
+    read_only,
+  } = use_context().expect("DateFieldInput must be used in a DateFieldRoot component");
+
+  let parts = Signal::derive(move || {
+    let localized_parts = is_localized
+      .get()
+      .then(|| {
+        let formatter = create_date_formatter(
+          locale.get().as_deref(),
+          &[
+            ("year", "numeric"),
+            ("month", "2-digit"),
+            ("day", "2-digit"),
+          ],
+        )?;
+
+        get_localized_parts::<DateSegments>(&formatter)
+      })
+      .flatten();
+
+    localized_parts.unwrap_or_else(|| {
+      vec![
+        FieldPart::Segment(DateSegment::Month),
+        FieldPart::Literal("/".into()),
+        FieldPart::Segment(DateSegment::Day),
+        FieldPart::Literal("/".into()),
+        FieldPart::Segment(DateSegment::Year),
+      ]
+    })
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:data-invalid=move || is_invalid.get().then_some("")
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:style="white-space: nowrap;"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {render_segments(segments, typing_segment, placeholder, parts, disabled, read_only, node_ref)}
+    </Primitive>
+  }
+}
+
+// the value only changes once every segment is filled in and none is still being typed into, so
+// partially typed values like the first digits of a year stay local
+pub(crate) fn create_field_segments<T, S>(
+  value: Signal<Option<T>>,
+  set_value: WriteControllableSignal<T>,
+  initial_segments: S,
+  from_value: impl Fn(S, T) -> S + 'static,
+  to_value: impl Fn(S) -> Option<T> + 'static,
+) -> (RwSignal<S>, RwSignal<Option<S::Segment>>)
+where
+  T: Clone + PartialEq + 'static,
+  S: SegmentValues,
+{
+  let segments = RwSignal::new(initial_segments);
+  let typing_segment = RwSignal::new(None::<S::Segment>);
+  let to_value = StoredValue::new(to_value);
+
+  Effect::new(move |_| {
+    let Some(value) = value.get() else {
+      return;
+    };
+
+    let current_segments = segments.get_untracked();
+
+    if to_value
+      .with_value(|to_value| to_value(current_segments))
+      .as_ref()
+      != Some(&value)
+    {
+      segments.set(from_value(current_segments, value));
+    }
+  });
+
+  Effect::new(move |_| {
+    let next_value = to_value.with_value(|to_value| to_value(segments.get()));
+
+    if typing_segment.with(Option::is_some) {
+      return;
+    }
+
+    if next_value == value.get_untracked() {
+      return;
+    }
+
+    match next_value {
+      Some(next_value) => set_value.set(next_value),
+      None => set_value.update(|value| *value = None),
+    }
+  });
+
+  (segments, typing_segment)
+}
+
+pub(crate) fn get_localized_parts<S: SegmentValues>(
+  formatter: &DateTimeFormat,
+) -> Option<Vec<FieldPart<S::Segment>>> {
+  let parts = formatter
+    .format_to_parts(&to_js_date(CalendarDate::new(2000, 1, 1)?))
+    .iter()
+    .filter_map(|part| {
+      let get = |key| {
+        Reflect::get(&part, &JsValue::from_str(key))
+          .ok()?
+          .as_string()
+      };
+
+      Some((get("type")?, get("value")?))
+    })
+    .collect::<Vec<_>>();
+
+  Some(get_parts::<S>(&parts))
+}
+
+fn get_parts<S: SegmentValues>(parts: &[(String, String)]) -> Vec<FieldPart<S::Segment>> {
+  parts
+    .iter()
+    .filter_map(|(kind, value)| match kind.as_str() {
+      "literal" => Some(FieldPart::Literal(value.clone())),
+      kind => S::from_part_type(kind).map(FieldPart::Segment),
+    })
+    .collect()
+}
+
+pub(crate) fn render_segments<S: SegmentValues>(
+  segments: RwSignal<S>,
+  typing_segment: RwSignal<Option<S::Segment>>,
+  placeholder: Signal<S>,
+  parts: Signal<Vec<FieldPart<S::Segment>>>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+  container_ref: NodeRef<AnyElement>,
+) -> impl IntoView {
+  // digits only accumulate while the same segment keeps focus
+  let focus_segment = move |current: &Element, offset: i32| {
+    let Some(container) = container_ref.get_untracked() else {
+      return;
+    };
+    let Ok(nodes) = container.query_selector_all("[data-segment]") else {
+      return;
+    };
+
+    let nodes = Array::from(&nodes);
+    let index = nodes.index_of(current, 0) + offset;
+
+    if let Ok(node) = nodes.get(index as u32).dyn_into::<HtmlElement>() {
+      _ = node.focus();
+    }
+  };
+
+  move || {
+    parts
+      .get()
+      .into_iter()
+      .map(|part| match part {
+        FieldPart::Literal(literal) => view! {
+          <span aria-hidden="true" data-literal="">
+            {literal}
+          </span>
+        }
+        .into_view(),
+        FieldPart::Segment(segment) => {
+          let value = Signal::derive(move || segments.get().get(segment));
+          let range = Signal::derive(move || segments.get().range(segment));
+
+          // the segment is marked as typed into before it changes, so an incomplete value isn't
+          // committed in between
+          let fill = move |current: &Element, next: u32, should_advance: bool| {
+            typing_segment.set(Some(segment));
+            segments.update(|segments| segments.set(segment, Some(next)));
+
+            if should_advance {
+              typing_segment.set(None);
+              focus_segment(current, 1);
+            }
+          };
+
+          view! {
+            <span
+              role="spinbutton"
+              tabindex=move || if disabled.get() { "-1" } else { "0" }
+              inputmode=S::is_numeric(segment).then_some("numeric")
+              aria-label=S::label(segment)
+              aria-valuenow=move || value.get().map(|value| value.to_string())
+              aria-valuemin=move || range.get().0.to_string()
+              aria-valuemax=move || range.get().1.to_string()
+              aria-valuetext=move || {
+                value.get().map_or("Empty".into(), |value| S::format(segment, Some(value)))
+              }
+              aria-disabled=move || disabled.get().then_some("true")
+              aria-readonly=move || read_only.get().then_some("true")
+              data-segment=segment.to_string()
+              data-placeholder=move || value.get().is_none().then_some("")
+              on:focus=move |_: FocusEvent| typing_segment.set(None)
+              on:blur=move |_: FocusEvent| typing_segment.set(None)
+              on:mousedown=move |ev: MouseEvent| {
+                if disabled.get_untracked() {
+                  ev.prevent_default();
+                }
+              }
+              on:keydown=move |ev: KeyboardEvent| {
+                let Some(current) = ev
+                  .current_target()
+                  .and_then(|target| target.dyn_into::<Element>().ok())
+                else {
+                  return;
+                };
+
+                match ev.key().as_str() {
+                  "ArrowLeft" => {
+                    ev.prevent_default();
+                    focus_segment(&current, -1);
+                  }
+                  "ArrowRight" => {
+                    ev.prevent_default();
+                    focus_segment(&current, 1);
+                  }
+                  _ if disabled.get_untracked() || read_only.get_untracked() => {}
+                  key @ ("ArrowUp" | "ArrowDown" | "PageUp" | "PageDown" | "Home" | "End") => {
+                    ev.prevent_default();
+                    typing_segment.set(None);
+
+                    let (min, max) = range.get_untracked();
+                    let next = match key {
+                      "Home" => min,
+                      "End" => max,
+                      _ => step_segment(
+                        value.get_untracked(),
+                        placeholder.get_untracked().get(segment).unwrap_or(min),
+                        match key {
+                          "ArrowUp" => 1,
+                          "ArrowDown" => -1,
+                          "PageUp" => S::large_step(segment),
+                          _ => -S::large_step(segment),
+                        },
+                        min,
+                        max,
+                      ),
+                    };
+
+                    segments.update(|segments| segments.set(segment, Some(next)));
+                  }
+                  key @ ("Backspace" | "Delete") => {
+                    ev.prevent_default();
+                    typing_segment.set(None);
+
+                    if value.get_untracked().is_some() {
+                      segments.update(|segments| segments.set(segment, None));
+                    } else if key == "Backspace" {
+                      focus_segment(&current, -1);
+                    }
+                  }
+                  key if !S::is_numeric(segment) => {
+                    if let Some(next) = S::parse_key(segment, key) {
+                      ev.prevent_default();
+                      fill(&current, next, true);
+                    }
+                  }
+                  key => {
+                    let Some(digit) = key.parse::<u32>().ok().filter(|_| key.len() == 1) else {
+                      return;
+                    };
+
+                    ev.prevent_default();
+
+                    let current_value = (typing_segment.get_untracked() == Some(segment))
+                      .then(|| value.get_untracked())
+                      .flatten();
+                    let (next, should_advance) =
+                      type_segment(current_value, digit, range.get_untracked().1);
+
+                    fill(&current, next, should_advance);
+                  }
+                }
+              }
+            >
+              {move || S::format(segment, value.get())}
+            </span>
+          }
+          .into_view()
+        }
+      })
+      .collect_view()
+  }
+}
+
+// keeps fields working in native forms, what they render for editing isn't submitted itself
+#[component]
+pub(crate) fn BubbleInput(
+  value: Signal<Option<String>>,
+  field_ref: NodeRef<AnyElement>,
+  name: Signal<Option<String>>,
+  form: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
+) -> impl IntoView {
+  let (is_form_control, set_is_form_control) = create_signal(true);
+
+  Effect::new(move |_| {
+    set_is_form_control.set(if let Some(field) = field_ref.get() {
+      form.get().is_some() || field.closest("form").ok().flatten().is_some()
+    } else {
+      true
+    });
+  });
+
+  view! {
+    <Show when=move || is_form_control.get()>
+      <VisuallyHidden as_child=true>
+        <input
+          aria-hidden="true"
+          tabindex=(-1).into_attribute()
+          name=name
+          form=form
+          value=move || value.get().unwrap_or_default()
+          disabled=disabled
+          required=required
+          // browsers focus the first invalid control when a form fails validation, fields without
+          // segments take the focus themselves
+          on:focus=move |_| {
+            let Some(field) = field_ref.get_untracked() else {
+              return;
+            };
+
+            let segment = field
+              .query_selector("[data-segment]")
+              .ok()
+              .flatten()
+              .and_then(|segment| segment.dyn_into::<HtmlElement>().ok());
+
+            _ = match segment {
+              Some(segment) => segment.focus(),
+              None => field.focus(),
+            };
+          }
+        />
+      </VisuallyHidden>
+    </Show>
+  }
+}
+
+// empty segments start from the placeholder, filled ones wrap around their range
+fn step_segment(value: Option<u32>, placeholder: u32, amount: i32, min: u32, max: u32) -> u32 {
+  let Some(value) = value else {
+    return placeholder.clamp(min, max);
+  };
+
+  let size = (max - min + 1) as i64;
+
+  min + (value.clamp(min, max) as i64 - min as i64 + amount as i64).rem_euclid(size) as u32
+}
+
+// returns the new value and whether another digit could still fit in the segment
+fn type_segment(current: Option<u32>, digit: u32, max: u32) -> (u32, bool) {
+  let next = current
+    .map(|current| current * 10 + digit)
+    .filter(|next| *next <= max)
+    .unwrap_or(digit);
+
+  (next, next * 10 > max)
+}
+
+#[test]
+fn types_digits_into_segments() {
+  assert_eq!(type_segment(None, 1, 12), (1, false));
+  assert_eq!(type_segment(Some(1), 2, 12), (12, true));
+  assert_eq!(type_segment(None, 4, 12), (4, true));
+  assert_eq!(type_segment(Some(3), 5, 31), (5, true));
+  assert_eq!(type_segment(Some(0), 7, 31), (7, true));
+  assert_eq!(type_segment(Some(2), 9, 31), (29, true));
+  assert_eq!(type_segment(None, 2, 31), (2, false));
+  assert_eq!(type_segment(Some(202), 4, 9999), (2024, true));
+}
+
+#[test]
+fn steps_segments_with_wrapping() {
+  assert_eq!(step_segment(None, 5, 1, 1, 12), 5);
+  assert_eq!(step_segment(Some(12), 5, 1, 1, 12), 1);
+  assert_eq!(step_segment(Some(1), 5, -1, 1, 12), 12);
+  assert_eq!(step_segment(Some(28), 5, 7, 1, 31), 4);
+}
+
+#[test]
+fn builds_dates_from_segments() {
+  let mut segments = DateSegments::from_date(CalendarDate::new(2024, 1, 31));
+
+  segments.set(DateSegment::Month, Some(2));
+  assert_eq!(segments.to_date(), CalendarDate::new(2024, 2, 29));
+  assert_eq!(segments.range(DateSegment::Day), (1, 29));
+
+  segments.set(DateSegment::Year, None);
+  assert_eq!(segments.to_date(), None);
+  assert_eq!(segments.range(DateSegment::Day), (1, 29));
+}
+
+#[test]
+fn orders_segments_by_locale_parts() {
+  let parts = [
+    ("day", "01"),
+    ("literal", "."),
+    ("month", "01"),
+    ("literal", "."),
+    ("year", "2000"),
+  ]
+  .map(|(kind, value)| (kind.to_string(), value.to_string()));
+
+  assert_eq!(
+    get_parts::<DateSegments>(&parts),
+    vec![
+      FieldPart::Segment(DateSegment::Day),
+      FieldPart::Literal(".".into()),
+      FieldPart::Segment(DateSegment::Month),
+      FieldPart::Literal(".".into()),
+      FieldPart::Segment(DateSegment::Year),
+    ]
+  );
+}
+
+#[test]
+fn commits_the_year_once_it_is_typed_in_full() {
+  let runtime = create_runtime();
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(|| None),
+    default_value: Signal::derive(|| None),
+    on_change: Callback::new(|_| {}),
+  });
+  let (segments, typing_segment) = create_field_segments(
+    value,
+    set_value,
+    DateSegments {
+      year: None,
+      month: Some(3),
+      day: Some(14),
+    },
+    |_, date| DateSegments::from_date(Some(date)),
+    DateSegments::to_date,
+  );
+
+  typing_segment.set(Some(DateSegment::Year));
+
+  for year in [2, 20, 202] {
+    segments.update(|segments| segments.set(DateSegment::Year, Some(year)));
+    assert_eq!(value.get_untracked(), None);
+  }
+
+  segments.update(|segments| segments.set(DateSegment::Year, Some(2024)));
+  typing_segment.set(None);
+  assert_eq!(value.get_untracked(), CalendarDate::new(2024, 3, 14));
+
+  runtime.dispose();
+}
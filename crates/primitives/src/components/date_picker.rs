@@ -0,0 +1,270 @@
+use leptos::{html::AnyElement, *};
+use web_sys::{Event, MouseEvent};
+
+use crate::{
+  calendar::{CalendarDate, CalendarKind, CalendarRoot, Weekday},
+  date_field::DateFieldRoot,
+  popover::{PopoverAnchor, PopoverContent, PopoverPortal, PopoverRoot, PopoverTrigger},
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  Align, Side,
+};
+
+#[derive(Clone)]
+struct DatePickerContextValue {
+  value: Signal<Option<CalendarDate>>,
+  min: Signal<Option<CalendarDate>>,
+  max: Signal<Option<CalendarDate>>,
+  is_date_disabled: Callback<CalendarDate, bool>,
+  week_start: Signal<Weekday>,
+  locale: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+  on_value_change: Callback<CalendarDate>,
+  on_open_change: Callback<bool>,
+}
+
+#[component]
+pub fn DatePickerRoot(
+  #[prop(optional, into)] value: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] default_value: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] min: MaybeProp<CalendarDate>,
+  #[prop(optional, into)] max: MaybeProp<CalendarDate>,
+  #[prop(default=(|_|false).into(), into)] is_date_disabled: Callback<CalendarDate, bool>,
+  #[prop(optional, into)] week_start: MaybeSignal<Weekday>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<CalendarDate>,
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let disabled = Signal::derive(move || disabled.get());
+  let on_open_change = Callback::new(move |open: bool| {
+    if !open || !disabled.get_untracked() {
+      set_open.set(open);
+    }
+  });
+
+  provide_context(DatePickerContextValue {
+    value,
+    min: Signal::derive(move || min.get()),
+    max: Signal::derive(move || max.get()),
+    is_date_disabled,
+    week_start: Signal::derive(move || week_start.get()),
+    locale: Signal::derive(move || locale.get()),
+    disabled,
+    read_only: Signal::derive(move || read_only.get()),
+    on_value_change: Callback::new(move |date| set_value.set(date)),
+    on_open_change,
+  });
+
+  view! {
+    <PopoverRoot
+      open=Signal::derive(move || Some(open.get().unwrap_or(false)))
+      modal=modal
+      on_open_change=on_open_change
+    >
+      {children()}
+    </PopoverRoot>
+  }
+}
+
+#[component]
+pub fn DatePickerField(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DatePickerContextValue {
+    value,
+    min,
+    max,
+    is_date_disabled,
+    locale,
+    disabled,
+    read_only,
+    on_value_change,
+    ..
+  } = use_context().expect("DatePickerField must be used in a DatePickerRoot component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  // the field is the anchor so the calendar lines up with the whole input rather than the trigger
+  view! {
+    <PopoverAnchor>
+      <DateFieldRoot
+        attrs=attrs.get_value()
+        value=value
+        min=min
+        max=max
+        is_date_disabled=is_date_disabled
+        locale=locale
+        disabled=disabled
+        read_only=read_only
+        on_value_change=on_value_change
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </DateFieldRoot>
+    </PopoverAnchor>
+  }
+}
+
+#[component]
+pub fn DatePickerTrigger(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DatePickerContextValue {
+    disabled,
+    read_only,
+    ..
+  } = use_context().expect("DatePickerTrigger must be used in a DatePickerRoot component");
+
+  let is_disabled = Signal::derive(move || disabled.get() || read_only.get());
+
+  view! {
+    <PopoverTrigger
+      attrs=attrs
+      attr:disabled=is_disabled
+      attr:data-disabled=move || is_disabled.get().then_some("")
+      on_click=move |ev: MouseEvent| {
+        if is_disabled.get_untracked() {
+          ev.prevent_default();
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </PopoverTrigger>
+  }
+}
+
+#[component]
+pub fn DatePickerPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <PopoverPortal container=container force_mount=force_mount>
+      {children()}
+    </PopoverPortal>
+  }
+}
+
+#[component]
+pub fn DatePickerContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(default=4.0.into(), into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <PopoverContent
+      attrs=attrs
+      force_mount=force_mount
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      avoid_collisions=avoid_collisions
+      collision_padding=collision_padding
+      // the calendar focuses its selected date itself once it's rendered
+      on_open_auto_focus=move |ev: Event| ev.prevent_default()
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </PopoverContent>
+  }
+}
+
+#[component]
+pub fn DatePickerCalendar(
+  #[prop(optional, into)] fixed_weeks: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DatePickerContextValue {
+    value,
+    min,
+    max,
+    is_date_disabled,
+    week_start,
+    locale,
+    disabled,
+    read_only,
+    on_value_change,
+    on_open_change,
+  } = use_context().expect("DatePickerCalendar must be used in a DatePickerRoot component");
+
+  view! {
+    <CalendarRoot
+      attrs=attrs
+      kind=CalendarKind::Single {
+        value: value.into(),
+        default_value: None::<CalendarDate>.into(),
+        on_value_change: Some(Callback::new(move |date| {
+          on_value_change.call(date);
+          on_open_change.call(false);
+        })),
+      }
+      min=min
+      max=max
+      is_date_disabled=is_date_disabled
+      week_start=week_start
+      fixed_weeks=fixed_weeks
+      locale=locale
+      disabled=disabled
+      read_only=read_only
+      auto_focus=true
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </CalendarRoot>
+  }
+}
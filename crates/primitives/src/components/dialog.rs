@@ -0,0 +1,464 @@
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, Event, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  aria_hidden::use_hide_others,
+  dismissable_layer::{get_original_event, DismissableLayer, FOCUS_OUTSIDE, POINTER_DOWN_OUTSIDE},
+  focus_guards::use_focus_guards,
+  focus_scope::FocusScope,
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  scroll_lock::use_body_scroll_lock,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+};
+
+#[derive(Clone)]
+struct DialogContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  title_id: Signal<String>,
+  description_id: Signal<String>,
+  open: Signal<bool>,
+  modal: Signal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+}
+
+#[derive(Clone)]
+struct DialogPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn DialogRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  provide_context(DialogContextValue {
+    trigger_ref: NodeRef::new(),
+    content_id: create_id(),
+    title_id: create_id(),
+    description_id: create_id(),
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    modal: Signal::derive(move || modal.get()),
+    on_open_change: Callback::new(move |value| {
+      set_open.set(value);
+    }),
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+  });
+
+  children()
+}
+
+#[component]
+pub fn DialogTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    on_open_toggle,
+    ..
+  } = use_context().expect("DialogTrigger must be used in a DialogRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      attr:aria-haspopup="dialog"
+      attr:aria-expanded=open
+      attr:aria-controls=content_id
+      attr:data-state=move || if open.get() { "open" } else { "closed" }
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_toggle.call(());
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DialogPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(DialogPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+pub(crate) fn use_dialog_is_present(force_mount: MaybeSignal<bool>) -> Signal<bool> {
+  let DialogContextValue { open, .. } =
+    use_context().expect("dialog content must be used in a DialogRoot component");
+
+  let portal_force_mount = use_context::<DialogPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  Signal::derive(move || open.get() || force_mount.get() || portal_force_mount.get())
+}
+
+#[component]
+pub fn DialogOverlay(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { open, modal, .. } =
+    use_context().expect("DialogOverlay must be used in a DialogRoot component");
+
+  let is_present = use_dialog_is_present(force_mount);
+  let presence = create_presence(is_present, node_ref);
+  let children = StoredValue::new(children);
+
+  // set on the node so it merges with a consumer's style instead of replacing it
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node.style("pointer-events", "auto");
+  });
+
+  view! {
+    <Show when=move || modal.get() && presence.get()>
+      <Primitive
+        {..attrs.clone()}
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn DialogContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let is_present = use_dialog_is_present(force_mount);
+  let presence = create_presence(is_present, node_ref);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <DialogContentImpl
+        on_open_auto_focus=on_open_auto_focus
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=on_focus_outside
+        on_interact_outside=on_interact_outside
+        node_ref=node_ref
+        attrs=attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </DialogContentImpl>
+    </Show>
+  }
+}
+
+#[component]
+pub(crate) fn DialogContentImpl(
+  #[prop(default = "dialog")] role: &'static str,
+
+  #[prop(into)] on_open_auto_focus: Callback<Event>,
+  #[prop(into)] on_close_auto_focus: Callback<Event>,
+  #[prop(into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(into)] on_interact_outside: Callback<CustomEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue {
+    trigger_ref,
+    content_id,
+    title_id,
+    description_id,
+    open,
+    modal,
+    on_open_change,
+    ..
+  } = use_context().expect("DialogContentImpl must be used in a DialogRoot component");
+
+  // the page behind a modal dialog can't be scrolled or reached by assistive technology
+  use_body_scroll_lock(modal);
+  use_hide_others(node_ref, modal);
+  use_focus_guards();
+
+  let has_interacted_outside = StoredValue::new(false);
+  let has_pointer_down_outside = StoredValue::new(false);
+
+  let focus_trigger = move || {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+      _ = trigger.focus();
+    }
+  };
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusScope
+      as_child=true
+      should_loop=true
+      trapped=modal
+      on_mount_auto_focus=on_open_auto_focus
+      on_unmount_auto_focus=move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if modal.get_untracked() {
+          if !ev.default_prevented() {
+            ev.prevent_default();
+            focus_trigger();
+          }
+        } else {
+          if !ev.default_prevented() {
+            if !has_interacted_outside.get_value() {
+              focus_trigger();
+            }
+
+            ev.prevent_default();
+          }
+
+          has_interacted_outside.set_value(false);
+          has_pointer_down_outside.set_value(false);
+        }
+      }
+    >
+      <DismissableLayer
+        as_child=true
+        disable_outside_pointer_events=modal
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=move |ev: CustomEvent| {
+          on_pointer_down_outside.call(ev.clone());
+
+          if !modal.get_untracked() {
+            return;
+          }
+
+          let Some(original_event) = get_original_event::<PointerEvent>(&ev) else {
+            return;
+          };
+
+          let is_ctrl_left_click = original_event.button() == 0 && original_event.ctrl_key();
+          let is_right_click = original_event.button() == 2 || is_ctrl_left_click;
+
+          // right clicking outside of a modal dialog shouldn't close it
+          if is_right_click {
+            ev.prevent_default();
+          }
+        }
+        on_focus_outside=move |ev: CustomEvent| {
+          on_focus_outside.call(ev.clone());
+
+          // focus is trapped inside of modal dialogs, so the dialog shouldn't close when focus moves out
+          if modal.get_untracked() {
+            ev.prevent_default();
+          }
+        }
+        on_interact_outside=move |ev: CustomEvent| {
+          on_interact_outside.call(ev.clone());
+
+          if modal.get_untracked() {
+            return;
+          }
+
+          if !ev.default_prevented() {
+            has_interacted_outside.set_value(true);
+
+            if ev.type_() == POINTER_DOWN_OUTSIDE {
+              has_pointer_down_outside.set_value(true);
+            }
+          }
+
+          let target = get_original_event::<Event>(&ev)
+            .and_then(|original_event| original_event.target())
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+          let is_target_trigger = trigger_ref
+            .get_untracked()
+            .zip(target)
+            .map(|(trigger, target)| trigger.contains(Some(&target)))
+            .unwrap_or(false);
+
+          // the trigger toggles the dialog itself, so interacting with it shouldn't dismiss the dialog first
+          if is_target_trigger {
+            ev.prevent_default();
+          }
+
+          // some browsers move focus to the trigger after a pointer down on it, which would otherwise
+          // dismiss the dialog right before the trigger's own click handler reopens it
+          if ev.type_() == FOCUS_OUTSIDE && has_pointer_down_outside.get_value() {
+            ev.prevent_default();
+          }
+        }
+        on_dismiss=move |_| on_open_change.call(false)
+      >
+        <Primitive
+          {..attrs.get_value()}
+          attr:role=role
+          attr:id=content_id
+          attr:aria-labelledby=title_id
+          attr:aria-describedby=description_id
+          attr:aria-modal=move || modal.get().then_some("true")
+          attr:data-state=move || if open.get() { "open" } else { "closed" }
+          element=html::div
+          node_ref=node_ref
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </DismissableLayer>
+    </FocusScope>
+  }
+}
+
+#[component]
+pub fn DialogTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { title_id, .. } =
+    use_context().expect("DialogTitle must be used in a DialogRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=title_id
+      element=html::h2
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DialogDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { description_id, .. } =
+    use_context().expect("DialogDescription must be used in a DialogRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=description_id
+      element=html::p
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DialogClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { on_open_change, .. } =
+    use_context().expect("DialogClose must be used in a DialogRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_change.call(false);
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
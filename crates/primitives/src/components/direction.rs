@@ -0,0 +1,33 @@
+use leptos::*;
+
+use crate::Direction;
+
+#[derive(Clone)]
+struct DirectionContextValue {
+  direction: Signal<Direction>,
+}
+
+#[component]
+pub fn DirectionProvider(
+  #[prop(into)] dir: MaybeSignal<Direction>,
+  children: Children,
+) -> impl IntoView {
+  provide_context(DirectionContextValue {
+    direction: Signal::derive(move || dir.get()),
+  });
+
+  children()
+}
+
+pub fn use_direction() -> Signal<Direction> {
+  use_context::<DirectionContextValue>()
+    .map(|context| context.direction)
+    .unwrap_or(Signal::derive(|| Direction::LeftToRight))
+}
+
+// components fall back to the closest `DirectionProvider` when they aren't given a direction
+pub(crate) fn use_local_direction(direction: MaybeProp<Direction>) -> Signal<Direction> {
+  let global_direction = use_direction();
+
+  Signal::derive(move || direction.get().unwrap_or_else(|| global_direction.get()))
+}
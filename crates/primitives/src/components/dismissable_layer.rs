@@ -0,0 +1,304 @@
+use std::cell::RefCell;
+
+use leptos::{
+  ev::{focusin, keydown, pointerdown},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{use_document, use_event_listener};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, CustomEventInit, Event, FocusEvent, KeyboardEvent, PointerEvent};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+pub const POINTER_DOWN_OUTSIDE: &str = "dismissable_layer.pointer_down_outside";
+pub const FOCUS_OUTSIDE: &str = "dismissable_layer.focus_outside";
+
+#[derive(Clone)]
+struct Layer {
+  id: String,
+  element: web_sys::Element,
+  disable_outside_pointer_events: bool,
+  pointer_events: RwSignal<Option<&'static str>>,
+}
+
+thread_local! {
+  static LAYERS: RefCell<Vec<Layer>> = const { RefCell::new(vec![]) };
+  static BRANCHES: RefCell<Vec<web_sys::Element>> = const { RefCell::new(vec![]) };
+  static ORIGINAL_BODY_POINTER_EVENTS: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+#[component]
+pub fn DismissableLayer(
+  #[prop(optional, into)] disable_outside_pointer_events: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_dismiss: Callback<()>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let id = StoredValue::new(nanoid::nanoid!());
+  let pointer_events = RwSignal::new(None);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let element: &web_sys::Element = &node;
+
+    LAYERS.with_borrow_mut(|layers| {
+      layers.push(Layer {
+        id: id.get_value(),
+        element: element.clone(),
+        disable_outside_pointer_events: disable_outside_pointer_events.get_untracked(),
+        pointer_events,
+      });
+    });
+    update_layers();
+
+    let id = id.get_value();
+
+    on_cleanup(move || {
+      LAYERS.with_borrow_mut(|layers| layers.retain(|layer| layer.id != id));
+      update_layers();
+    });
+  });
+
+  Effect::new(move |_| {
+    let disable_outside_pointer_events = disable_outside_pointer_events.get();
+
+    LAYERS.with_borrow_mut(|layers| {
+      if let Some(layer) = layers
+        .iter_mut()
+        .find(|layer| id.with_value(|id| layer.id == *id))
+      {
+        layer.disable_outside_pointer_events = disable_outside_pointer_events;
+      }
+    });
+    update_layers();
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let style = web_sys::HtmlElement::style(&node);
+
+    _ = match pointer_events.get() {
+      Some(pointer_events) => style.set_property("pointer-events", pointer_events),
+      None => style.remove_property("pointer-events").map(|_| ()),
+    };
+  });
+
+  // layers pushed after this one are nested inside of it even when they are portalled elsewhere,
+  // so interacting with them isn't considered to be outside of this layer
+  let is_inside = move |ev: &Event| {
+    let Some(node) = node_ref.get_untracked() else {
+      return true;
+    };
+
+    let Some(target) = ev
+      .target()
+      .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+    else {
+      return false;
+    };
+
+    node.contains(Some(&target))
+      || id.with_value(|id| {
+        LAYERS.with_borrow(|layers| {
+          layers
+            .iter()
+            .skip_while(|layer| layer.id != *id)
+            .any(|layer| layer.element.contains(Some(&target)))
+        })
+      })
+      || BRANCHES
+        .with_borrow(|branches| branches.iter().any(|branch| branch.contains(Some(&target))))
+  };
+
+  _ = use_event_listener(use_document(), keydown, move |ev: KeyboardEvent| {
+    if ev.key() != "Escape" {
+      return;
+    }
+
+    let is_highest_layer = id.with_value(|id| {
+      LAYERS.with_borrow(|layers| layers.last().is_some_and(|layer| layer.id == *id))
+    });
+
+    if !is_highest_layer {
+      return;
+    }
+
+    on_escape_key_down.call(ev.clone());
+
+    if !ev.default_prevented() {
+      ev.prevent_default();
+      on_dismiss.call(());
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerdown, move |ev: PointerEvent| {
+    if pointer_events.get_untracked() == Some("none") || is_inside(&ev) {
+      return;
+    }
+
+    let Some(outside_event) = create_outside_event(POINTER_DOWN_OUTSIDE, &ev) else {
+      return;
+    };
+
+    on_pointer_down_outside.call(outside_event.clone());
+    on_interact_outside.call(outside_event.clone());
+
+    if !outside_event.default_prevented() {
+      on_dismiss.call(());
+    }
+  });
+
+  _ = use_event_listener(use_document(), focusin, move |ev: FocusEvent| {
+    if is_inside(&ev) {
+      return;
+    }
+
+    let Some(outside_event) = create_outside_event(FOCUS_OUTSIDE, &ev) else {
+      return;
+    };
+
+    on_focus_outside.call(outside_event.clone());
+    on_interact_outside.call(outside_event.clone());
+
+    if !outside_event.default_prevented() {
+      on_dismiss.call(());
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DismissableLayerBranch(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let element: &web_sys::Element = &node;
+    let element = element.clone();
+
+    BRANCHES.with_borrow_mut(|branches| branches.push(element.clone()));
+
+    on_cleanup(move || {
+      BRANCHES.with_borrow_mut(|branches| branches.retain(|branch| *branch != element));
+    });
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn update_layers() {
+  let (highest_disabled_index, layers) = LAYERS.with_borrow(|layers| {
+    (
+      layers
+        .iter()
+        .rposition(|layer| layer.disable_outside_pointer_events),
+      layers
+        .iter()
+        .map(|layer| layer.pointer_events)
+        .collect::<Vec<_>>(),
+    )
+  });
+
+  set_body_pointer_events_disabled(highest_disabled_index.is_some());
+
+  // signals are set outside of the borrow since the effects they trigger may mount other layers
+  for (index, pointer_events) in layers.into_iter().enumerate() {
+    _ = pointer_events.try_set(get_pointer_events(index, highest_disabled_index));
+  }
+}
+
+fn set_body_pointer_events_disabled(disabled: bool) {
+  let Some(body) = document().body() else {
+    return;
+  };
+
+  let style = body.style();
+
+  ORIGINAL_BODY_POINTER_EVENTS.with_borrow_mut(|original| match (disabled, original.take()) {
+    (true, None) => {
+      *original = Some(
+        style
+          .get_property_value("pointer-events")
+          .unwrap_or_default(),
+      );
+      _ = style.set_property("pointer-events", "none");
+    }
+    (false, Some(original)) => {
+      _ = style.set_property("pointer-events", &original);
+    }
+    (_, previous) => *original = previous,
+  });
+}
+
+fn get_pointer_events(index: usize, highest_disabled_index: Option<usize>) -> Option<&'static str> {
+  highest_disabled_index.map(|highest_disabled_index| {
+    if index >= highest_disabled_index {
+      "auto"
+    } else {
+      "none"
+    }
+  })
+}
+
+fn create_outside_event(name: &str, original_event: &Event) -> Option<CustomEvent> {
+  let init = CustomEventInit::new();
+  init.set_bubbles(false);
+  init.set_cancelable(true);
+  init.set_detail(original_event);
+
+  CustomEvent::new_with_event_init_dict(name, &init).ok()
+}
+
+pub fn get_original_event<T: JsCast>(ev: &CustomEvent) -> Option<T> {
+  ev.detail().dyn_into::<T>().ok()
+}
+
+#[test]
+fn layers_below_the_highest_modal_layer_ignore_pointer_events() {
+  assert_eq!(get_pointer_events(0, None), None);
+  assert_eq!(get_pointer_events(0, Some(1)), Some("none"));
+  assert_eq!(get_pointer_events(1, Some(1)), Some("auto"));
+  assert_eq!(get_pointer_events(2, Some(1)), Some("auto"));
+}
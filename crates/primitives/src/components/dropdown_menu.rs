@@ -0,0 +1,659 @@
+use leptos::{html::AnyElement, *};
+use web_sys::{CustomEvent, Event, KeyboardEvent, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  direction::use_local_direction,
+  dismissable_layer::get_original_event,
+  menu::{
+    MenuAnchor, MenuArrow, MenuCheckboxItem, MenuContent, MenuGroup, MenuItem, MenuItemIndicator,
+    MenuLabel, MenuPortal, MenuRadioGroup, MenuRadioItem, MenuRoot, MenuSeparator, MenuSub,
+    MenuSubContent, MenuSubTrigger,
+  },
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  Align, Direction, Side, Sticky,
+};
+
+#[derive(Clone)]
+struct DropdownMenuContextValue {
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  modal: Signal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+}
+
+#[component]
+pub fn DropdownMenuRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let on_open_change = Callback::new(move |value| set_open.set(value));
+
+  provide_context(DropdownMenuContextValue {
+    trigger_id: create_id(),
+    trigger_ref: NodeRef::new(),
+    content_id: create_id(),
+    open,
+    modal: Signal::derive(move || modal.get()),
+    on_open_change,
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+  });
+
+  view! {
+    <MenuRoot
+      open=open
+      direction=direction
+      modal=modal
+      on_open_change=on_open_change
+    >
+      {children()}
+    </MenuRoot>
+  }
+}
+
+#[component]
+pub fn DropdownMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DropdownMenuContextValue {
+    trigger_id,
+    trigger_ref,
+    content_id,
+    open,
+    on_open_change,
+    on_open_toggle,
+    ..
+  } = use_context().expect("DropdownMenuTrigger must be used in a DropdownMenuRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <MenuAnchor as_child=true>
+      <Primitive
+        {..attrs.get_value()}
+        attr:type="button"
+        attr:id=trigger_id
+        attr:aria-haspopup="menu"
+        attr:aria-expanded=open
+        attr:aria-controls=move || open.get().then(|| content_id.get())
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-disabled=move || disabled.get().then_some("")
+        attr:disabled=move || disabled.get()
+        element=html::button
+        on:pointerdown=move |ev: PointerEvent| {
+          // only the left button without ctrl opens the menu so the context menu keeps working
+          if disabled.get_untracked() || ev.button() != 0 || ev.ctrl_key() {
+            return;
+          }
+
+          on_open_toggle.call(());
+
+          // keeps the trigger from taking focus away from the content as it opens
+          if !open.get_untracked() {
+            ev.prevent_default();
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          if disabled.get_untracked() {
+            return;
+          }
+
+          match ev.key().as_str() {
+            "Enter" | " " => on_open_toggle.call(()),
+            "ArrowDown" => on_open_change.call(true),
+            _ => return,
+          }
+
+          // keeps the keydown from scrolling the page
+          ev.prevent_default();
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </MenuAnchor>
+  }
+}
+
+#[component]
+pub fn DropdownMenuPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <MenuPortal container=container force_mount=force_mount>
+      {children()}
+    </MenuPortal>
+  }
+}
+
+#[component]
+pub fn DropdownMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DropdownMenuContextValue {
+    trigger_id,
+    trigger_ref,
+    content_id,
+    modal,
+    ..
+  } = use_context().expect("DropdownMenuContent must be used in a DropdownMenuRoot component");
+
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-dropdown-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  view! {
+    <MenuContent
+      {..attrs}
+      attr:id=content_id
+      attr:aria-labelledby=trigger_id
+      force_mount=force_mount
+      should_loop=should_loop
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if !ev.default_prevented() {
+          if !has_interacted_outside.get_value() {
+            if let Some(trigger) = trigger_ref.get_untracked() {
+              _ = trigger.focus();
+            }
+          }
+
+          ev.prevent_default();
+        }
+
+        has_interacted_outside.set_value(false);
+      }
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_interact_outside=move |ev: CustomEvent| {
+        on_interact_outside.call(ev.clone());
+
+        let Some(original_event) = get_original_event::<PointerEvent>(&ev) else {
+          if !modal.get_untracked() {
+            has_interacted_outside.set_value(true);
+          }
+
+          return;
+        };
+
+        let is_ctrl_left_click = original_event.button() == 0 && original_event.ctrl_key();
+        let is_right_click = original_event.button() == 2 || is_ctrl_left_click;
+
+        // right clicking outside of a modal menu should return focus to the trigger
+        if !modal.get_untracked() || is_right_click {
+          has_interacted_outside.set_value(true);
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn DropdownMenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn DropdownMenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn DropdownMenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuCheckboxItem(
+  #[prop(default=CheckedState::Checked(false).into(), into)] checked: MaybeSignal<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn DropdownMenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children()}
+      </MenuItemIndicator>
+    },
+    None => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn DropdownMenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn DropdownMenuArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </MenuArrow>
+    },
+    None => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn DropdownMenuSub(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=move |value| set_open.set(value)
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn DropdownMenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSubTrigger
+      disabled=disabled
+      text_value=text_value
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn DropdownMenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-dropdown-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  view! {
+    <MenuSubContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_interact_outside=on_interact_outside
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
@@ -0,0 +1,74 @@
+use std::cell::Cell;
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+thread_local! {
+  static FOCUS_GUARD_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+#[component]
+pub fn FocusGuards(children: Children) -> impl IntoView {
+  use_focus_guards();
+
+  children()
+}
+
+pub fn use_focus_guards() {
+  Effect::new(move |_| {
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    // existing guards are reused so the body only ever has one guard at each edge
+    let guards = body
+      .query_selector_all("[data-leptix-focus-guard]")
+      .ok()
+      .map(|guards| {
+        (0..guards.length())
+          .filter_map(|index| guards.item(index)?.dyn_into::<web_sys::Element>().ok())
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+
+    let start_guard = guards.first().cloned().or_else(create_focus_guard);
+    let end_guard = guards.get(1).cloned().or_else(create_focus_guard);
+
+    if let Some(start_guard) = start_guard {
+      _ = body.insert_adjacent_element("afterbegin", &start_guard);
+    }
+
+    if let Some(end_guard) = end_guard {
+      _ = body.insert_adjacent_element("beforeend", &end_guard);
+    }
+
+    FOCUS_GUARD_COUNT.set(FOCUS_GUARD_COUNT.get() + 1);
+
+    on_cleanup(move || {
+      if FOCUS_GUARD_COUNT.get() == 1 {
+        if let Ok(guards) = document().query_selector_all("[data-leptix-focus-guard]") {
+          for guard in (0..guards.length()).filter_map(|index| guards.item(index)) {
+            if let Some(parent) = guard.parent_node() {
+              _ = parent.remove_child(&guard);
+            }
+          }
+        }
+      }
+
+      FOCUS_GUARD_COUNT.set(FOCUS_GUARD_COUNT.get().saturating_sub(1));
+    });
+  });
+}
+
+fn create_focus_guard() -> Option<web_sys::Element> {
+  let guard = document().create_element("span").ok()?;
+
+  _ = guard.set_attribute("data-leptix-focus-guard", "");
+  _ = guard.set_attribute("tabindex", "0");
+  _ = guard.set_attribute(
+    "style",
+    "outline: none; opacity: 0; position: fixed; pointer-events: none;",
+  );
+
+  Some(guard)
+}
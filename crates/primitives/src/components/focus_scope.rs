@@ -0,0 +1,376 @@
+use std::{cell::RefCell, time::Duration};
+
+use leptos::{
+  ev::{focusin, focusout},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_mutation_observer_with_options, UseMutationObserverOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, CustomEventInit, Event, FocusEvent, KeyboardEvent};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+const AUTO_FOCUS_ON_MOUNT: &str = "focus_scope.auto_focus_on_mount";
+const AUTO_FOCUS_ON_UNMOUNT: &str = "focus_scope.auto_focus_on_unmount";
+
+#[derive(Clone)]
+struct FocusScopeEntry {
+  id: String,
+  paused: StoredValue<bool>,
+}
+
+thread_local! {
+  static FOCUS_SCOPES: RefCell<Vec<FocusScopeEntry>> = const { RefCell::new(vec![]) };
+}
+
+#[component]
+pub fn FocusScope(
+  #[prop(optional, into)] trapped: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_mount_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_unmount_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let last_focused_element = StoredValue::<Option<web_sys::HtmlElement>>::new(None);
+  let previously_focused_element = StoredValue::<Option<web_sys::HtmlElement>>::new(None);
+  let id = StoredValue::new(nanoid::nanoid!());
+  let paused = StoredValue::new(false);
+
+  _ = use_event_listener(use_document(), focusin, move |ev: FocusEvent| {
+    if !trapped.get_untracked() || paused.get_value() {
+      return;
+    }
+
+    let Some(container) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+
+    if target
+      .as_ref()
+      .map(|target| container.contains(Some(target)))
+      .unwrap_or(false)
+    {
+      last_focused_element.set_value(target);
+    } else {
+      focus(
+        last_focused_element
+          .get_value()
+          .or_else(|| container.dyn_ref::<web_sys::HtmlElement>().cloned()),
+        false,
+      );
+    }
+  });
+
+  _ = use_event_listener(use_document(), focusout, move |ev: FocusEvent| {
+    if !trapped.get_untracked() || paused.get_value() {
+      return;
+    }
+
+    let Some(container) = node_ref.get_untracked() else {
+      return;
+    };
+
+    // focus moving to nothing (e.g. the browser's address bar) shouldn't be treated as leaving the scope
+    let Some(related_target) = ev
+      .related_target()
+      .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+    else {
+      return;
+    };
+
+    if !container.contains(Some(&related_target)) {
+      focus(
+        last_focused_element
+          .get_value()
+          .or_else(|| container.dyn_ref::<web_sys::HtmlElement>().cloned()),
+        false,
+      );
+    }
+  });
+
+  // when the focused element is removed the browser moves focus to the body, so it is moved back
+  // into the container to keep it trapped
+  _ = use_mutation_observer_with_options(
+    node_ref,
+    move |mutations, _| {
+      if !trapped.get_untracked() || paused.get_value() {
+        return;
+      }
+
+      let Some(container) = node_ref.get_untracked() else {
+        return;
+      };
+
+      if document().active_element() != document().body().map(Into::into) {
+        return;
+      }
+
+      if mutations
+        .iter()
+        .any(|mutation| mutation.removed_nodes().length() > 0)
+      {
+        focus(container.dyn_ref::<web_sys::HtmlElement>().cloned(), false);
+      }
+    },
+    UseMutationObserverOptions::default()
+      .child_list(true)
+      .subtree(true),
+  );
+
+  Effect::new(move |has_mounted: Option<bool>| {
+    if has_mounted.unwrap_or(false) {
+      return true;
+    }
+
+    let Some(container) = node_ref.get() else {
+      return false;
+    };
+
+    add_focus_scope(FocusScopeEntry {
+      id: id.get_value(),
+      paused,
+    });
+
+    let previously_focused = document()
+      .active_element()
+      .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+
+    previously_focused_element.set_value(previously_focused.clone());
+
+    let has_focused_candidate = previously_focused
+      .as_ref()
+      .map(|el| container.contains(Some(el)))
+      .unwrap_or(false);
+
+    if !has_focused_candidate {
+      let Some(mount_event) = create_auto_focus_event(AUTO_FOCUS_ON_MOUNT) else {
+        return true;
+      };
+
+      on_mount_auto_focus.call(mount_event.clone().into());
+
+      if !mount_event.default_prevented() {
+        focus_first(&remove_links(get_tabbable_candidates(&container)), true);
+
+        if document().active_element() == previously_focused.map(Into::into) {
+          focus(container.dyn_ref::<web_sys::HtmlElement>().cloned(), false);
+        }
+      }
+    }
+
+    true
+  });
+
+  on_cleanup(move || {
+    if let Some(id) = id.try_get_value() {
+      remove_focus_scope(&id);
+    }
+
+    let Some(unmount_event) = create_auto_focus_event(AUTO_FOCUS_ON_UNMOUNT) else {
+      return;
+    };
+
+    on_unmount_auto_focus.call(unmount_event.clone().into());
+
+    if !unmount_event.default_prevented() {
+      let previously_focused = previously_focused_element
+        .try_get_value()
+        .flatten()
+        .or_else(|| document().body());
+
+      set_timeout(move || focus(previously_focused, true), Duration::ZERO);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:tabindex=-1
+      element=html::div
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if (!should_loop.get() && !trapped.get()) || paused.get_value() {
+          return;
+        }
+
+        let is_tab_key = ev.key() == "Tab" && !ev.alt_key() && !ev.ctrl_key() && !ev.meta_key();
+        let focused_element = document().active_element();
+
+        if !is_tab_key || focused_element.is_none() {
+          return;
+        }
+
+        let Some(container) = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+          return;
+        };
+
+        let candidates = get_tabbable_candidates(&container);
+
+        let (Some(first), Some(last)) = (candidates.first(), candidates.last()) else {
+          if focused_element.as_ref() == Some(&container) {
+            ev.prevent_default();
+          }
+
+          return;
+        };
+
+        let focused_element = focused_element.as_ref();
+
+        if !ev.shift_key() && focused_element == Some(last) {
+          ev.prevent_default();
+
+          if should_loop.get() {
+            focus(Some(first.clone()), true);
+          }
+        } else if ev.shift_key() && focused_element == Some(first) {
+          ev.prevent_default();
+
+          if should_loop.get() {
+            focus(Some(last.clone()), true);
+          }
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+// only the most recently mounted scope traps focus, the ones below it are paused until it unmounts
+fn add_focus_scope(scope: FocusScopeEntry) {
+  FOCUS_SCOPES.with_borrow_mut(|scopes| {
+    if let Some(active_scope) = scopes.last() {
+      if active_scope.id != scope.id {
+        _ = active_scope.paused.try_set_value(true);
+      }
+    }
+
+    scopes.retain(|entry| entry.id != scope.id);
+    _ = scope.paused.try_set_value(false);
+    scopes.push(scope);
+  });
+}
+
+fn remove_focus_scope(id: &str) {
+  FOCUS_SCOPES.with_borrow_mut(|scopes| {
+    scopes.retain(|entry| entry.id != id);
+
+    if let Some(active_scope) = scopes.last() {
+      _ = active_scope.paused.try_set_value(false);
+    }
+  });
+}
+
+fn create_auto_focus_event(name: &str) -> Option<CustomEvent> {
+  let init = CustomEventInit::new();
+  init.set_bubbles(false);
+  init.set_cancelable(true);
+
+  CustomEvent::new_with_event_init_dict(name, &init).ok()
+}
+
+pub fn focus(element: Option<web_sys::HtmlElement>, select: bool) {
+  let Some(element) = element else {
+    return;
+  };
+
+  let previously_focused = document().active_element();
+
+  _ = element.focus();
+
+  if select && previously_focused.as_ref() != Some(&element) {
+    if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+      input.select();
+    }
+  }
+}
+
+pub fn focus_first(candidates: &[web_sys::HtmlElement], select: bool) -> bool {
+  let previously_focused = document().active_element();
+
+  for candidate in candidates {
+    focus(Some(candidate.clone()), select);
+
+    if document().active_element() != previously_focused {
+      return true;
+    }
+  }
+
+  false
+}
+
+pub fn get_tabbable_candidates(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+  let Ok(nodes) = container.query_selector_all(
+    "a[href], area[href], button, input, select, textarea, iframe, summary, [tabindex], [contenteditable]",
+  ) else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.get(index)?.dyn_into::<web_sys::HtmlElement>().ok())
+    .filter(|node| {
+      let is_hidden_input = node
+        .dyn_ref::<web_sys::HtmlInputElement>()
+        .map(|input| input.type_() == "hidden")
+        .unwrap_or(false);
+
+      !(node.has_attribute("disabled") || node.hidden() || is_hidden_input || node.tab_index() < 0)
+    })
+    .filter(|node| !is_hidden(node, container))
+    .collect()
+}
+
+fn remove_links(candidates: Vec<web_sys::HtmlElement>) -> Vec<web_sys::HtmlElement> {
+  candidates
+    .into_iter()
+    .filter(|candidate| candidate.tag_name() != "A")
+    .collect()
+}
+
+fn is_hidden(node: &web_sys::Element, up_to: &web_sys::Element) -> bool {
+  let Ok(Some(style)) = window().get_computed_style(node) else {
+    return false;
+  };
+
+  if style.get_property_value("visibility").ok().as_deref() == Some("hidden") {
+    return true;
+  }
+
+  let mut current = Some(node.clone());
+
+  while let Some(el) = current {
+    if &el == up_to {
+      break;
+    }
+
+    if let Ok(Some(style)) = window().get_computed_style(&el) {
+      if style.get_property_value("display").ok().as_deref() == Some("none") {
+        return true;
+      }
+    }
+
+    current = el.parent_element();
+  }
+
+  false
+}
@@ -0,0 +1,878 @@
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+
+use leptos::{html::AnyElement, *};
+use leptos_use::{use_event_listener, use_event_listener_with_options, UseEventListenerOptions};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{
+  js_sys::{Function, JsString, Reflect},
+  Event, FormData, HtmlElement, HtmlFormElement, MouseEvent, SubmitEvent,
+};
+
+use crate::{
+  components::label::LabelRoot,
+  primitive::Primitive,
+  util::{create_id::create_id, Attributes},
+};
+
+const DEFAULT_INVALID_MESSAGE: &str = "This value is not valid";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FormValidity {
+  pub bad_input: bool,
+  pub custom_error: bool,
+  pub pattern_mismatch: bool,
+  pub range_overflow: bool,
+  pub range_underflow: bool,
+  pub step_mismatch: bool,
+  pub too_long: bool,
+  pub too_short: bool,
+  pub type_mismatch: bool,
+  pub valid: bool,
+  pub value_missing: bool,
+}
+
+impl FormValidity {
+  fn has_built_in_error(&self) -> bool {
+    self.bad_input
+      || self.pattern_mismatch
+      || self.range_overflow
+      || self.range_underflow
+      || self.step_mismatch
+      || self.too_long
+      || self.too_short
+      || self.type_mismatch
+      || self.value_missing
+  }
+}
+
+type CustomMatcher = Rc<dyn Fn(String, FormData) -> bool>;
+type AsyncCustomMatcher = Rc<dyn Fn(String, FormData) -> Pin<Box<dyn Future<Output = bool>>>>;
+
+#[derive(Clone)]
+pub enum FormMatcher {
+  BadInput,
+  PatternMismatch,
+  RangeOverflow,
+  RangeUnderflow,
+  StepMismatch,
+  TooLong,
+  TooShort,
+  TypeMismatch,
+  Valid,
+  ValueMissing,
+  Custom(CustomMatcher),
+  CustomAsync(AsyncCustomMatcher),
+}
+
+impl FormMatcher {
+  pub fn custom(matcher: impl Fn(String, FormData) -> bool + 'static) -> Self {
+    Self::Custom(Rc::new(matcher))
+  }
+
+  pub fn custom_async<Fut>(matcher: impl Fn(String, FormData) -> Fut + 'static) -> Self
+  where
+    Fut: Future<Output = bool> + 'static,
+  {
+    Self::CustomAsync(Rc::new(move |value, form_data| {
+      Box::pin(matcher(value, form_data))
+    }))
+  }
+
+  fn matches_validity(&self, validity: &FormValidity) -> Option<bool> {
+    Some(match self {
+      Self::BadInput => validity.bad_input,
+      Self::PatternMismatch => validity.pattern_mismatch,
+      Self::RangeOverflow => validity.range_overflow,
+      Self::RangeUnderflow => validity.range_underflow,
+      Self::StepMismatch => validity.step_mismatch,
+      Self::TooLong => validity.too_long,
+      Self::TooShort => validity.too_short,
+      Self::TypeMismatch => validity.type_mismatch,
+      Self::Valid => validity.valid,
+      Self::ValueMissing => validity.value_missing,
+      Self::Custom(_) | Self::CustomAsync(_) => return None,
+    })
+  }
+
+  fn default_message(&self) -> Option<&'static str> {
+    match self {
+      Self::BadInput => Some(DEFAULT_INVALID_MESSAGE),
+      Self::PatternMismatch => Some("This value does not match the required pattern"),
+      Self::RangeOverflow => Some("This value is too large"),
+      Self::RangeUnderflow => Some("This value is too small"),
+      Self::StepMismatch => Some("This value does not match the required step"),
+      Self::TooLong => Some("This value is too long"),
+      Self::TooShort => Some("This value is too short"),
+      Self::TypeMismatch => Some("This value does not match the required type"),
+      Self::Valid => None,
+      Self::ValueMissing => Some("This value is missing"),
+      Self::Custom(_) | Self::CustomAsync(_) => Some(DEFAULT_INVALID_MESSAGE),
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+struct FormContextValue {
+  validity_map: RwSignal<HashMap<String, FormValidity>>,
+  custom_errors_map: RwSignal<HashMap<String, HashMap<String, bool>>>,
+  custom_matchers_map: StoredValue<HashMap<String, Vec<(String, FormMatcher)>>>,
+  message_ids_map: RwSignal<HashMap<String, Vec<String>>>,
+}
+
+#[derive(Clone)]
+struct FormFieldContextValue {
+  id: Signal<String>,
+  name: Signal<String>,
+  server_invalid: Signal<bool>,
+}
+
+#[component]
+pub fn FormRoot(
+  #[prop(default=(|_|{}).into(), into)] on_clear_server_errors: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_submit: Callback<SubmitEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_reset: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  provide_context(FormContextValue {
+    validity_map: RwSignal::new(HashMap::new()),
+    custom_errors_map: RwSignal::new(HashMap::new()),
+    custom_matchers_map: StoredValue::new(HashMap::new()),
+    message_ids_map: RwSignal::new(HashMap::new()),
+  });
+
+  // `invalid` does not bubble, so it is caught during capture to focus the first invalid control
+  // and to suppress the browser's own validation bubbles
+  _ = use_event_listener_with_options(
+    node_ref,
+    ev::Custom::<Event>::new("invalid"),
+    move |ev: Event| {
+      let Some(form) = node_ref.get_untracked() else {
+        return;
+      };
+
+      if let Some(first_invalid_control) = get_first_invalid_control(&form) {
+        let target = ev
+          .target()
+          .and_then(|target| target.dyn_into::<HtmlElement>().ok());
+
+        if target.as_ref() == Some(&first_invalid_control) {
+          focus_control(&first_invalid_control);
+        }
+      }
+
+      ev.prevent_default();
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::form
+      on:submit=move |ev: SubmitEvent| {
+        on_submit.call(ev);
+        on_clear_server_errors.call(());
+      }
+      on:reset=move |ev: Event| {
+        on_reset.call(ev);
+        on_clear_server_errors.call(());
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormField(
+  #[prop(into)] name: MaybeSignal<String>,
+  #[prop(optional, into)] server_invalid: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let FormContextValue { validity_map, .. } =
+    use_context().expect("FormField must be used in a FormRoot component");
+
+  let name = Signal::derive(move || name.get());
+  let server_invalid = Signal::derive(move || server_invalid.get());
+
+  provide_context(FormFieldContextValue {
+    id: create_id(),
+    name,
+    server_invalid,
+  });
+
+  let validity = Signal::derive(move || {
+    validity_map.with(|validity_map| validity_map.get(&name.get()).copied())
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      attr:data-valid=move || is_valid(validity.get(), server_invalid.get()).then_some("")
+      attr:data-invalid=move || is_invalid(validity.get(), server_invalid.get()).then_some("")
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let FormContextValue { validity_map, .. } =
+    use_context().expect("FormLabel must be used in a FormRoot component");
+  let FormFieldContextValue {
+    id,
+    name,
+    server_invalid,
+  } = use_context().expect("FormLabel must be used in a FormField component");
+
+  let validity = Signal::derive(move || {
+    validity_map.with(|validity_map| validity_map.get(&name.get()).copied())
+  });
+
+  view! {
+    <LabelRoot
+      {..attrs}
+      for_html=Signal::derive(move || Some(id.get()))
+      attr:data-valid=move || is_valid(validity.get(), server_invalid.get()).then_some("")
+      attr:data-invalid=move || is_invalid(validity.get(), server_invalid.get()).then_some("")
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </LabelRoot>
+  }
+}
+
+#[component]
+pub fn FormControl(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context: FormContextValue =
+    use_context().expect("FormControl must be used in a FormRoot component");
+  let FormFieldContextValue {
+    id,
+    name,
+    server_invalid,
+  } = use_context().expect("FormControl must be used in a FormField component");
+
+  let FormContextValue {
+    validity_map,
+    custom_errors_map,
+    message_ids_map,
+    ..
+  } = context;
+
+  let validity = Signal::derive(move || {
+    validity_map.with(|validity_map| validity_map.get(&name.get()).copied())
+  });
+
+  // `CheckboxRoot` and `SwitchRoot` render a button, in which case the hidden input rendered next
+  // to it is the element that carries the constraint validation state
+  let validation_target = Signal::derive(move || {
+    let node = node_ref.get()?;
+
+    if is_native_control(&node) {
+      Some((*node).clone())
+    } else {
+      node
+        .next_element_sibling()
+        .filter(|sibling| sibling.tag_name() == "INPUT")
+        .and_then(|sibling| sibling.dyn_into::<HtmlElement>().ok())
+    }
+  });
+
+  // async matchers can finish after the control has changed again, bumping this on every
+  // validation and reset lets their outdated results be dropped
+  let validation_generation = StoredValue::new(0usize);
+
+  let reset_control_validity = move |control: &HtmlElement| {
+    validation_generation.update_value(|generation| *generation += 1);
+    set_custom_validity(control, "");
+
+    let name = name.get_untracked();
+    validity_map.update(|validity_map| {
+      validity_map.remove(&name);
+    });
+    custom_errors_map.update(|custom_errors_map| {
+      custom_errors_map.remove(&name);
+    });
+  };
+
+  _ = use_event_listener(validation_target, ev::change, move |_: Event| {
+    if let Some(control) = validation_target.get_untracked() {
+      update_control_validity(
+        context,
+        name.get_untracked(),
+        control,
+        validation_generation,
+      );
+    }
+  });
+
+  _ = use_event_listener(
+    validation_target,
+    ev::Custom::<Event>::new("invalid"),
+    move |_: Event| {
+      if let Some(control) = validation_target.get_untracked() {
+        update_control_validity(
+          context,
+          name.get_untracked(),
+          control,
+          validation_generation,
+        );
+      }
+    },
+  );
+
+  // the hidden inputs of `CheckboxRoot` and `SwitchRoot` only ever dispatch `click`
+  _ = use_event_listener(validation_target, ev::click, move |_: MouseEvent| {
+    let Some(control) = validation_target.get_untracked() else {
+      return;
+    };
+
+    if node_ref
+      .get_untracked()
+      .is_some_and(|node| !is_native_control(&node))
+    {
+      update_control_validity(
+        context,
+        name.get_untracked(),
+        control,
+        validation_generation,
+      );
+    }
+  });
+
+  _ = use_event_listener(validation_target, ev::input, move |_: Event| {
+    if let Some(control) = validation_target.get_untracked() {
+      reset_control_validity(&control);
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(control) = validation_target.get() else {
+      return;
+    };
+
+    let Some(form) = get_form(&control) else {
+      return;
+    };
+
+    let reset_control = control.clone();
+    let reset = Closure::<dyn FnMut()>::new(move || {
+      reset_control_validity(&reset_control);
+    });
+
+    _ = form.add_event_listener_with_callback("reset", reset.as_ref().unchecked_ref());
+
+    on_cleanup(move || {
+      _ = form.remove_event_listener_with_callback("reset", reset.as_ref().unchecked_ref());
+    });
+  });
+
+  // a server error is not reflected by the browser, so the control is marked invalid through a
+  // custom validity message until the server errors are cleared
+  Effect::new(move |_| {
+    let Some(control) = validation_target.get() else {
+      return;
+    };
+
+    if server_invalid.get() {
+      set_custom_validity(&control, DEFAULT_INVALID_MESSAGE);
+    } else if !validity
+      .get_untracked()
+      .is_some_and(|validity| validity.custom_error)
+    {
+      set_custom_validity(&control, "");
+    }
+  });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::input
+      attr:id=move || id.get()
+      attr:name=move || name.get()
+      attr:title=""
+      attr:aria-invalid=move || server_invalid.get().then_some("true")
+      attr:aria-describedby=move || {
+        message_ids_map.with(|message_ids_map| {
+          message_ids_map
+            .get(&name.get())
+            .filter(|message_ids| !message_ids.is_empty())
+            .map(|message_ids| message_ids.join(" "))
+        })
+      }
+      attr:data-valid=move || is_valid(validity.get(), server_invalid.get()).then_some("")
+      attr:data-invalid=move || is_invalid(validity.get(), server_invalid.get()).then_some("")
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormMessage(
+  #[prop(optional, into)] matcher: Option<FormMatcher>,
+  #[prop(optional, into)] force_match: MaybeSignal<bool>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let FormContextValue {
+    validity_map,
+    custom_errors_map,
+    custom_matchers_map,
+    ..
+  } = use_context().expect("FormMessage must be used in a FormRoot component");
+  let field_context = use_context::<FormFieldContextValue>();
+
+  let name = name.get_untracked().unwrap_or_else(|| {
+    field_context
+      .expect("FormMessage must be used in a FormField component or be given a name")
+      .name
+      .get_untracked()
+  });
+
+  let id = create_id();
+
+  let is_custom = matches!(
+    matcher,
+    Some(FormMatcher::Custom(_) | FormMatcher::CustomAsync(_))
+  );
+
+  if let Some(matcher) = matcher.clone().filter(|_| is_custom) {
+    let matcher_name = name.clone();
+    let matcher_id = id.get_untracked();
+
+    custom_matchers_map.update_value(|custom_matchers_map| {
+      custom_matchers_map
+        .entry(matcher_name.clone())
+        .or_default()
+        .push((matcher_id.clone(), matcher));
+    });
+
+    on_cleanup(move || {
+      custom_matchers_map.update_value(|custom_matchers_map| {
+        if let Some(matchers) = custom_matchers_map.get_mut(&matcher_name) {
+          matchers.retain(|(id, _)| *id != matcher_id);
+        }
+      });
+    });
+  }
+
+  let default_message = matcher
+    .as_ref()
+    .map_or(Some(DEFAULT_INVALID_MESSAGE), |matcher| {
+      matcher.default_message()
+    });
+  let matcher = StoredValue::new(matcher);
+  let name = StoredValue::new(name);
+
+  let is_matching = Signal::derive(move || {
+    if force_match.get() {
+      return true;
+    }
+
+    matcher.with_value(|matcher| match matcher {
+      None => true,
+      Some(FormMatcher::Custom(_) | FormMatcher::CustomAsync(_)) => {
+        custom_errors_map.with(|custom_errors_map| {
+          name.with_value(|name| {
+            custom_errors_map
+              .get(name)
+              .and_then(|custom_errors| custom_errors.get(&id.get()))
+              .copied()
+              .unwrap_or(false)
+          })
+        })
+      }
+      Some(matcher) => validity_map.with(|validity_map| {
+        name.with_value(|name| {
+          validity_map
+            .get(name)
+            .and_then(|validity| matcher.matches_validity(validity))
+            .unwrap_or(false)
+        })
+      }),
+    })
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_matching.get()>
+      <FormMessageImpl
+        {..attrs.get_value()}
+        id=id
+        name=name.get_value()
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| match children {
+          Some(children) => children().into_view(),
+          None => default_message.into_view(),
+        })}
+      </FormMessageImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn FormMessageImpl(
+  id: Signal<String>,
+  name: String,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let FormContextValue {
+    message_ids_map, ..
+  } = use_context().expect("FormMessage must be used in a FormRoot component");
+
+  let message_id = id.get_untracked();
+
+  message_ids_map.update({
+    let name = name.clone();
+    let message_id = message_id.clone();
+
+    move |message_ids_map| {
+      message_ids_map.entry(name).or_default().push(message_id);
+    }
+  });
+
+  on_cleanup(move || {
+    message_ids_map.update(|message_ids_map| {
+      if let Some(message_ids) = message_ids_map.get_mut(&name) {
+        message_ids.retain(|id| *id != message_id);
+      }
+    });
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::span
+      attr:id=move || id.get()
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormValidityState<F, IV>(
+  #[prop(optional, into)] name: MaybeProp<String>,
+
+  children: F,
+) -> impl IntoView
+where
+  F: Fn(Option<FormValidity>) -> IV + 'static,
+  IV: IntoView,
+{
+  let FormContextValue { validity_map, .. } =
+    use_context().expect("FormValidityState must be used in a FormRoot component");
+  let field_context = use_context::<FormFieldContextValue>();
+
+  let name = Signal::derive(move || {
+    name.get().unwrap_or_else(|| {
+      field_context
+        .as_ref()
+        .expect("FormValidityState must be used in a FormField component or be given a name")
+        .name
+        .get()
+    })
+  });
+
+  move || children(validity_map.with(|validity_map| validity_map.get(&name.get()).copied()))
+}
+
+#[component]
+pub fn FormSubmit(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::button
+      attr:type="submit"
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn update_control_validity(
+  context: FormContextValue,
+  name: String,
+  control: HtmlElement,
+  validation_generation: StoredValue<usize>,
+) {
+  validation_generation.update_value(|generation| *generation += 1);
+  let generation = validation_generation.get_value();
+
+  let Some(validity) = get_validity(&control) else {
+    return;
+  };
+
+  if validity.has_built_in_error() {
+    context.validity_map.update(|validity_map| {
+      validity_map.insert(name, validity);
+    });
+    return;
+  }
+
+  let form_data = get_form(&control)
+    .and_then(|form| FormData::new_with_form(&form).ok())
+    .or_else(|| FormData::new().ok());
+  let Some(form_data) = form_data else {
+    return;
+  };
+
+  let value = Reflect::get(&control, &JsString::from("value"))
+    .ok()
+    .and_then(|value| value.as_string())
+    .unwrap_or_default();
+
+  let (sync_matchers, async_matchers): (Vec<_>, Vec<_>) = context
+    .custom_matchers_map
+    .with_value(|custom_matchers_map| custom_matchers_map.get(&name).cloned().unwrap_or_default())
+    .into_iter()
+    .partition(|(_, matcher)| matches!(matcher, FormMatcher::Custom(_)));
+
+  let sync_errors = sync_matchers
+    .into_iter()
+    .filter_map(|(id, matcher)| match matcher {
+      FormMatcher::Custom(matcher) => Some((id, matcher(value.clone(), form_data.clone()))),
+      _ => None,
+    })
+    .collect::<HashMap<_, _>>();
+  let has_sync_errors = sync_errors.values().any(|error| *error);
+
+  set_custom_validity(
+    &control,
+    if has_sync_errors {
+      DEFAULT_INVALID_MESSAGE
+    } else {
+      ""
+    },
+  );
+
+  if let Some(validity) = get_validity(&control) {
+    context.validity_map.update(|validity_map| {
+      validity_map.insert(name.clone(), validity);
+    });
+  }
+  context.custom_errors_map.update(|custom_errors_map| {
+    custom_errors_map.insert(name.clone(), sync_errors);
+  });
+
+  if has_sync_errors || async_matchers.is_empty() {
+    return;
+  }
+
+  spawn_local(async move {
+    let mut async_errors = HashMap::new();
+
+    for (id, matcher) in async_matchers {
+      if let FormMatcher::CustomAsync(matcher) = matcher {
+        async_errors.insert(id, matcher(value.clone(), form_data.clone()).await);
+      }
+    }
+
+    if validation_generation.try_get_value() != Some(generation) {
+      return;
+    }
+
+    let has_async_errors = async_errors.values().any(|error| *error);
+
+    set_custom_validity(
+      &control,
+      if has_async_errors {
+        DEFAULT_INVALID_MESSAGE
+      } else {
+        ""
+      },
+    );
+
+    if let Some(validity) = get_validity(&control) {
+      context.validity_map.update(|validity_map| {
+        validity_map.insert(name.clone(), validity);
+      });
+    }
+    context.custom_errors_map.update(|custom_errors_map| {
+      custom_errors_map.insert(name, async_errors);
+    });
+  });
+}
+
+fn is_valid(validity: Option<FormValidity>, server_invalid: bool) -> bool {
+  !server_invalid && validity.is_some_and(|validity| validity.valid)
+}
+
+fn is_invalid(validity: Option<FormValidity>, server_invalid: bool) -> bool {
+  server_invalid || validity.is_some_and(|validity| !validity.valid)
+}
+
+fn is_native_control(node: &HtmlElement) -> bool {
+  matches!(node.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA")
+}
+
+fn get_form(control: &HtmlElement) -> Option<HtmlFormElement> {
+  Reflect::get(control, &JsString::from("form"))
+    .ok()?
+    .dyn_into::<HtmlFormElement>()
+    .ok()
+}
+
+fn get_validity(control: &HtmlElement) -> Option<FormValidity> {
+  let validity = Reflect::get(control, &JsString::from("validity")).ok()?;
+
+  if validity.is_undefined() {
+    return None;
+  }
+
+  let get = |key: &str| {
+    Reflect::get(&validity, &JsString::from(key))
+      .ok()
+      .and_then(|value| value.as_bool())
+      .unwrap_or(false)
+  };
+
+  Some(FormValidity {
+    bad_input: get("badInput"),
+    custom_error: get("customError"),
+    pattern_mismatch: get("patternMismatch"),
+    range_overflow: get("rangeOverflow"),
+    range_underflow: get("rangeUnderflow"),
+    step_mismatch: get("stepMismatch"),
+    too_long: get("tooLong"),
+    too_short: get("tooShort"),
+    type_mismatch: get("typeMismatch"),
+    valid: get("valid"),
+    value_missing: get("valueMissing"),
+  })
+}
+
+fn set_custom_validity(control: &HtmlElement, message: &str) {
+  let Some(set_custom_validity) = Reflect::get(control, &JsString::from("setCustomValidity"))
+    .ok()
+    .and_then(|function| function.dyn_into::<Function>().ok())
+  else {
+    return;
+  };
+
+  _ = set_custom_validity.call1(control, &JsValue::from_str(message));
+}
+
+fn get_first_invalid_control(form: &HtmlElement) -> Option<HtmlElement> {
+  let controls = form.query_selector_all("input, select, textarea").ok()?;
+
+  (0..controls.length())
+    .filter_map(|index| controls.item(index))
+    .filter_map(|control| control.dyn_into::<HtmlElement>().ok())
+    .find(|control| {
+      get_validity(control).is_some_and(|validity| !validity.valid)
+        || control.get_attribute("aria-invalid").as_deref() == Some("true")
+    })
+}
+
+fn focus_control(control: &HtmlElement) {
+  // the hidden inputs of `CheckboxRoot` and `SwitchRoot` cannot be focused meaningfully, so the
+  // button rendered right before them is focused instead
+  if control.has_attribute("aria-hidden") {
+    if let Some(button) = control
+      .previous_element_sibling()
+      .and_then(|sibling| sibling.dyn_into::<HtmlElement>().ok())
+    {
+      _ = button.focus();
+      return;
+    }
+  }
+
+  _ = control.focus();
+}
+
+#[test]
+fn built_in_matchers_read_validity() {
+  let validity = FormValidity {
+    value_missing: true,
+    ..Default::default()
+  };
+
+  assert!(validity.has_built_in_error());
+  assert_eq!(
+    FormMatcher::ValueMissing.matches_validity(&validity),
+    Some(true)
+  );
+  assert_eq!(
+    FormMatcher::TooShort.matches_validity(&validity),
+    Some(false)
+  );
+  assert_eq!(FormMatcher::Valid.matches_validity(&validity), Some(false));
+  assert_eq!(
+    FormMatcher::custom(|_, _| true).matches_validity(&validity),
+    None
+  );
+}
+
+#[test]
+fn custom_errors_are_not_built_in() {
+  let validity = FormValidity {
+    custom_error: true,
+    ..Default::default()
+  };
+
+  assert!(!validity.has_built_in_error());
+  assert_eq!(FormMatcher::Valid.default_message(), None);
+  assert_eq!(
+    FormMatcher::custom(|_, _| false).default_message(),
+    Some(DEFAULT_INVALID_MESSAGE)
+  );
+}
@@ -0,0 +1,410 @@
+use std::time::Duration;
+
+use leptos::{ev::pointerup, html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use leptos_use::{use_document, use_event_listener};
+use web_sys::{CustomEvent, FocusEvent, KeyboardEvent, PointerEvent, TouchEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  focus_scope::get_tabbable_candidates,
+  popper::{PopperAnchor, PopperArrow, PopperContent, PopperRoot},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  Align, Side, Sticky,
+};
+
+#[derive(Clone)]
+struct HoverCardContextValue {
+  open: Signal<bool>,
+  has_selection: StoredValue<bool>,
+  is_pointer_down_on_content: StoredValue<bool>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+  on_dismiss: Callback<()>,
+}
+
+#[derive(Clone)]
+struct HoverCardPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn HoverCardRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(default=700.into(), into)] open_delay: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] close_delay: MaybeSignal<u64>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let has_selection = StoredValue::new(false);
+  let is_pointer_down_on_content = StoredValue::new(false);
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+    if let Some(handle) = timer.get_value() {
+      handle.clear();
+      timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    for timer in [open_timer, close_timer] {
+      if let Some(Some(handle)) = timer.try_get_value() {
+        handle.clear();
+      }
+    }
+  });
+
+  provide_context(HoverCardContextValue {
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    has_selection,
+    is_pointer_down_on_content,
+    on_open: Callback::new(move |_| {
+      clear_timer(close_timer);
+
+      let Ok(handle) = set_timeout_with_handle(
+        move || set_open.set(true),
+        Duration::from_millis(open_delay.get_untracked()),
+      ) else {
+        return;
+      };
+
+      open_timer.set_value(Some(handle));
+    }),
+    on_close: Callback::new(move |_| {
+      clear_timer(open_timer);
+
+      // selecting text inside of the card shouldn't close it when the pointer drifts away
+      if has_selection.get_value() || is_pointer_down_on_content.get_value() {
+        return;
+      }
+
+      let Ok(handle) = set_timeout_with_handle(
+        move || set_open.set(false),
+        Duration::from_millis(close_delay.get_untracked()),
+      ) else {
+        return;
+      };
+
+      close_timer.set_value(Some(handle));
+    }),
+    on_dismiss: Callback::new(move |_| set_open.set(false)),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn HoverCardTrigger(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let HoverCardContextValue {
+    open,
+    on_open,
+    on_close,
+    ..
+  } = use_context().expect("HoverCardTrigger must be used in a HoverCardRoot component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true>
+      <Primitive
+        {..attrs.get_value()}
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        element=html::a
+        on:pointerenter=move |ev: PointerEvent| {
+          if ev.pointer_type() != "touch" {
+            on_open.call(());
+          }
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          if ev.pointer_type() != "touch" {
+            on_close.call(());
+          }
+        }
+        on:focus=move |_: FocusEvent| on_open.call(())
+        on:blur=move |_: FocusEvent| on_close.call(())
+        on:touchstart=move |ev: TouchEvent| ev.prevent_default()
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn HoverCardPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(HoverCardPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+#[component]
+pub fn HoverCardContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let HoverCardContextValue {
+    open,
+    has_selection,
+    is_pointer_down_on_content,
+    on_open,
+    on_close,
+    on_dismiss,
+  } = use_context().expect("HoverCardContent must be used in a HoverCardRoot component");
+
+  let portal_force_mount = use_context::<HoverCardPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || open.get() || force_mount.get() || portal_force_mount.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let (contain_selection, set_contain_selection) = create_signal(false);
+
+  Effect::new(move |_| {
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    // keeps a selection started inside of the card from spilling out into the rest of the page
+    if contain_selection.get() {
+      let original_user_select = body
+        .style()
+        .get_property_value("user-select")
+        .unwrap_or_default();
+
+      _ = body.style().set_property("user-select", "none");
+
+      on_cleanup(move || {
+        _ = body
+          .style()
+          .set_property("user-select", &original_user_select);
+      });
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerup, move |_| {
+    set_contain_selection.set(false);
+    is_pointer_down_on_content.set_value(false);
+
+    // the selection only settles after the pointer is released
+    set_timeout(
+      move || {
+        let has_text_selection = document()
+          .get_selection()
+          .ok()
+          .flatten()
+          .and_then(|selection| selection.to_string().as_string())
+          .map(|selection| !selection.is_empty())
+          .unwrap_or(false);
+
+        if has_text_selection {
+          _ = has_selection.try_set_value(true);
+        }
+      },
+      Duration::ZERO,
+    );
+  });
+
+  on_cleanup(move || {
+    _ = has_selection.try_set_value(false);
+    _ = is_pointer_down_on_content.try_set_value(false);
+  });
+
+  // the card is only a preview of the trigger, so its contents are kept out of the tab order
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    for candidate in get_tabbable_candidates(&node) {
+      _ = candidate.set_attribute("tabindex", "-1");
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("user-select", move || {
+        contain_selection.get().then_some("text")
+      })
+      .style(
+        "--leptix-hover-card-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-hover-card-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-hover-card-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-hover-card-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-hover-card-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <PopperContent
+        as_child=true
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        node_ref=node_ref
+      >
+        <DismissableLayer
+          as_child=true
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=move |ev: CustomEvent| {
+            on_focus_outside.call(ev.clone());
+            ev.prevent_default();
+          }
+          on_interact_outside=on_interact_outside
+          on_dismiss=move |_| on_dismiss.call(())
+        >
+          <Primitive
+            {..attrs.get_value()}
+            attr:data-state=move || if open.get() { "open" } else { "closed" }
+            element=html::div
+            on:pointerenter=move |ev: PointerEvent| {
+              if ev.pointer_type() != "touch" {
+                on_open.call(());
+              }
+            }
+            on:pointerleave=move |ev: PointerEvent| {
+              if ev.pointer_type() != "touch" {
+                on_close.call(());
+              }
+            }
+            on:pointerdown=move |_: PointerEvent| {
+              set_contain_selection.set(true);
+              has_selection.set_value(false);
+              is_pointer_down_on_content.set_value(true);
+            }
+            node_ref=node_ref
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+          </Primitive>
+        </DismissableLayer>
+      </PopperContent>
+    </Show>
+  }
+}
+
+#[component]
+pub fn HoverCardArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </PopperArrow>
+    },
+    None => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
@@ -0,0 +1,1547 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{keydown, pointerdown, pointermove},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{use_document, use_event_listener_with_options, UseEventListenerOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  CustomEvent, CustomEventInit, Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent,
+};
+
+use crate::{
+  aria_hidden::use_hide_others,
+  checkbox::CheckedState,
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
+  dismissable_layer::{get_original_event, DismissableLayer},
+  focus_guards::use_focus_guards,
+  focus_scope::FocusScope,
+  popper::{PopperAnchor, PopperArrow, PopperContent, PopperRoot, PopperVirtualAnchor, Rect},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
+  scroll_lock::use_body_scroll_lock,
+  util::{
+    create_id::create_id,
+    get_next_match,
+    polygon::{is_point_in_polygon, Point, Polygon},
+    Attributes,
+  },
+  Align, Direction, Orientation, Side, Sticky,
+};
+
+const SELECTION_KEYS: [&str; 2] = ["Enter", " "];
+const FIRST_KEYS: [&str; 3] = ["ArrowDown", "PageUp", "Home"];
+const LAST_KEYS: [&str; 3] = ["ArrowUp", "PageDown", "End"];
+
+const ITEM_SELECT: &str = "menu.item_select";
+
+fn get_sub_open_keys(direction: Direction) -> [&'static str; 3] {
+  match direction {
+    Direction::LeftToRight => ["Enter", " ", "ArrowRight"],
+    Direction::RightToLeft => ["Enter", " ", "ArrowLeft"],
+  }
+}
+
+fn get_sub_close_key(direction: Direction) -> &'static str {
+  match direction {
+    Direction::LeftToRight => "ArrowLeft",
+    Direction::RightToLeft => "ArrowRight",
+  }
+}
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+struct MenuItemData {
+  id: String,
+  disabled: Signal<bool>,
+  text_value: Signal<Option<String>>,
+}
+
+impl Ord for MenuItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.id.cmp(&other.id)
+  }
+}
+
+impl PartialOrd for MenuItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct MenuRootContextValue {
+  is_using_keyboard: StoredValue<bool>,
+  direction: Signal<Direction>,
+  modal: Signal<bool>,
+  on_close: Callback<()>,
+}
+
+#[derive(Clone)]
+struct MenuContextValue {
+  open: Signal<bool>,
+  content_ref: NodeRef<AnyElement>,
+  on_open_change: Callback<bool>,
+}
+
+#[derive(Clone)]
+struct MenuSubContextValue {
+  content_id: Signal<String>,
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+}
+
+#[derive(Clone)]
+struct GraceIntent {
+  area: Polygon,
+  side: Side,
+}
+
+#[derive(Clone)]
+struct MenuContentContextValue {
+  search: StoredValue<String>,
+  pointer_grace_timer: StoredValue<Option<TimeoutHandle>>,
+  on_item_enter: Callback<PointerEvent>,
+  on_item_leave: Callback<PointerEvent>,
+  on_trigger_leave: Callback<PointerEvent>,
+  on_pointer_grace_intent_change: Callback<Option<GraceIntent>>,
+}
+
+#[derive(Clone)]
+struct MenuPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub(crate) fn MenuRoot(
+  #[prop(optional, into)] open: MaybeSignal<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let is_using_keyboard = StoredValue::new(false);
+
+  // pointer interactions shouldn't move focus into the menu the way keyboard interactions do
+  _ = use_event_listener_with_options(
+    use_document(),
+    keydown,
+    move |_| is_using_keyboard.set_value(true),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointerdown,
+    move |_| is_using_keyboard.set_value(false),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointermove,
+    move |_| is_using_keyboard.set_value(false),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  provide_context(MenuRootContextValue {
+    is_using_keyboard,
+    direction: Signal::derive(move || direction.get()),
+    modal: Signal::derive(move || modal.get()),
+    on_close: Callback::new(move |_| on_open_change.call(false)),
+  });
+
+  provide_context(MenuContextValue {
+    open: Signal::derive(move || open.get()),
+    content_ref: NodeRef::new(),
+    on_open_change,
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub(crate) fn MenuAnchor(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <PopperAnchor
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub(crate) fn MenuVirtualAnchor(#[prop(into)] rect: Signal<Rect>) -> impl IntoView {
+  view! {
+    <PopperVirtualAnchor rect=rect/>
+  }
+}
+
+#[component]
+pub(crate) fn MenuPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(MenuPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+fn use_menu_is_present(force_mount: MaybeSignal<bool>) -> Signal<bool> {
+  let MenuContextValue { open, .. } =
+    use_context().expect("menu content must be used in a MenuRoot component");
+
+  let portal_force_mount = use_context::<MenuPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  Signal::derive(move || open.get() || force_mount.get() || portal_force_mount.get())
+}
+
+#[component]
+pub(crate) fn MenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open,
+    on_open_change,
+    ..
+  } = use_context().expect("MenuContent must be used in a MenuRoot component");
+  let MenuRootContextValue { modal, .. } =
+    use_context().expect("MenuContent must be used in a MenuRoot component");
+
+  let is_present = use_menu_is_present(force_mount);
+  let presence = create_presence(is_present, node_ref);
+
+  let is_modal_open = Signal::derive(move || modal.get() && open.get());
+
+  use_body_scroll_lock(is_modal_open);
+  use_hide_others(node_ref, is_modal_open);
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <MenuContentImpl
+        {..attrs.get_value()}
+        should_loop=should_loop
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        trap_focus=is_modal_open
+        disable_outside_pointer_events=is_modal_open
+        on_open_auto_focus=|_| {}
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=move |ev: CustomEvent| {
+          on_focus_outside.call(ev.clone());
+
+          // modal menus stay open while focus moves elsewhere
+          if modal.get_untracked() {
+            ev.prevent_default();
+          }
+        }
+        on_interact_outside=on_interact_outside
+        on_key_down=on_key_down
+        on_dismiss=move |_| on_open_change.call(false)
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuContentImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn MenuContentImpl(
+  #[prop(into)] should_loop: MaybeSignal<bool>,
+  #[prop(into)] side: MaybeSignal<Side>,
+  #[prop(into)] side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  #[prop(into)] align_offset: MaybeSignal<f64>,
+  #[prop(into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(into)] collision_padding: MaybeSignal<f64>,
+  #[prop(into)] sticky: MaybeSignal<Sticky>,
+  #[prop(into)] hide_when_detached: MaybeSignal<bool>,
+  #[prop(optional, into)] trap_focus: MaybeSignal<bool>,
+  #[prop(optional, into)] disable_outside_pointer_events: MaybeSignal<bool>,
+
+  #[prop(into)] on_open_auto_focus: Callback<Event>,
+  #[prop(into)] on_close_auto_focus: Callback<Event>,
+  #[prop(into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(into)] on_interact_outside: Callback<CustomEvent>,
+  #[prop(into)] on_dismiss: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open, content_ref, ..
+  } = use_context().expect("MenuContentImpl must be used in a MenuRoot component");
+  let MenuRootContextValue {
+    is_using_keyboard,
+    direction,
+    ..
+  } = use_context().expect("MenuContentImpl must be used in a MenuRoot component");
+
+  provide_context(CollectionContextValue::<MenuItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let get_items = use_collection_context::<MenuItemData, AnyElement>();
+
+  use_focus_guards();
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    content_ref.load(&node);
+  });
+
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let pointer_grace_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let pointer_grace_intent = StoredValue::<Option<GraceIntent>>::new(None);
+  let pointer_direction = StoredValue::new(Side::Right);
+  let last_pointer_x = StoredValue::new(0);
+
+  on_cleanup(move || {
+    for timer in [search_timer, pointer_grace_timer] {
+      if let Some(Some(handle)) = timer.try_get_value() {
+        handle.clear();
+      }
+    }
+  });
+
+  let update_search = move |value: String| {
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+      search_timer.set_value(None);
+    }
+
+    search.set_value(value.clone());
+
+    // resets the search after a second of inactivity
+    if !value.is_empty() {
+      if let Ok(handle) = set_timeout_with_handle(
+        move || search.set_value(String::new()),
+        Duration::from_millis(1000),
+      ) {
+        search_timer.set_value(Some(handle));
+      }
+    }
+  };
+
+  let handle_typeahead_search = move |key: String| {
+    let next_search = search.get_value() + &key;
+
+    let items = get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, item)| !item.disabled.get_untracked())
+      .filter_map(|(node, item)| {
+        let node = node.get_untracked()?;
+        let text_value = item
+          .text_value
+          .get_untracked()
+          .unwrap_or_else(|| node.text_content().unwrap_or_default().trim().to_string());
+
+        Some((node, text_value))
+      })
+      .collect::<Vec<_>>();
+
+    let active_element = document().active_element();
+
+    let current_match = items
+      .iter()
+      .find(|(node, _)| {
+        let node: &web_sys::Element = node;
+        Some(node) == active_element.as_ref()
+      })
+      .map(|(_, text_value)| text_value.clone());
+
+    let values = items
+      .iter()
+      .map(|(_, text_value)| text_value.clone())
+      .collect::<Vec<_>>();
+
+    let next_item = get_next_match(&values, &next_search, current_match.as_deref())
+      .and_then(|next_match| {
+        items
+          .iter()
+          .find(|(_, text_value)| *text_value == next_match)
+      })
+      .map(|(node, _)| node.clone());
+
+    update_search(next_search);
+
+    if let Some(next_item) = next_item {
+      set_timeout(
+        move || {
+          _ = next_item.focus();
+        },
+        Duration::ZERO,
+      );
+    }
+  };
+
+  let is_pointer_moving_to_submenu = move |ev: &PointerEvent| {
+    pointer_grace_intent.with_value(|intent| {
+      intent
+        .as_ref()
+        .map(|intent| {
+          let is_moving_towards = pointer_direction.get_value() == intent.side;
+          let position = Point {
+            x: ev.client_x() as f64,
+            y: ev.client_y() as f64,
+          };
+
+          is_moving_towards && is_point_in_polygon(position, &intent.area)
+        })
+        .unwrap_or(false)
+    })
+  };
+
+  provide_context(MenuContentContextValue {
+    search,
+    pointer_grace_timer,
+    on_item_enter: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        ev.prevent_default();
+      }
+    }),
+    on_item_leave: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        return;
+      }
+
+      if let Some(content) = node_ref.get_untracked() {
+        _ = content.focus();
+      }
+    }),
+    on_trigger_leave: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        ev.prevent_default();
+      }
+    }),
+    on_pointer_grace_intent_change: Callback::new(move |intent| {
+      pointer_grace_intent.set_value(intent);
+    }),
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperContent
+      as_child=true
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      node_ref=node_ref
+    >
+      <FocusScope
+        as_child=true
+        trapped=trap_focus
+        on_mount_auto_focus=move |ev: Event| {
+          on_open_auto_focus.call(ev.clone());
+
+          // the content receives focus rather than its first item so pointer users don't see a
+          // highlighted item they didn't hover
+          if !ev.default_prevented() {
+            ev.prevent_default();
+
+            if let Some(content) = node_ref.get_untracked() {
+              _ = content.focus();
+            }
+          }
+        }
+        on_unmount_auto_focus=on_close_auto_focus
+      >
+        <DismissableLayer
+          as_child=true
+          on_escape_key_down=on_escape_key_down
+          disable_outside_pointer_events=disable_outside_pointer_events
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=on_focus_outside
+          on_interact_outside=on_interact_outside
+          on_dismiss=on_dismiss
+        >
+          <RovingFocusGroup
+            as_child=true
+            orientation=Orientation::Vertical
+            direction=direction
+            should_loop=should_loop
+            on_entry_focus=move |ev: Event| {
+              // only keyboard users should have the first item focused when the content receives focus
+              if !is_using_keyboard.get_value() {
+                ev.prevent_default();
+              }
+            }
+          >
+            <Primitive
+              {..attrs.get_value()}
+              attr:role="menu"
+              attr:aria-orientation="vertical"
+              attr:data-state=move || if open.get() { "open" } else { "closed" }
+              attr:data-leptix-menu-content=""
+              attr:dir=move || direction.get().to_string()
+              element=html::div
+              on:keydown=move |ev: KeyboardEvent| {
+                on_key_down.call(ev.clone());
+
+                let Some(current_target) = ev
+                  .current_target()
+                  .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                else {
+                  return;
+                };
+
+                let target = ev
+                  .target()
+                  .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+
+                let is_key_down_inside = target
+                  .as_ref()
+                  .and_then(|target| target.closest("[data-leptix-menu-content]").ok().flatten())
+                  .map(|content| content == current_target)
+                  .unwrap_or(false);
+
+                let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+                let key = ev.key();
+                let is_character_key = key.chars().count() == 1;
+
+                if is_key_down_inside {
+                  // menus don't participate in the tab order
+                  if key == "Tab" {
+                    ev.prevent_default();
+                  }
+
+                  if !is_modifier_key && is_character_key {
+                    handle_typeahead_search(key.clone());
+                  }
+                }
+
+                if target.as_ref() != Some(&current_target) {
+                  return;
+                }
+
+                if !FIRST_KEYS.contains(&key.as_str()) && !LAST_KEYS.contains(&key.as_str()) {
+                  return;
+                }
+
+                ev.prevent_default();
+
+                let mut candidates = get_items
+                  .get_untracked()
+                  .into_iter()
+                  .filter(|(_, item)| !item.disabled.get_untracked())
+                  .filter_map(|(node, _)| node.get_untracked())
+                  .collect::<Vec<_>>();
+
+                if LAST_KEYS.contains(&key.as_str()) {
+                  candidates.reverse();
+                }
+
+                let previously_focused = document().active_element();
+
+                for candidate in candidates {
+                  _ = candidate.focus();
+
+                  if document().active_element() != previously_focused {
+                    break;
+                  }
+                }
+              }
+              on:blur=move |ev: FocusEvent| {
+                let Some(current_target) = ev
+                  .current_target()
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                else {
+                  return;
+                };
+
+                let related_target = ev
+                  .related_target()
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+                // clear the search once focus leaves the menu
+                if !current_target.contains(related_target.as_ref()) {
+                  if let Some(handle) = search_timer.get_value() {
+                    handle.clear();
+                    search_timer.set_value(None);
+                  }
+
+                  search.set_value(String::new());
+                }
+              }
+              on:pointermove=move |ev: PointerEvent| {
+                if ev.pointer_type() != "mouse" {
+                  return;
+                }
+
+                let Some(current_target) = ev
+                  .current_target()
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                else {
+                  return;
+                };
+
+                let target = ev
+                  .target()
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+                let pointer_x = ev.client_x();
+
+                if current_target.contains(target.as_ref()) && last_pointer_x.get_value() != pointer_x {
+                  pointer_direction.set_value(if pointer_x > last_pointer_x.get_value() {
+                    Side::Right
+                  } else {
+                    Side::Left
+                  });
+
+                  last_pointer_x.set_value(pointer_x);
+                }
+              }
+              node_ref=node_ref
+              as_child=as_child
+            >
+              {children.with_value(|children| children())}
+            </Primitive>
+          </RovingFocusGroup>
+        </DismissableLayer>
+      </FocusScope>
+    </PopperContent>
+  }
+}
+
+#[component]
+pub(crate) fn MenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub(crate) fn MenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn create_item_select_event() -> Option<CustomEvent> {
+  let init = CustomEventInit::new();
+  init.set_bubbles(true);
+  init.set_cancelable(true);
+
+  CustomEvent::new_with_event_init_dict(ITEM_SELECT, &init).ok()
+}
+
+#[component]
+pub(crate) fn MenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+  #[prop(default = "menuitem")] role: &'static str,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuRootContextValue { on_close, .. } =
+    use_context().expect("MenuItem must be used in a MenuRoot component");
+  let MenuContentContextValue { search, .. } =
+    use_context().expect("MenuItem must be used in a MenuContent component");
+
+  let is_pointer_down = StoredValue::new(false);
+
+  let handle_select = move || {
+    if disabled.get_untracked() {
+      return;
+    }
+
+    let Some(select_event) = create_item_select_event() else {
+      return;
+    };
+
+    on_select.call(select_event.clone().into());
+
+    if select_event.default_prevented() {
+      is_pointer_down.set_value(false);
+    } else {
+      on_close.call(());
+    }
+  };
+
+  let click_current_target = move |ev: &Event| {
+    if let Some(current_target) = ev
+      .current_target()
+      .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+      current_target.click();
+    }
+  };
+
+  view! {
+    <MenuItemImpl
+      {..attrs}
+      role=role
+      disabled=disabled
+      text_value=text_value
+      on:click=move |_| handle_select()
+      on:pointerdown=move |_: PointerEvent| is_pointer_down.set_value(true)
+      on:pointerup=move |ev: PointerEvent| {
+        // items can be selected by pressing the trigger and releasing the pointer over them
+        if !is_pointer_down.get_value() {
+          click_current_target(&ev);
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        let is_typing_ahead = !search.get_value().is_empty();
+
+        if disabled.get_untracked() || (is_typing_ahead && ev.key() == " ") {
+          return;
+        }
+
+        if SELECTION_KEYS.contains(&ev.key().as_str()) {
+          click_current_target(&ev);
+
+          // keeps space from scrolling the page and enter from submitting forms
+          ev.prevent_default();
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItemImpl>
+  }
+}
+
+#[component]
+fn MenuItemImpl(
+  #[prop(into)] disabled: MaybeSignal<bool>,
+  #[prop(into)] text_value: MaybeProp<String>,
+  role: &'static str,
+
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContentContextValue {
+    on_item_enter,
+    on_item_leave,
+    ..
+  } = use_context().expect("MenuItem must be used in a MenuContent component");
+
+  let (is_focused, set_is_focused) = create_signal(false);
+
+  use_collection_item_ref::<AnyElement, MenuItemData>(
+    node_ref,
+    MenuItemData {
+      id: create_id().get_untracked(),
+      disabled: Signal::derive(move || disabled.get()),
+      text_value: Signal::derive(move || text_value.get()),
+    },
+  );
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <RovingFocusGroupItem
+      as_child=true
+      focusable=Signal::derive(move || !disabled.get())
+    >
+      <Primitive
+        {..attrs.get_value()}
+        attr:role=role
+        attr:aria-disabled=move || disabled.get().then_some("true")
+        attr:data-disabled=move || disabled.get().then_some("")
+        attr:data-highlighted=move || is_focused.get().then_some("")
+        element=html::div
+        on:pointermove=move |ev: PointerEvent| {
+          on_pointer_move.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          if disabled.get_untracked() {
+            on_item_leave.call(ev);
+            return;
+          }
+
+          on_item_enter.call(ev.clone());
+
+          if !ev.default_prevented() {
+            if let Some(item) = ev
+              .current_target()
+              .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+            {
+              _ = item.focus();
+            }
+          }
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          on_pointer_leave.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          on_item_leave.call(ev);
+        }
+        on:focus=move |_: FocusEvent| set_is_focused.set(true)
+        on:blur=move |_: FocusEvent| set_is_focused.set(false)
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </RovingFocusGroupItem>
+  }
+}
+
+#[derive(Clone)]
+struct MenuItemIndicatorContextValue {
+  checked: Signal<CheckedState>,
+}
+
+fn get_checked_state_attribute(checked: CheckedState) -> &'static str {
+  match checked {
+    CheckedState::Checked(true) => "checked",
+    CheckedState::Checked(false) => "unchecked",
+    CheckedState::Indeterminate => "indeterminate",
+  }
+}
+
+#[component]
+pub(crate) fn MenuCheckboxItem(
+  #[prop(default=CheckedState::Checked(false).into(), into)] checked: MaybeSignal<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let checked = Signal::derive(move || checked.get());
+
+  provide_context(MenuItemIndicatorContextValue { checked });
+
+  view! {
+    <MenuItem
+      {..attrs}
+      attr:aria-checked=move || match checked.get() {
+        CheckedState::Checked(checked) => checked.to_string(),
+        CheckedState::Indeterminate => "mixed".to_string(),
+      }
+      attr:data-state=move || get_checked_state_attribute(checked.get())
+      role="menuitemcheckbox"
+      disabled=disabled
+      text_value=text_value
+      on_select=move |ev: Event| {
+        on_select.call(ev);
+
+        on_checked_change.call(match checked.get_untracked() {
+          CheckedState::Checked(checked) => CheckedState::Checked(!checked),
+          CheckedState::Indeterminate => CheckedState::Checked(true),
+        });
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[derive(Clone)]
+struct MenuRadioGroupContextValue {
+  value: Signal<Option<String>>,
+  on_value_change: Callback<String>,
+}
+
+#[component]
+pub(crate) fn MenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  provide_context(MenuRadioGroupContextValue {
+    value: Signal::derive(move || value.get()),
+    on_value_change,
+  });
+
+  view! {
+    <MenuGroup
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub(crate) fn MenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuRadioGroupContextValue {
+    value: group_value,
+    on_value_change,
+  } = use_context().expect("MenuRadioItem must be used in a MenuRadioGroup component");
+
+  let value = Signal::derive(move || value.get());
+  let checked = Signal::derive(move || group_value.get() == Some(value.get()));
+
+  provide_context(MenuItemIndicatorContextValue {
+    checked: Signal::derive(move || CheckedState::Checked(checked.get())),
+  });
+
+  view! {
+    <MenuItem
+      {..attrs}
+      attr:aria-checked=move || checked.get().to_string()
+      attr:data-state=move || get_checked_state_attribute(CheckedState::Checked(checked.get()))
+      role="menuitemradio"
+      disabled=disabled
+      text_value=text_value
+      on_select=move |ev: Event| {
+        on_select.call(ev);
+        on_value_change.call(value.get_untracked());
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub(crate) fn MenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuItemIndicatorContextValue { checked } = use_context()
+    .expect("MenuItemIndicator must be used in a MenuCheckboxItem or MenuRadioItem component");
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || checked.get() != CheckedState::Checked(false));
+  let presence = create_presence(is_present, node_ref);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <Primitive
+        {..attrs.clone()}
+        attr:data-state=move || get_checked_state_attribute(checked.get())
+        element=html::span
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="separator"
+      attr:aria-orientation="horizontal"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub(crate) fn MenuArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </PopperArrow>
+    },
+    None => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub(crate) fn MenuSub(
+  #[prop(optional, into)] open: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let MenuContextValue {
+    open: parent_open, ..
+  } = use_context().expect("MenuSub must be used in a MenuRoot component");
+
+  // submenus close along with their parent menu
+  Effect::new(move |_| {
+    if !parent_open.get() && open.get_untracked() {
+      on_open_change.call(false);
+    }
+  });
+
+  provide_context(MenuContextValue {
+    open: Signal::derive(move || open.get()),
+    content_ref: NodeRef::new(),
+    on_open_change,
+  });
+
+  provide_context(MenuSubContextValue {
+    content_id: create_id(),
+    trigger_id: create_id(),
+    trigger_ref: NodeRef::new(),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open,
+    content_ref,
+    on_open_change,
+  } = use_context().expect("MenuSubTrigger must be used in a MenuSub component");
+  let MenuRootContextValue { direction, .. } =
+    use_context().expect("MenuSubTrigger must be used in a MenuRoot component");
+  let MenuSubContextValue {
+    content_id,
+    trigger_id,
+    trigger_ref,
+  } = use_context().expect("MenuSubTrigger must be used in a MenuSub component");
+  let MenuContentContextValue {
+    search,
+    pointer_grace_timer,
+    on_trigger_leave,
+    on_pointer_grace_intent_change,
+    ..
+  } = use_context().expect("MenuSubTrigger must be used in a MenuContent component");
+
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let clear_open_timer = move || {
+    if let Some(handle) = open_timer.get_value() {
+      handle.clear();
+      open_timer.set_value(None);
+    }
+  };
+
+  let clear_pointer_grace_timer = move || {
+    if let Some(handle) = pointer_grace_timer.get_value() {
+      handle.clear();
+      pointer_grace_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = open_timer.try_get_value() {
+      handle.clear();
+    }
+
+    if let Some(Some(handle)) = pointer_grace_timer.try_get_value() {
+      handle.clear();
+      _ = pointer_grace_timer.try_set_value(None);
+    }
+
+    on_pointer_grace_intent_change.call(None);
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let text_value = Signal::derive(move || text_value.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true>
+      <MenuItemImpl
+        {..attrs.get_value()}
+        attr:id=trigger_id
+        attr:aria-haspopup="menu"
+        attr:aria-expanded=open
+        attr:aria-controls=content_id
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        role="menuitem"
+        disabled=disabled
+        text_value=text_value
+        on_pointer_move=move |ev: PointerEvent| {
+          if ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          if disabled.get_untracked() || open.get_untracked() || open_timer.get_value().is_some() {
+            return;
+          }
+
+          on_pointer_grace_intent_change.call(None);
+
+          let Ok(handle) = set_timeout_with_handle(
+            move || {
+              on_open_change.call(true);
+              open_timer.set_value(None);
+            },
+            Duration::from_millis(100),
+          ) else {
+            return;
+          };
+
+          open_timer.set_value(Some(handle));
+        }
+        on_pointer_leave=move |ev: PointerEvent| {
+          if ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          clear_open_timer();
+
+          let Some(content) = content_ref.get_untracked() else {
+            on_trigger_leave.call(ev.clone());
+
+            if !ev.default_prevented() {
+              on_pointer_grace_intent_change.call(None);
+            }
+
+            return;
+          };
+
+          // the area between the pointer and the submenu that the pointer can cross without the
+          // submenu closing
+          let content_rect = content.get_bounding_client_rect();
+          let is_right_side = content.get_attribute("data-side").as_deref() != Some("left");
+          let bleed = if is_right_side { -5.0 } else { 5.0 };
+          let (near_edge, far_edge) = if is_right_side {
+            (content_rect.left(), content_rect.right())
+          } else {
+            (content_rect.right(), content_rect.left())
+          };
+
+          on_pointer_grace_intent_change.call(Some(GraceIntent {
+            area: vec![
+              Point {
+                x: ev.client_x() as f64 + bleed,
+                y: ev.client_y() as f64,
+              },
+              Point {
+                x: near_edge,
+                y: content_rect.top(),
+              },
+              Point {
+                x: far_edge,
+                y: content_rect.top(),
+              },
+              Point {
+                x: far_edge,
+                y: content_rect.bottom(),
+              },
+              Point {
+                x: near_edge,
+                y: content_rect.bottom(),
+              },
+            ],
+            side: if is_right_side { Side::Right } else { Side::Left },
+          }));
+
+          clear_pointer_grace_timer();
+
+          if let Ok(handle) = set_timeout_with_handle(
+            move || on_pointer_grace_intent_change.call(None),
+            Duration::from_millis(300),
+          ) {
+            pointer_grace_timer.set_value(Some(handle));
+          }
+        }
+        on:click=move |ev: MouseEvent| {
+          if disabled.get_untracked() || ev.default_prevented() {
+            return;
+          }
+
+          // some browsers don't focus buttons on click, which the submenu needs to close correctly
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+
+          if !open.get_untracked() {
+            on_open_change.call(true);
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          let is_typing_ahead = !search.get_value().is_empty();
+          let key = ev.key();
+
+          if disabled.get_untracked() || (is_typing_ahead && key == " ") {
+            return;
+          }
+
+          if get_sub_open_keys(direction.get_untracked()).contains(&key.as_str()) {
+            on_open_change.call(true);
+
+            if let Some(content) = content_ref.get_untracked() {
+              _ = content.focus();
+            }
+
+            // keeps the keydown from scrolling the page or reaching the submenu's items
+            ev.prevent_default();
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuItemImpl>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue { on_open_change, .. } =
+    use_context().expect("MenuSubContent must be used in a MenuSub component");
+  let MenuRootContextValue {
+    is_using_keyboard,
+    direction,
+    on_close,
+    ..
+  } = use_context().expect("MenuSubContent must be used in a MenuRoot component");
+  let MenuSubContextValue {
+    content_id,
+    trigger_id,
+    trigger_ref,
+  } = use_context().expect("MenuSubContent must be used in a MenuSub component");
+
+  let is_present = use_menu_is_present(force_mount);
+  let presence = create_presence(is_present, node_ref);
+
+  let focus_trigger = move || {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+      _ = trigger.focus();
+    }
+  };
+
+  let side = Signal::derive(move || match direction.get() {
+    Direction::LeftToRight => Side::Right,
+    Direction::RightToLeft => Side::Left,
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <MenuContentImpl
+        {..attrs.get_value()}
+        attr:id=content_id
+        attr:aria-labelledby=trigger_id
+        should_loop=should_loop
+        side=side
+        side_offset=side_offset
+        align=Align::Start
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        on_open_auto_focus=move |ev: Event| {
+          // submenus opened by the pointer keep focus on their trigger
+          if is_using_keyboard.get_value() {
+            if let Some(content) = node_ref.get_untracked() {
+              _ = content.focus();
+            }
+          }
+
+          ev.prevent_default();
+        }
+        on_close_auto_focus=|ev: Event| ev.prevent_default()
+        on_escape_key_down=move |ev: KeyboardEvent| {
+          on_escape_key_down.call(ev.clone());
+
+          if !ev.default_prevented() {
+            on_close.call(());
+            ev.prevent_default();
+          }
+        }
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=move |ev: CustomEvent| {
+          on_focus_outside.call(ev.clone());
+
+          let target = get_original_event::<Event>(&ev)
+            .and_then(|ev| ev.target())
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+
+          let trigger = trigger_ref.get_untracked();
+          let is_target_trigger = target
+            .zip(trigger)
+            .map(|(target, trigger)| {
+              let trigger: &web_sys::Element = &trigger;
+              &target == trigger
+            })
+            .unwrap_or(false);
+
+          // the trigger regains focus when the submenu closes from the keyboard
+          if !ev.default_prevented() && !is_target_trigger {
+            on_open_change.call(false);
+          }
+        }
+        on_interact_outside=on_interact_outside
+        on_dismiss=|_| {}
+        on_key_down=move |ev: KeyboardEvent| {
+          let is_key_down_inside = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+            .map(|current_target| {
+              current_target.contains(
+                ev.target()
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                  .as_ref(),
+              )
+            })
+            .unwrap_or(false);
+
+          if is_key_down_inside && ev.key() == get_sub_close_key(direction.get_untracked()) {
+            on_open_change.call(false);
+            focus_trigger();
+
+            // keeps the parent menu's roving focus from also handling the key
+            ev.prevent_default();
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuContentImpl>
+    </Show>
+  }
+}
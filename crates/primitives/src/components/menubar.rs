@@ -0,0 +1,880 @@
+use std::collections::HashMap;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, Event, FocusEvent, KeyboardEvent, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
+  dismissable_layer::get_original_event,
+  menu::{
+    MenuAnchor, MenuArrow, MenuCheckboxItem, MenuContent, MenuGroup, MenuItem, MenuItemIndicator,
+    MenuLabel, MenuPortal, MenuRadioGroup, MenuRadioItem, MenuRoot, MenuSeparator, MenuSub,
+    MenuSubContent, MenuSubTrigger,
+  },
+  primitive::Primitive,
+  roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  Align, Direction, Orientation, Side, Sticky,
+};
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+struct MenubarTriggerData {
+  value: String,
+  disabled: Signal<bool>,
+}
+
+impl Ord for MenubarTriggerData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for MenubarTriggerData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct MenubarContextValue {
+  value: Signal<String>,
+  direction: Signal<Direction>,
+  should_loop: Signal<bool>,
+  on_menu_open: Callback<String>,
+  on_menu_close: Callback<()>,
+  on_menu_toggle: Callback<String>,
+}
+
+#[derive(Clone)]
+struct MenubarMenuContextValue {
+  value: Signal<String>,
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+}
+
+#[component]
+pub fn MenubarRoot(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+
+  provide_context(CollectionContextValue::<MenubarTriggerData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let set_on_menu_open = set_value.clone();
+  let set_on_menu_close = set_value.clone();
+
+  provide_context(MenubarContextValue {
+    value,
+    direction: Signal::derive(move || direction.get()),
+    should_loop: Signal::derive(move || should_loop.get()),
+    on_menu_open: Callback::new(move |value| set_on_menu_open.set(value)),
+    on_menu_close: Callback::new(move |_| set_on_menu_close.set(String::new())),
+    on_menu_toggle: Callback::new(move |menu_value: String| {
+      // clicking the trigger of the open menu closes it, otherwise that menu opens
+      set_value.set(if value.get_untracked() == menu_value {
+        String::new()
+      } else {
+        menu_value
+      });
+    }),
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <RovingFocusGroup
+      as_child=true
+      orientation=Orientation::Horizontal
+      direction=Signal::derive(move || direction.get())
+      should_loop=should_loop
+    >
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="menubar"
+        attr:dir=move || direction.get().to_string()
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </RovingFocusGroup>
+  }
+}
+
+#[component]
+pub fn MenubarMenu(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    direction,
+    on_menu_close,
+    ..
+  } = use_context().expect("MenubarMenu must be used in a MenubarRoot component");
+
+  // menus without an explicit value still need one to tell them apart
+  let fallback_value = create_id();
+  let value = Signal::derive(move || value.get().unwrap_or_else(|| fallback_value.get()));
+
+  let open = Signal::derive(move || menubar_value.get() == value.get());
+
+  provide_context(MenubarMenuContextValue {
+    value,
+    trigger_id: create_id(),
+    trigger_ref: NodeRef::new(),
+    content_id: create_id(),
+  });
+
+  view! {
+    <MenuRoot
+      open=open
+      direction=direction
+      modal=false
+      on_open_change=move |open: bool| {
+        // menus open through their trigger, so only closing is handled here
+        if !open {
+          on_menu_close.call(());
+        }
+      }
+    >
+      {children()}
+    </MenuRoot>
+  }
+}
+
+#[component]
+pub fn MenubarTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    on_menu_open,
+    on_menu_toggle,
+    ..
+  } = use_context().expect("MenubarTrigger must be used in a MenubarRoot component");
+  let MenubarMenuContextValue {
+    value,
+    trigger_id,
+    trigger_ref,
+    content_id,
+  } = use_context().expect("MenubarTrigger must be used in a MenubarMenu component");
+
+  let open = Signal::derive(move || menubar_value.get() == value.get());
+  let (is_focused, set_is_focused) = create_signal(false);
+
+  use_collection_item_ref::<AnyElement, MenubarTriggerData>(
+    node_ref,
+    MenubarTriggerData {
+      value: value.get_untracked(),
+      disabled: Signal::derive(move || disabled.get()),
+    },
+  );
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <RovingFocusGroupItem
+      as_child=true
+      tab_stop_id=value
+      focusable=Signal::derive(move || !disabled.get())
+    >
+      <MenuAnchor as_child=true>
+        <Primitive
+          {..attrs.get_value()}
+          attr:type="button"
+          attr:role="menuitem"
+          attr:id=trigger_id
+          attr:aria-haspopup="menu"
+          attr:aria-expanded=open
+          attr:aria-controls=move || open.get().then(|| content_id.get())
+          attr:data-highlighted=move || is_focused.get().then_some("")
+          attr:data-state=move || if open.get() { "open" } else { "closed" }
+          attr:data-disabled=move || disabled.get().then_some("")
+          attr:disabled=move || disabled.get()
+          element=html::button
+          on:pointerdown=move |ev: PointerEvent| {
+            // only the left button without ctrl opens the menu so the context menu keeps working
+            if disabled.get_untracked() || ev.button() != 0 || ev.ctrl_key() {
+              return;
+            }
+
+            on_menu_open.call(value.get_untracked());
+
+            // keeps the trigger from taking focus away from the content as it opens
+            if !open.get_untracked() {
+              ev.prevent_default();
+            }
+          }
+          on:pointerenter=move |ev: PointerEvent| {
+            let is_menubar_open = !menubar_value.get_untracked().is_empty();
+
+            // hovering across the triggers switches menus once one of them is open
+            if is_menubar_open && !open.get_untracked() && !disabled.get_untracked() {
+              on_menu_open.call(value.get_untracked());
+
+              if let Some(trigger) = ev
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+              {
+                _ = trigger.focus();
+              }
+            }
+          }
+          on:keydown=move |ev: KeyboardEvent| {
+            if disabled.get_untracked() {
+              return;
+            }
+
+            match ev.key().as_str() {
+              "Enter" | " " => on_menu_toggle.call(value.get_untracked()),
+              "ArrowDown" => on_menu_open.call(value.get_untracked()),
+              _ => return,
+            }
+
+            // keeps the keydown from scrolling the page
+            ev.prevent_default();
+          }
+          on:focus=move |_: FocusEvent| set_is_focused.set(true)
+          on:blur=move |_: FocusEvent| set_is_focused.set(false)
+          node_ref=node_ref
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </MenuAnchor>
+    </RovingFocusGroupItem>
+  }
+}
+
+#[component]
+pub fn MenubarPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <MenuPortal container=container force_mount=force_mount>
+      {children()}
+    </MenuPortal>
+  }
+}
+
+#[component]
+pub fn MenubarContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    direction,
+    should_loop: menubar_should_loop,
+    on_menu_open,
+    ..
+  } = use_context().expect("MenubarContent must be used in a MenubarRoot component");
+  let MenubarMenuContextValue {
+    value,
+    trigger_id,
+    trigger_ref,
+    content_id,
+  } = use_context().expect("MenubarContent must be used in a MenubarMenu component");
+
+  let get_items = use_collection_context::<MenubarTriggerData, AnyElement>();
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-menubar-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-menubar-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-menubar-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-menubar-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-menubar-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  view! {
+    <MenuContent
+      {..attrs}
+      attr:id=content_id
+      attr:aria-labelledby=trigger_id
+      attr:data-leptix-menubar-content=""
+      force_mount=force_mount
+      should_loop=should_loop
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        // focus stays in the newly opened menu when moving between menus
+        let is_menubar_open = !menubar_value.get_untracked().is_empty();
+
+        if !ev.default_prevented() && !is_menubar_open && !has_interacted_outside.get_value() {
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+        }
+
+        has_interacted_outside.set_value(false);
+        ev.prevent_default();
+      }
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=move |ev: CustomEvent| {
+        on_focus_outside.call(ev.clone());
+
+        let target = get_original_event::<Event>(&ev)
+          .and_then(|ev| ev.target())
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        let is_menubar_trigger = get_items.get_untracked().iter().any(|(trigger, _)| {
+          trigger
+            .get_untracked()
+            .map(|trigger| trigger.contains(target.as_ref()))
+            .unwrap_or(false)
+        });
+
+        // the triggers handle switching menus themselves
+        if is_menubar_trigger {
+          ev.prevent_default();
+        }
+      }
+      on_interact_outside=move |ev: CustomEvent| {
+        on_interact_outside.call(ev);
+        has_interacted_outside.set_value(true);
+      }
+      on_key_down=move |ev: KeyboardEvent| {
+        let key = ev.key();
+
+        if key != "ArrowRight" && key != "ArrowLeft" {
+          return;
+        }
+
+        let Some(target) = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+          return;
+        };
+
+        let current_target = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+
+        let is_target_sub_trigger = target.has_attribute("data-leptix-menubar-subtrigger");
+        let is_key_down_inside_sub_menu =
+          target.closest("[data-leptix-menu-content]").ok().flatten() != current_target;
+
+        let previous_key = match direction.get_untracked() {
+          Direction::LeftToRight => "ArrowLeft",
+          Direction::RightToLeft => "ArrowRight",
+        };
+        let is_previous_key = key == previous_key;
+
+        // the sub trigger opens its submenu and the submenu closes itself instead
+        if (!is_previous_key && is_target_sub_trigger)
+          || (is_previous_key && is_key_down_inside_sub_menu)
+        {
+          return;
+        }
+
+        let mut candidate_values = get_items
+          .get_untracked()
+          .into_iter()
+          .filter(|(_, item)| !item.disabled.get_untracked())
+          .map(|(_, item)| item.value)
+          .collect::<Vec<_>>();
+
+        if is_previous_key {
+          candidate_values.reverse();
+        }
+
+        let current_value = value.get_untracked();
+        let current_index = candidate_values
+          .iter()
+          .position(|candidate| *candidate == current_value)
+          .map(|index| index + 1)
+          .unwrap_or(0);
+
+        let next_value = if menubar_should_loop.get_untracked() {
+          candidate_values
+            .iter()
+            .cycle()
+            .skip(current_index)
+            .take(candidate_values.len())
+            .next()
+            .cloned()
+        } else {
+          candidate_values.get(current_index).cloned()
+        };
+
+        if let Some(next_value) = next_value {
+          on_menu_open.call(next_value);
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn MenubarGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn MenubarLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn MenubarItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn MenubarCheckboxItem(
+  #[prop(default=CheckedState::Checked(false).into(), into)] checked: MaybeSignal<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn MenubarRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn MenubarRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn MenubarItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children()}
+      </MenuItemIndicator>
+    },
+    None => view! {
+      <MenuItemIndicator
+        force_mount=force_mount
+        attrs=attrs
+        node_ref=node_ref
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn MenubarSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      attrs=attrs
+      node_ref=node_ref
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn MenubarArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </MenuArrow>
+    },
+    None => view! {
+      <MenuArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
+
+#[component]
+pub fn MenubarSub(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=move |value| set_open.set(value)
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn MenubarSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSubTrigger
+      {..attrs}
+      attr:data-leptix-menubar-subtrigger=""
+      disabled=disabled
+      text_value=text_value
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn MenubarSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-menubar-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-menubar-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-menubar-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-menubar-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-menubar-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  view! {
+    <MenuSubContent
+      {..attrs}
+      attr:data-leptix-menubar-content=""
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_interact_outside=on_interact_outside
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
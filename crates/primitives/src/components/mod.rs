@@ -1,24 +1,54 @@
+pub mod accessible_icon;
 pub mod accordion;
+pub mod alert_dialog;
+pub mod aria_hidden;
 pub mod aspect_ratio;
 pub mod avatar;
+pub mod calendar;
 pub mod checkbox;
 pub mod collapsible;
+pub mod combobox;
+pub mod command;
+pub mod context_menu;
+pub mod date_field;
+pub mod date_picker;
+pub mod dialog;
+pub mod direction;
+pub mod dismissable_layer;
+pub mod dropdown_menu;
+pub mod focus_guards;
+pub mod focus_scope;
+pub mod form;
+pub mod hover_card;
 pub mod label;
+pub mod menubar;
+pub mod navigation_menu;
+pub mod number_field;
+pub mod popover;
+pub mod portal;
 pub mod primitive;
 pub mod progress;
 pub(crate) mod radio;
 pub mod radio_group;
 pub mod scroll_area;
+pub mod scroll_lock;
+pub mod select;
 pub mod separator;
 pub mod slider;
 pub mod slot;
 pub mod switch;
 pub mod tabs;
+pub mod time_field;
+pub mod toast;
 pub mod toggle;
 pub mod toggle_group;
 pub mod toolbar;
+pub mod tooltip;
+pub mod visually_hidden;
 
 pub(crate) mod collection;
+pub(crate) mod menu;
+pub(crate) mod popper;
 pub(crate) mod presence;
 pub(crate) mod roving_focus;
 
@@ -39,3 +69,34 @@ pub enum Orientation {
   #[strum(to_string = "vertical")]
   Vertical,
 }
+
+#[derive(Default, Clone, PartialEq, Copy, Debug, strum_macros::Display)]
+pub enum Side {
+  #[strum(to_string = "top")]
+  Top,
+  #[strum(to_string = "right")]
+  Right,
+  #[default]
+  #[strum(to_string = "bottom")]
+  Bottom,
+  #[strum(to_string = "left")]
+  Left,
+}
+
+#[derive(Default, Clone, PartialEq, Copy, Debug, strum_macros::Display)]
+pub enum Align {
+  #[strum(to_string = "start")]
+  Start,
+  #[default]
+  #[strum(to_string = "center")]
+  Center,
+  #[strum(to_string = "end")]
+  End,
+}
+
+#[derive(Default, Clone, PartialEq, Copy, Debug)]
+pub enum Sticky {
+  #[default]
+  Partial,
+  Always,
+}
@@ -0,0 +1,1295 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use leptos_use::{use_event_listener, use_resize_observer};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, CustomEventInit, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
+  dismissable_layer::DismissableLayer,
+  focus_scope::{focus_first, get_tabbable_candidates},
+  popper::Size,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+  Direction, Orientation,
+};
+
+const LINK_SELECT: &str = "navigation_menu.link_select";
+const ROOT_CONTENT_DISMISS: &str = "navigation_menu.root_content_dismiss";
+
+const ARROW_KEYS: [&str; 4] = ["ArrowRight", "ArrowLeft", "ArrowUp", "ArrowDown"];
+
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord)]
+struct NavigationMenuTriggerData {
+  value: String,
+}
+
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord)]
+struct FocusGroupItemData;
+
+#[derive(Clone)]
+struct NavigationMenuContextValue {
+  is_root_menu: bool,
+  value: Signal<String>,
+  previous_value: Signal<String>,
+  base_id: Signal<String>,
+  direction: Signal<Direction>,
+  orientation: Signal<Orientation>,
+  root_ref: NodeRef<AnyElement>,
+  list_ref: NodeRef<AnyElement>,
+  indicator_track_ref: NodeRef<AnyElement>,
+  viewport_ref: NodeRef<AnyElement>,
+  has_viewport: RwSignal<bool>,
+  content_nodes: RwSignal<HashMap<String, web_sys::HtmlElement>>,
+  on_trigger_enter: Callback<String>,
+  on_trigger_leave: Callback<()>,
+  on_content_enter: Callback<()>,
+  on_content_leave: Callback<()>,
+  on_item_select: Callback<String>,
+  on_item_dismiss: Callback<()>,
+}
+
+#[derive(Clone)]
+struct NavigationMenuItemContextValue {
+  value: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_ref: NodeRef<AnyElement>,
+  focus_proxy_ref: NodeRef<AnyElement>,
+  was_escape_close: StoredValue<bool>,
+}
+
+fn create_previous_value(value: Signal<String>) -> Signal<String> {
+  let (previous_value, set_previous_value) = create_signal(String::new());
+
+  Effect::new(move |previous: Option<String>| {
+    let value = value.get();
+
+    if let Some(previous) = previous {
+      if previous != value {
+        set_previous_value.set(previous);
+      }
+    }
+
+    value
+  });
+
+  previous_value.into()
+}
+
+fn make_trigger_id(base_id: &str, value: &str) -> String {
+  format!("{base_id}-trigger-{value}")
+}
+
+fn make_content_id(base_id: &str, value: &str) -> String {
+  format!("{base_id}-content-{value}")
+}
+
+// contents animate in from the side of the previously open item and out towards the newly open one
+pub(crate) fn get_motion_attribute(
+  values: &[String],
+  item_value: &str,
+  value: &str,
+  previous_value: &str,
+) -> Option<&'static str> {
+  let index = values.iter().position(|candidate| candidate == value);
+  let previous_index = values
+    .iter()
+    .position(|candidate| candidate == previous_value);
+
+  let is_selected = item_value == value;
+  let was_selected = item_value == previous_value;
+
+  if index == previous_index {
+    return None;
+  }
+
+  if is_selected && previous_index.is_some() {
+    return Some(if index > previous_index {
+      "from-end"
+    } else {
+      "from-start"
+    });
+  }
+
+  if was_selected && index.is_some() {
+    return Some(if index > previous_index {
+      "to-start"
+    } else {
+      "to-end"
+    });
+  }
+
+  None
+}
+
+#[component]
+pub fn NavigationMenuRoot(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(default=200.into(), into)] delay_duration: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] skip_delay_duration: MaybeSignal<u64>,
+  #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(optional, into)] aria_label: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let skip_delay_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let (is_open_delayed, set_is_open_delayed) = create_signal(true);
+
+  let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+    if let Some(handle) = timer.get_value() {
+      handle.clear();
+      timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    for timer in [open_timer, close_timer, skip_delay_timer] {
+      if let Some(Some(handle)) = timer.try_get_value() {
+        handle.clear();
+      }
+    }
+  });
+
+  // moving quickly between items skips the open delay
+  Effect::new(move |_| {
+    let is_open = !value.get().is_empty();
+
+    clear_timer(skip_delay_timer);
+
+    if is_open {
+      set_is_open_delayed.set(false);
+    } else if let Ok(handle) = set_timeout_with_handle(
+      move || set_is_open_delayed.set(true),
+      Duration::from_millis(skip_delay_duration.get_untracked()),
+    ) {
+      skip_delay_timer.set_value(Some(handle));
+    }
+  });
+
+  let set_value = StoredValue::new(set_value);
+
+  let start_close_timer = move || {
+    clear_timer(close_timer);
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || set_value.with_value(|set_value| set_value.set(String::new())),
+      Duration::from_millis(150),
+    ) {
+      close_timer.set_value(Some(handle));
+    }
+  };
+
+  let handle_open = move |item_value: String| {
+    clear_timer(close_timer);
+    set_value.with_value(|set_value| set_value.set(item_value));
+  };
+
+  let handle_delayed_open = move |item_value: String| {
+    if value.get_untracked() == item_value {
+      clear_timer(close_timer);
+      return;
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || {
+        clear_timer(close_timer);
+        set_value.with_value(|set_value| set_value.set(item_value));
+      },
+      Duration::from_millis(delay_duration.get_untracked()),
+    ) {
+      open_timer.set_value(Some(handle));
+    }
+  };
+
+  provide_context(
+    CollectionContextValue::<NavigationMenuTriggerData, AnyElement> {
+      collection_ref: NodeRef::new(),
+      item_map: RwSignal::new(HashMap::new()),
+    },
+  );
+
+  let CollectionContextValue {
+    collection_ref: list_ref,
+    ..
+  } = use_context::<CollectionContextValue<NavigationMenuTriggerData, AnyElement>>()
+    .expect("NavigationMenuRoot provides a trigger collection");
+
+  provide_context(NavigationMenuContextValue {
+    is_root_menu: true,
+    value,
+    previous_value: create_previous_value(value),
+    base_id: create_id(),
+    direction: Signal::derive(move || direction.get()),
+    orientation: Signal::derive(move || orientation.get()),
+    root_ref: node_ref,
+    list_ref,
+    indicator_track_ref: NodeRef::new(),
+    viewport_ref: NodeRef::new(),
+    has_viewport: RwSignal::new(false),
+    content_nodes: RwSignal::new(HashMap::new()),
+    on_trigger_enter: Callback::new(move |item_value: String| {
+      clear_timer(open_timer);
+
+      if is_open_delayed.get_untracked() {
+        handle_delayed_open(item_value);
+      } else {
+        handle_open(item_value);
+      }
+    }),
+    on_trigger_leave: Callback::new(move |_| {
+      clear_timer(open_timer);
+      start_close_timer();
+    }),
+    on_content_enter: Callback::new(move |_| clear_timer(close_timer)),
+    on_content_leave: Callback::new(move |_| start_close_timer()),
+    on_item_select: Callback::new(move |item_value: String| {
+      let next_value = if value.get_untracked() == item_value {
+        String::new()
+      } else {
+        item_value
+      };
+
+      set_value.with_value(|set_value| set_value.set(next_value));
+    }),
+    on_item_dismiss: Callback::new(move |_| {
+      set_value.with_value(|set_value| set_value.set(String::new()));
+    }),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:aria-label=move || aria_label.get().unwrap_or_else(|| "Main".into())
+      attr:data-orientation=move || orientation.get().to_string()
+      attr:dir=move || direction.get().to_string()
+      element=html::nav
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NavigationMenuSub(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    direction,
+    root_ref,
+    ..
+  } = use_context().expect("NavigationMenuSub must be used in a NavigationMenuRoot component");
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+  let set_value = StoredValue::new(set_value);
+
+  provide_context(
+    CollectionContextValue::<NavigationMenuTriggerData, AnyElement> {
+      collection_ref: NodeRef::new(),
+      item_map: RwSignal::new(HashMap::new()),
+    },
+  );
+
+  let CollectionContextValue {
+    collection_ref: list_ref,
+    ..
+  } = use_context::<CollectionContextValue<NavigationMenuTriggerData, AnyElement>>()
+    .expect("NavigationMenuSub provides a trigger collection");
+
+  // submenus open straight away since they're already inside of an open menu
+  provide_context(NavigationMenuContextValue {
+    is_root_menu: false,
+    value,
+    previous_value: create_previous_value(value),
+    base_id: create_id(),
+    direction,
+    orientation: Signal::derive(move || orientation.get()),
+    root_ref,
+    list_ref,
+    indicator_track_ref: NodeRef::new(),
+    viewport_ref: NodeRef::new(),
+    has_viewport: RwSignal::new(false),
+    content_nodes: RwSignal::new(HashMap::new()),
+    on_trigger_enter: Callback::new(move |item_value| {
+      set_value.with_value(|set_value| set_value.set(item_value));
+    }),
+    on_trigger_leave: Callback::new(|_| {}),
+    on_content_enter: Callback::new(|_| {}),
+    on_content_leave: Callback::new(|_| {}),
+    on_item_select: Callback::new(move |item_value| {
+      set_value.with_value(|set_value| set_value.set(item_value));
+    }),
+    on_item_dismiss: Callback::new(move |_| {
+      set_value.with_value(|set_value| set_value.set(String::new()));
+    }),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:data-orientation=move || orientation.get().to_string()
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NavigationMenuList(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    orientation,
+    list_ref,
+    indicator_track_ref,
+    ..
+  } = use_context().expect("NavigationMenuList must be used in a NavigationMenuRoot component");
+
+  provide_context(CollectionContextValue::<FocusGroupItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      list_ref.load(&node);
+    }
+  });
+
+  let children = StoredValue::new(children);
+
+  // the indicator is rendered into the track so it can be positioned relative to the list
+  view! {
+    <Primitive
+      element=html::div
+      attr:style="position: relative;"
+      node_ref=indicator_track_ref
+    >
+      <Primitive
+        {..attrs.clone()}
+        attr:data-orientation=move || orientation.get().to_string()
+        element=html::ul
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Primitive>
+  }
+}
+
+#[component]
+fn FocusGroupItem(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  children: ChildrenFn,
+) -> impl IntoView {
+  let NavigationMenuContextValue { direction, .. } =
+    use_context().expect("FocusGroupItem must be used in a NavigationMenuRoot component");
+
+  use_collection_item_ref::<AnyElement, FocusGroupItemData>(node_ref, FocusGroupItemData);
+
+  let get_items = use_collection_context::<FocusGroupItemData, AnyElement>();
+
+  view! {
+    <Primitive
+      element=html::div
+      on:keydown=move |ev: KeyboardEvent| {
+        let key = ev.key();
+
+        if key != "Home" && key != "End" && !ARROW_KEYS.contains(&key.as_str()) {
+          return;
+        }
+
+        let mut candidates = get_items
+          .get_untracked()
+          .into_iter()
+          .filter_map(|(node, _)| node.get_untracked())
+          .filter_map(|node| {
+            let element: &web_sys::Element = &node;
+            element.clone().dyn_into::<web_sys::HtmlElement>().ok()
+          })
+          .collect::<Vec<_>>();
+
+        let previous_key = match direction.get_untracked() {
+          Direction::LeftToRight => "ArrowLeft",
+          Direction::RightToLeft => "ArrowRight",
+        };
+
+        if [previous_key, "ArrowUp", "End"].contains(&key.as_str()) {
+          candidates.reverse();
+        }
+
+        if ARROW_KEYS.contains(&key.as_str()) {
+          let current_target = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+
+          if let Some(current_index) = candidates
+            .iter()
+            .position(|candidate| Some(candidate) == current_target.as_ref())
+          {
+            candidates = candidates.split_off(current_index + 1);
+          }
+        }
+
+        set_timeout(
+          move || {
+            focus_first(&candidates, false);
+          },
+          Duration::ZERO,
+        );
+
+        // keeps the arrow keys from scrolling the page
+        ev.prevent_default();
+      }
+      node_ref=node_ref
+      as_child=true
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NavigationMenuItem(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  // items without an explicit value still need one to tell them apart
+  let fallback_value = create_id();
+
+  provide_context(NavigationMenuItemContextValue {
+    value: Signal::derive(move || value.get().unwrap_or_else(|| fallback_value.get())),
+    trigger_ref: NodeRef::new(),
+    content_ref: NodeRef::new(),
+    focus_proxy_ref: NodeRef::new(),
+    was_escape_close: StoredValue::new(false),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::li
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn focus_content_entry(content_ref: NodeRef<AnyElement>, from_start: bool) {
+  let Some(content) = content_ref.get_untracked() else {
+    return;
+  };
+
+  let mut candidates = get_tabbable_candidates(&content);
+
+  if !from_start {
+    candidates.reverse();
+  }
+
+  focus_first(&candidates, false);
+}
+
+#[component]
+pub fn NavigationMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    value: menu_value,
+    base_id,
+    direction,
+    orientation,
+    has_viewport,
+    on_trigger_enter,
+    on_trigger_leave,
+    on_item_select,
+    ..
+  } = use_context().expect("NavigationMenuTrigger must be used in a NavigationMenuRoot component");
+  let NavigationMenuItemContextValue {
+    value,
+    trigger_ref,
+    content_ref,
+    focus_proxy_ref,
+    was_escape_close,
+  } = use_context().expect("NavigationMenuTrigger must be used in a NavigationMenuItem component");
+
+  let open = Signal::derive(move || menu_value.get() == value.get());
+  let trigger_id = Signal::derive(move || make_trigger_id(&base_id.get(), &value.get()));
+  let content_id = Signal::derive(move || make_content_id(&base_id.get(), &value.get()));
+
+  let has_pointer_move_opened = StoredValue::new(false);
+  let was_click_close = StoredValue::new(false);
+
+  use_collection_item_ref::<AnyElement, NavigationMenuTriggerData>(
+    node_ref,
+    NavigationMenuTriggerData {
+      value: value.get_untracked(),
+    },
+  );
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusGroupItem>
+      <Primitive
+        {..attrs.get_value()}
+        attr:id=trigger_id
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-disabled=move || disabled.get().then_some("")
+        attr:disabled=move || disabled.get()
+        attr:aria-expanded=open
+        attr:aria-controls=content_id
+        element=html::button
+        on:pointerenter=move |_: PointerEvent| {
+          was_click_close.set_value(false);
+          was_escape_close.set_value(false);
+        }
+        on:pointermove=move |ev: PointerEvent| {
+          if ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          // a menu closed by clicking or escape shouldn't reopen until the pointer leaves
+          if disabled.get_untracked()
+            || was_click_close.get_value()
+            || was_escape_close.get_value()
+            || has_pointer_move_opened.get_value()
+          {
+            return;
+          }
+
+          on_trigger_enter.call(value.get_untracked());
+          has_pointer_move_opened.set_value(true);
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          if ev.pointer_type() != "mouse" || disabled.get_untracked() {
+            return;
+          }
+
+          on_trigger_leave.call(());
+          has_pointer_move_opened.set_value(false);
+        }
+        on:click=move |_: MouseEvent| {
+          on_item_select.call(value.get_untracked());
+          was_click_close.set_value(open.get_untracked());
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          let entry_key = match (orientation.get_untracked(), direction.get_untracked()) {
+            (Orientation::Horizontal, _) => "ArrowDown",
+            (Orientation::Vertical, Direction::LeftToRight) => "ArrowRight",
+            (Orientation::Vertical, Direction::RightToLeft) => "ArrowLeft",
+          };
+
+          if open.get_untracked() && ev.key() == entry_key {
+            focus_content_entry(content_ref, true);
+
+            // keeps the focus group from also moving focus
+            ev.prevent_default();
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </FocusGroupItem>
+
+    // the proxy sits between the trigger and the content in the tab order so tabbing moves through
+    // the content even when it's rendered elsewhere in the viewport
+    <Show when=move || open.get()>
+      <VisuallyHidden
+        attr:aria-hidden="true"
+        attr:tabindex=0
+        on:focus=move |ev: FocusEvent| {
+          let related_target = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+          let was_trigger_focused = trigger_ref
+            .get_untracked()
+            .zip(related_target.as_ref())
+            .map(|(trigger, related_target)| {
+              let trigger: &web_sys::Node = &trigger;
+              trigger == related_target
+            })
+            .unwrap_or(false);
+
+          let was_focus_from_content = content_ref
+            .get_untracked()
+            .map(|content| content.contains(related_target.as_ref()))
+            .unwrap_or(false);
+
+          if was_trigger_focused || !was_focus_from_content {
+            focus_content_entry(content_ref, was_trigger_focused);
+          }
+        }
+        node_ref=focus_proxy_ref
+      >
+        {None::<View>}
+      </VisuallyHidden>
+
+      // the content is moved into the viewport, so it's still owned by the trigger for assistive technology
+      <Show when=move || has_viewport.get()>
+        <span attr:aria-owns=content_id></span>
+      </Show>
+    </Show>
+  }
+}
+
+#[component]
+pub fn NavigationMenuLink(
+  #[prop(optional, into)] active: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<web_sys::Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusGroupItem>
+      <Primitive
+        {..attrs.get_value()}
+        attr:data-active=move || active.get().then_some("")
+        attr:aria-current=move || active.get().then_some("page")
+        element=html::a
+        on:click=move |ev: MouseEvent| {
+          let Some(target) = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+          else {
+            return;
+          };
+
+          let init = CustomEventInit::new();
+          init.set_bubbles(true);
+          init.set_cancelable(true);
+
+          let Ok(link_select_event) = CustomEvent::new_with_event_init_dict(LINK_SELECT, &init)
+          else {
+            return;
+          };
+
+          on_select.call(link_select_event.clone().into());
+
+          // links opened in a new tab or window keep the menu open
+          if !link_select_event.default_prevented() && !ev.meta_key() {
+            let init = CustomEventInit::new();
+            init.set_bubbles(true);
+            init.set_cancelable(true);
+
+            if let Ok(dismiss_event) =
+              CustomEvent::new_with_event_init_dict(ROOT_CONTENT_DISMISS, &init)
+            {
+              _ = target.dispatch_event(&dismiss_event);
+            }
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </FocusGroupItem>
+  }
+}
+
+#[component]
+pub fn NavigationMenuIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    value,
+    orientation,
+    indicator_track_ref,
+    ..
+  } =
+    use_context().expect("NavigationMenuIndicator must be used in a NavigationMenuRoot component");
+
+  let get_items = use_collection_context::<NavigationMenuTriggerData, AnyElement>();
+
+  let is_visible = Signal::derive(move || !value.get().is_empty());
+  let is_present = Signal::derive(move || force_mount.get() || is_visible.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let active_trigger = Signal::derive(move || {
+    let value = value.get();
+
+    get_items
+      .get()
+      .into_iter()
+      .find(|(_, item)| item.value == value)
+      .and_then(|(node, _)| node.get())
+      .map(|node| {
+        let element: &web_sys::Element = &node;
+        element.clone()
+      })
+  });
+
+  // the position is kept while the indicator animates out
+  let (position, set_position) = create_signal::<Option<(f64, f64)>>(None);
+
+  let handle_position_change = move || {
+    let Some(trigger) = active_trigger
+      .get_untracked()
+      .and_then(|trigger| trigger.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+      return;
+    };
+
+    set_position.set(Some(match orientation.get_untracked() {
+      Orientation::Horizontal => (trigger.offset_width() as f64, trigger.offset_left() as f64),
+      Orientation::Vertical => (trigger.offset_height() as f64, trigger.offset_top() as f64),
+    }));
+  };
+
+  Effect::new(move |_| {
+    _ = active_trigger.get();
+    _ = orientation.get();
+
+    handle_position_change();
+  });
+
+  use_resize_observer(active_trigger, move |_, _| handle_position_change());
+  use_resize_observer(indicator_track_ref, move |_, _| handle_position_change());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get() && position.get().is_some()>
+      {move || indicator_track_ref.get().map(|track| {
+        let track: &web_sys::Element = &track;
+
+        view! {
+          <Portal mount=track.clone()>
+            <Primitive
+              {..attrs.get_value()}
+              attr:aria-hidden="true"
+              attr:data-state=move || if is_visible.get() { "visible" } else { "hidden" }
+              attr:data-orientation=move || orientation.get().to_string()
+              attr:style=move || {
+                let Some((size, offset)) = position.get() else {
+                  return String::new();
+                };
+
+                match orientation.get() {
+                  Orientation::Horizontal => format!(
+                    "position: absolute; left: 0; width: {size}px; transform: translateX({offset}px);"
+                  ),
+                  Orientation::Vertical => format!(
+                    "position: absolute; top: 0; height: {size}px; transform: translateY({offset}px);"
+                  ),
+                }
+              }
+              element=html::div
+              node_ref=node_ref
+              as_child=as_child
+            >
+              {children.with_value(|children| children.as_ref().map(|children| children()))}
+            </Primitive>
+          </Portal>
+        }
+      })}
+    </Show>
+  }
+}
+
+#[component]
+pub fn NavigationMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    value: menu_value,
+    previous_value,
+    base_id,
+    direction,
+    orientation,
+    root_ref,
+    viewport_ref,
+    has_viewport,
+    content_nodes,
+    on_content_enter,
+    on_content_leave,
+    on_item_dismiss,
+    ..
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuRoot component");
+  let NavigationMenuItemContextValue {
+    value,
+    trigger_ref,
+    content_ref,
+    focus_proxy_ref,
+    was_escape_close,
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuItem component");
+
+  let get_items = use_collection_context::<NavigationMenuTriggerData, AnyElement>();
+
+  let open = Signal::derive(move || menu_value.get() == value.get());
+  let is_present = Signal::derive(move || force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let trigger_id = Signal::derive(move || make_trigger_id(&base_id.get(), &value.get()));
+  let content_id = Signal::derive(move || make_content_id(&base_id.get(), &value.get()));
+
+  let motion_attribute = create_memo(move |previous: Option<&Option<&'static str>>| {
+    let mut values = get_items
+      .get()
+      .into_iter()
+      .map(|(_, item)| item.value)
+      .collect::<Vec<_>>();
+
+    if direction.get() == Direction::RightToLeft {
+      values.reverse();
+    }
+
+    let (item_value, value, previous_value) = (value.get(), menu_value.get(), previous_value.get());
+
+    // contents that weren't involved in the change keep animating the way they were
+    if item_value != value && item_value != previous_value {
+      return previous.copied().flatten();
+    }
+
+    get_motion_attribute(&values, &item_value, &value, &previous_value)
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    content_ref.load(&node);
+
+    let element: &web_sys::Element = &node;
+    let Ok(element) = element.clone().dyn_into::<web_sys::HtmlElement>() else {
+      return;
+    };
+
+    let item_value = value.get_untracked();
+
+    content_nodes.update(|nodes| {
+      nodes.insert(item_value.clone(), element);
+    });
+
+    on_cleanup(move || {
+      _ = content_nodes.try_update(|nodes| {
+        nodes.remove(&item_value);
+      });
+    });
+  });
+
+  // links inside of the content, and the content of any nested menu, dismiss every menu they're in
+  _ = use_event_listener(
+    node_ref,
+    ev::Custom::<web_sys::Event>::new(ROOT_CONTENT_DISMISS),
+    move |_| {
+      on_item_dismiss.call(());
+
+      let Some(content) = node_ref.get_untracked() else {
+        return;
+      };
+
+      let active_element = document()
+        .active_element()
+        .map(|element| element.unchecked_into::<web_sys::Node>());
+
+      if content.contains(active_element.as_ref()) {
+        if let Some(trigger) = trigger_ref.get_untracked() {
+          _ = trigger.focus();
+        }
+      }
+    },
+  );
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  let content = move || {
+    view! {
+      <DismissableLayer
+        as_child=true
+        on_escape_key_down=move |ev: KeyboardEvent| {
+          on_escape_key_down.call(ev);
+          was_escape_close.set_value(true);
+        }
+        on_pointer_down_outside=move |ev: CustomEvent| {
+          on_pointer_down_outside.call(ev.clone());
+
+          let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+          let is_trigger = get_items.get_untracked().iter().any(|(trigger, _)| {
+            trigger
+              .get_untracked()
+              .map(|trigger| trigger.contains(target.as_ref()))
+              .unwrap_or(false)
+          });
+
+          let is_root_viewport = is_root_menu
+            && viewport_ref
+              .get_untracked()
+              .map(|viewport| viewport.contains(target.as_ref()))
+              .unwrap_or(false);
+
+          // the triggers toggle the menus themselves
+          if is_trigger || is_root_viewport || !is_root_menu {
+            ev.prevent_default();
+          }
+        }
+        on_focus_outside=move |ev: CustomEvent| {
+          on_focus_outside.call(ev.clone());
+
+          let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+          // focus moving to another part of the navigation menu is handled by the triggers
+          if root_ref
+            .get_untracked()
+            .map(|root| root.contains(target.as_ref()))
+            .unwrap_or(false)
+          {
+            ev.prevent_default();
+          }
+        }
+        on_interact_outside=on_interact_outside
+        on_dismiss=move |_| {
+          let Some(content) = node_ref.get_untracked() else {
+            on_item_dismiss.call(());
+            return;
+          };
+
+          let init = CustomEventInit::new();
+          init.set_bubbles(true);
+          init.set_cancelable(true);
+
+          if let Ok(dismiss_event) = CustomEvent::new_with_event_init_dict(ROOT_CONTENT_DISMISS, &init) {
+            _ = content.dispatch_event(&dismiss_event);
+          }
+        }
+      >
+        <Primitive
+          {..attrs.get_value()}
+          attr:id=content_id
+          attr:aria-labelledby=trigger_id
+          attr:data-motion=move || motion_attribute.get()
+          attr:data-orientation=move || orientation.get().to_string()
+          attr:data-state=move || if open.get() { "open" } else { "closed" }
+          element=html::div
+          on:pointerenter=move |_: PointerEvent| {
+            // the viewport handles the pointer when the content is rendered inside of it
+            if !has_viewport.get_untracked() {
+              on_content_enter.call(());
+            }
+          }
+          on:pointerleave=move |ev: PointerEvent| {
+            if !has_viewport.get_untracked() && ev.pointer_type() == "mouse" {
+              on_content_leave.call(());
+            }
+          }
+          on:keydown=move |ev: KeyboardEvent| {
+            let is_meta_key = ev.alt_key() || ev.ctrl_key() || ev.meta_key();
+
+            if ev.key() != "Tab" || is_meta_key {
+              return;
+            }
+
+            let Some(current_target) = ev
+              .current_target()
+              .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            else {
+              return;
+            };
+
+            let candidates = get_tabbable_candidates(&current_target);
+            let active_element = document().active_element();
+            let index = candidates
+              .iter()
+              .position(|candidate| {
+                let candidate: &web_sys::Element = candidate;
+                Some(candidate) == active_element.as_ref()
+              });
+
+            let next_candidates = match (ev.shift_key(), index) {
+              (true, Some(index)) => candidates[..index].iter().rev().cloned().collect::<Vec<_>>(),
+              (true, None) => vec![],
+              (false, Some(index)) => candidates[index + 1..].to_vec(),
+              (false, None) => candidates,
+            };
+
+            // tabbing past either end of the content moves back into the list through the focus proxy
+            if focus_first(&next_candidates, false) {
+              ev.prevent_default();
+            } else if let Some(focus_proxy) = focus_proxy_ref.get_untracked() {
+              _ = focus_proxy.focus();
+            }
+          }
+          node_ref=node_ref
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </DismissableLayer>
+    }
+  };
+
+  view! {
+    <Show when=move || presence.get()>
+      {move || {
+        if has_viewport.get() {
+          viewport_ref.get().map(|viewport| {
+            let viewport: &web_sys::Element = &viewport;
+
+            view! {
+              <Portal mount=viewport.clone()>
+                {content()}
+              </Portal>
+            }
+            .into_view()
+          })
+        } else {
+          Some(content().into_view())
+        }
+      }}
+    </Show>
+  }
+}
+
+#[component]
+pub fn NavigationMenuViewport(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    value,
+    previous_value,
+    orientation,
+    viewport_ref,
+    has_viewport,
+    content_nodes,
+    on_content_enter,
+    on_content_leave,
+    ..
+  } = use_context().expect("NavigationMenuViewport must be used in a NavigationMenuRoot component");
+
+  // set while rendering so the contents know to render into the viewport from the start
+  has_viewport.set(true);
+
+  on_cleanup(move || {
+    _ = has_viewport.try_set(false);
+  });
+
+  let open = Signal::derive(move || !value.get().is_empty());
+  let is_present = Signal::derive(move || force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      viewport_ref.load(&node);
+    }
+  });
+
+  // the closing content keeps its size while the viewport animates out
+  let active_content = Signal::derive(move || {
+    let active_value = if open.get() {
+      value.get()
+    } else {
+      previous_value.get()
+    };
+
+    content_nodes.with(|nodes| nodes.get(&active_value).cloned())
+  });
+
+  let (size, set_size) = create_signal::<Option<Size>>(None);
+
+  let handle_size_change = move || {
+    if let Some(content) = active_content.get_untracked() {
+      set_size.set(Some(Size {
+        width: content.offset_width() as f64,
+        height: content.offset_height() as f64,
+      }));
+    }
+  };
+
+  Effect::new(move |_| {
+    _ = active_content.get();
+
+    handle_size_change();
+  });
+
+  use_resize_observer(
+    Signal::derive(move || {
+      active_content
+        .get()
+        .map(|content| content.unchecked_into::<web_sys::Element>())
+    }),
+    move |_, _| handle_size_change(),
+  );
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("--leptix-navigation-menu-viewport-width", move || {
+        size.get().map(|size| format!("{}px", size.width))
+      })
+      .style("--leptix-navigation-menu-viewport-height", move || {
+        size.get().map(|size| format!("{}px", size.height))
+      })
+      .style("pointer-events", move || {
+        (!open.get() && is_root_menu).then_some("none")
+      });
+  });
+
+  let attrs = StoredValue::new(attrs);
+
+  view! {
+    <Show when=move || presence.get()>
+      <Primitive
+        {..attrs.get_value()}
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-orientation=move || orientation.get().to_string()
+        element=html::div
+        on:pointerenter=move |_: PointerEvent| on_content_enter.call(())
+        on:pointerleave=move |ev: PointerEvent| {
+          if ev.pointer_type() == "mouse" {
+            on_content_leave.call(());
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {None::<View>}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[test]
+fn motion_attribute_is_relative_to_item_order() {
+  let values = ["a", "b", "c"].map(String::from);
+
+  assert_eq!(
+    get_motion_attribute(&values, "c", "c", "a"),
+    Some("from-end")
+  );
+  assert_eq!(
+    get_motion_attribute(&values, "a", "c", "a"),
+    Some("to-start")
+  );
+  assert_eq!(
+    get_motion_attribute(&values, "a", "a", "c"),
+    Some("from-start")
+  );
+  assert_eq!(get_motion_attribute(&values, "c", "a", "c"), Some("to-end"));
+}
+
+#[test]
+fn motion_attribute_is_empty_when_opening_from_closed() {
+  let values = ["a", "b", "c"].map(String::from);
+
+  assert_eq!(get_motion_attribute(&values, "b", "b", ""), None);
+  assert_eq!(get_motion_attribute(&values, "b", "", "b"), None);
+}
@@ -0,0 +1,669 @@
+use std::time::Duration;
+
+use leptos::{
+  html::AnyElement,
+  leptos_dom::helpers::{IntervalHandle, TimeoutHandle},
+  *,
+};
+use wasm_bindgen::JsValue;
+use web_sys::{
+  js_sys::{Array, Intl::NumberFormat, Object, Reflect},
+  Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent, WheelEvent,
+};
+
+use crate::{
+  date_field::BubbleInput,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    get_decimal_count, round_value, snap_value_to_step, Attributes,
+  },
+};
+
+// holding a step button waits a moment before repeating, like holding down a key
+const AUTO_REPEAT_DELAY: u64 = 400;
+const AUTO_REPEAT_INTERVAL: u64 = 60;
+
+#[derive(Clone)]
+struct NumberFieldContextValue {
+  input_id: Signal<String>,
+  input_ref: NodeRef<AnyElement>,
+  value: Signal<Option<f64>>,
+  input_value: RwSignal<String>,
+  min: Signal<f64>,
+  max: Signal<f64>,
+  step: Signal<f64>,
+  large_step: Signal<f64>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+  required: Signal<bool>,
+  disable_wheel: Signal<bool>,
+  format: Signal<NumberFieldFormat>,
+  on_step: Callback<f64>,
+  on_commit: Callback<()>,
+}
+
+#[derive(Clone)]
+struct NumberFieldFormat {
+  formatter: Option<NumberFormat>,
+  fraction_digits: usize,
+  group: String,
+  decimal: String,
+}
+
+impl NumberFieldFormat {
+  fn format(&self, value: f64) -> String {
+    self
+      .formatter
+      .as_ref()
+      .and_then(|formatter| {
+        formatter
+          .format()
+          .call1(&JsValue::UNDEFINED, &JsValue::from_f64(value))
+          .ok()?
+          .as_string()
+      })
+      .unwrap_or_else(|| round_value(value, self.fraction_digits as u32).to_string())
+  }
+
+  fn parse(&self, text: &str) -> Option<f64> {
+    parse_number(text, &self.group, &self.decimal)
+  }
+}
+
+#[component]
+pub fn NumberFieldRoot(
+  #[prop(optional, into)] value: MaybeProp<f64>,
+  #[prop(optional, into)] default_value: MaybeProp<f64>,
+  #[prop(optional, into)] min: MaybeProp<f64>,
+  #[prop(optional, into)] max: MaybeProp<f64>,
+  #[prop(default=1.0f64.into(), into)] step: MaybeSignal<f64>,
+  #[prop(optional, into)] large_step: MaybeProp<f64>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] form: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+  #[prop(optional, into)] disable_wheel: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let min = Signal::derive(move || min.get().unwrap_or(f64::NEG_INFINITY));
+  let max = Signal::derive(move || max.get().unwrap_or(f64::INFINITY));
+  let step = Signal::derive(move || step.get());
+  let large_step = Signal::derive(move || large_step.get().unwrap_or_else(|| step.get() * 10.0));
+  let disabled = Signal::derive(move || disabled.get());
+  let read_only = Signal::derive(move || read_only.get());
+
+  let (is_localized, set_is_localized) = create_signal(false);
+
+  Effect::new(move |_| set_is_localized.set(true));
+
+  // formatting is only localized in the browser, so servers and the first client render agree
+  let format = Signal::derive(move || {
+    let fraction_digits = get_decimal_count(step.get()).max(3);
+
+    let formatter = is_localized
+      .get()
+      .then(|| create_number_formatter(locale.get().as_deref(), fraction_digits))
+      .flatten();
+
+    let (group, decimal) = formatter
+      .as_ref()
+      .and_then(get_separators)
+      .unwrap_or_else(|| (",".into(), ".".into()));
+
+    NumberFieldFormat {
+      formatter,
+      fraction_digits,
+      group,
+      decimal,
+    }
+  });
+
+  let input_value = RwSignal::new(String::new());
+
+  let format_input_value = move |value: Option<f64>| {
+    input_value.set(
+      value
+        .map(|value| format.with_untracked(|format| format.format(value)))
+        .unwrap_or_default(),
+    );
+  };
+
+  Effect::new(move |_| {
+    format.track();
+    format_input_value(value.get());
+  });
+
+  let set_number = move |next_value: Option<f64>| {
+    match next_value {
+      Some(next_value) => set_value.set(next_value),
+      None => set_value.update(|value| *value = None),
+    }
+
+    // the text still needs reformatting when the value itself didn't change
+    format_input_value(next_value);
+  };
+
+  let input_ref = NodeRef::new();
+
+  provide_context(NumberFieldContextValue {
+    input_id: create_id(),
+    input_ref,
+    value,
+    input_value,
+    min,
+    max,
+    step,
+    large_step,
+    disabled,
+    read_only,
+    required: Signal::derive(move || required.get()),
+    disable_wheel: Signal::derive(move || disable_wheel.get()),
+    format,
+    on_step: Callback::new(move |amount| {
+      if disabled.get_untracked() || read_only.get_untracked() {
+        return;
+      }
+
+      // steps start from whatever was typed even if it wasn't committed yet
+      let current_value = format
+        .with_untracked(|format| input_value.with_untracked(|text| format.parse(text)))
+        .or(value.get_untracked());
+
+      set_number(Some(get_stepped_value(
+        current_value,
+        amount,
+        min.get_untracked(),
+        max.get_untracked(),
+        step.get_untracked(),
+      )));
+    }),
+    on_commit: Callback::new(move |_| {
+      let text = input_value.get_untracked();
+
+      if text.trim().is_empty() {
+        set_number(None);
+        return;
+      }
+
+      match format.with_untracked(|format| format.parse(&text)) {
+        Some(parsed_value) => set_number(Some(snap_value_to_step(
+          parsed_value,
+          min.get_untracked(),
+          max.get_untracked(),
+          step.get_untracked(),
+        ))),
+        None => format_input_value(value.get_untracked()),
+      }
+    }),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:data-readonly=move || read_only.get().then_some("")
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+
+    // the visible input holds formatted text, forms get the plain number instead
+    <BubbleInput
+      value=Signal::derive(move || value.get().map(|value| value.to_string()))
+      field_ref=input_ref
+      name=Signal::derive(move || name.get())
+      form=Signal::derive(move || form.get())
+      disabled=disabled
+      required=Signal::derive(move || required.get())
+    />
+  }
+}
+
+#[component]
+pub fn NumberFieldInput(
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NumberFieldContextValue {
+    input_id,
+    input_ref,
+    value,
+    input_value,
+    min,
+    max,
+    step,
+    large_step,
+    disabled,
+    read_only,
+    required,
+    disable_wheel,
+    format,
+    on_step,
+    on_commit,
+  } = use_context().expect("NumberFieldInput must be used in a NumberFieldRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      input_ref.load(&node);
+    }
+  });
+
+  Effect::new(move |_| {
+    let input_value = input_value.get();
+
+    if let Some(node) = node_ref.get() {
+      _ = Reflect::set(&node, &"value".into(), &input_value.into());
+    }
+  });
+
+  let is_focused = StoredValue::new(false);
+
+  // mobile keyboards without a minus sign can't enter negative numbers
+  let input_mode = Signal::derive(move || {
+    if min.get() < 0.0 {
+      "text"
+    } else if step.get().fract() == 0.0 {
+      "numeric"
+    } else {
+      "decimal"
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=input_id
+      attr:type="text"
+      attr:role="spinbutton"
+      attr:inputmode=input_mode
+      attr:autocomplete="off"
+      attr:autocorrect="off"
+      attr:spellcheck="false"
+      attr:aria-valuenow=move || value.get()
+      attr:aria-valuetext=move || value.get().map(|value| format.with(|format| format.format(value)))
+      attr:aria-valuemin=move || Some(min.get()).filter(|min| min.is_finite())
+      attr:aria-valuemax=move || Some(max.get()).filter(|max| max.is_finite())
+      attr:aria-required=move || required.get().then_some("true")
+      attr:aria-readonly=move || read_only.get().then_some("true")
+      attr:readonly=read_only
+      attr:disabled=disabled
+      attr:data-disabled=move || disabled.get().then_some("")
+      element=html::input
+      on:input=move |ev: Event| input_value.set(event_target_value(&ev))
+      on:focus=move |_: FocusEvent| is_focused.set_value(true)
+      on:blur=move |ev: FocusEvent| {
+        on_blur.call(ev);
+        is_focused.set_value(false);
+        on_commit.call(());
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        let amount = match ev.key().as_str() {
+          "ArrowUp" => step.get_untracked(),
+          "ArrowDown" => -step.get_untracked(),
+          "PageUp" => large_step.get_untracked(),
+          "PageDown" => -large_step.get_untracked(),
+          "Home" if min.get_untracked().is_finite() => f64::NEG_INFINITY,
+          "End" if max.get_untracked().is_finite() => f64::INFINITY,
+          "Enter" => {
+            on_commit.call(());
+            return;
+          }
+          _ => return,
+        };
+
+        ev.prevent_default();
+        on_step.call(amount);
+      }
+      on:wheel=move |ev: WheelEvent| {
+        // only a focused field takes the wheel so scrolling the page past it still works
+        if !is_focused.get_value() || disable_wheel.get_untracked() || ev.delta_y() == 0.0 {
+          return;
+        }
+
+        ev.prevent_default();
+        on_step.call(if ev.delta_y() < 0.0 { step.get_untracked() } else { -step.get_untracked() });
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NumberFieldIncrement(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <NumberFieldStepButton
+      attrs=attrs
+      direction=1.0
+      label="Increase"
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "+".into_view())}
+    </NumberFieldStepButton>
+  }
+}
+
+#[component]
+pub fn NumberFieldDecrement(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <NumberFieldStepButton
+      attrs=attrs
+      direction=-1.0
+      label="Decrease"
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "−".into_view())}
+    </NumberFieldStepButton>
+  }
+}
+
+#[component]
+fn NumberFieldStepButton(
+  direction: f64,
+  label: &'static str,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NumberFieldContextValue {
+    input_id,
+    input_ref,
+    value,
+    min,
+    max,
+    step,
+    disabled,
+    read_only,
+    on_step,
+    ..
+  } = use_context().expect(
+    "NumberFieldIncrement and NumberFieldDecrement must be used in a NumberFieldRoot component",
+  );
+
+  let is_disabled = Signal::derive(move || {
+    disabled.get()
+      || read_only.get()
+      || value.get().is_some_and(|value| {
+        if direction > 0.0 {
+          value >= max.get()
+        } else {
+          value <= min.get()
+        }
+      })
+  });
+
+  let delay_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let repeat_timer = StoredValue::<Option<IntervalHandle>>::new(None);
+
+  let stop_auto_repeat = move || {
+    if let Some(Some(handle)) = delay_timer.try_get_value() {
+      handle.clear();
+      delay_timer.set_value(None);
+    }
+
+    if let Some(Some(handle)) = repeat_timer.try_get_value() {
+      handle.clear();
+      repeat_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(stop_auto_repeat);
+
+  let step_once = move || {
+    if is_disabled.get_untracked() {
+      stop_auto_repeat();
+    } else {
+      on_step.call(step.get_untracked() * direction);
+    }
+  };
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      attr:tabindex="-1"
+      attr:aria-label=label
+      attr:aria-controls=input_id
+      attr:disabled=is_disabled
+      attr:data-disabled=move || is_disabled.get().then_some("")
+      element=html::button
+      on:pointerdown=move |ev: PointerEvent| {
+        if ev.button() != 0 || is_disabled.get_untracked() {
+          return;
+        }
+
+        // keeps focus in the input so typing can continue after stepping
+        ev.prevent_default();
+
+        if let Some(input) = input_ref.get_untracked() {
+          _ = input.focus();
+        }
+
+        stop_auto_repeat();
+        step_once();
+
+        if let Ok(handle) = set_timeout_with_handle(
+          move || {
+            if let Ok(handle) =
+              set_interval_with_handle(step_once, Duration::from_millis(AUTO_REPEAT_INTERVAL))
+            {
+              repeat_timer.set_value(Some(handle));
+            }
+          },
+          Duration::from_millis(AUTO_REPEAT_DELAY),
+        ) {
+          delay_timer.set_value(Some(handle));
+        }
+      }
+      on:pointerup=move |_: PointerEvent| stop_auto_repeat()
+      on:pointerleave=move |_: PointerEvent| stop_auto_repeat()
+      on:pointercancel=move |_: PointerEvent| stop_auto_repeat()
+      // assistive technology activates the button with a click that has no pointer down before it
+      on:click=move |ev: MouseEvent| {
+        if ev.detail() == 0 {
+          step_once();
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn create_number_formatter(locale: Option<&str>, fraction_digits: usize) -> Option<NumberFormat> {
+  let locales = Array::new();
+
+  if let Some(locale) = locale {
+    locales.push(&JsValue::from_str(locale));
+  }
+
+  let options = Object::new();
+
+  Reflect::set(
+    &options,
+    &JsValue::from_str("maximumFractionDigits"),
+    &JsValue::from_f64(fraction_digits as f64),
+  )
+  .ok()?;
+
+  Some(NumberFormat::new(&locales, &options))
+}
+
+fn get_separators(formatter: &NumberFormat) -> Option<(String, String)> {
+  let mut group = None;
+  let mut decimal = None;
+
+  for part in formatter.format_to_parts(12345.6).iter() {
+    let get = |key| {
+      Reflect::get(&part, &JsValue::from_str(key))
+        .ok()?
+        .as_string()
+    };
+
+    match get("type").as_deref() {
+      Some("group") => group = get("value"),
+      Some("decimal") => decimal = get("value"),
+      _ => {}
+    }
+  }
+
+  // some locales don't group at all, a comma can't be mistaken for their decimal point then
+  Some((group.unwrap_or_default(), decimal?))
+}
+
+fn parse_number(text: &str, group: &str, decimal: &str) -> Option<f64> {
+  let mut normalized = text
+    .chars()
+    .filter(|char| !char.is_whitespace())
+    .map(|char| if char == '\u{2212}' { '-' } else { char })
+    .collect::<String>();
+
+  if !group.trim().is_empty() {
+    normalized = normalized.replace(group, "");
+  }
+
+  if decimal != "." {
+    normalized = normalized.replace(decimal, ".");
+  }
+
+  let is_number = normalized.char_indices().all(|(index, char)| {
+    char.is_ascii_digit() || char == '.' || (index == 0 && "+-".contains(char))
+  });
+
+  is_number
+    .then(|| normalized.parse::<f64>().ok())
+    .flatten()
+    .filter(|value| value.is_finite())
+}
+
+// an empty field starts from the bound it's moving away from, or zero when unbounded, while home
+// and end always land on their own bound
+fn get_stepped_value(value: Option<f64>, amount: f64, min: f64, max: f64, step: f64) -> f64 {
+  if amount.is_infinite() {
+    return if amount > 0.0 { max } else { min };
+  }
+
+  let Some(value) = value else {
+    let start = if amount > 0.0 { min } else { max };
+
+    return if start.is_finite() {
+      start
+    } else {
+      0.0f64.clamp(min, max)
+    };
+  };
+
+  snap_value_to_step(value + amount, min, max, step)
+}
+
+#[test]
+fn parses_localized_numbers() {
+  assert_eq!(parse_number("1,234.5", ",", "."), Some(1234.5));
+  assert_eq!(parse_number("1.234,5", ".", ","), Some(1234.5));
+  assert_eq!(
+    parse_number("1\u{202f}234,5", "\u{202f}", ","),
+    Some(1234.5)
+  );
+  assert_eq!(parse_number("\u{2212}5", ",", "."), Some(-5.0));
+  assert_eq!(parse_number(" -0.25 ", ",", "."), Some(-0.25));
+  assert_eq!(parse_number("1e5", ",", "."), None);
+  assert_eq!(parse_number("inf", ",", "."), None);
+  assert_eq!(parse_number("1-2", ",", "."), None);
+}
+
+#[test]
+fn steps_snap_and_clamp() {
+  assert_eq!(get_stepped_value(Some(1.0), 0.1, 0.0, 10.0, 0.1), 1.1);
+  assert_eq!(get_stepped_value(Some(0.3), 0.1, 0.0, 10.0, 0.1), 0.4);
+  assert_eq!(get_stepped_value(Some(1.3), 1.0, 0.0, 10.0, 1.0), 2.0);
+  assert_eq!(get_stepped_value(Some(9.5), 10.0, 0.0, 10.0, 1.0), 10.0);
+  assert_eq!(get_stepped_value(Some(7.0), 5.0, 1.0, 100.0, 5.0), 11.0);
+  assert_eq!(
+    get_stepped_value(Some(4.0), f64::INFINITY, 1.0, 10.0, 1.0),
+    10.0
+  );
+}
+
+#[test]
+fn steps_from_bounds_when_empty() {
+  assert_eq!(get_stepped_value(None, 1.0, 5.0, 10.0, 1.0), 5.0);
+  assert_eq!(get_stepped_value(None, -1.0, 5.0, 10.0, 1.0), 10.0);
+  assert_eq!(
+    get_stepped_value(None, 1.0, f64::NEG_INFINITY, f64::INFINITY, 1.0),
+    0.0
+  );
+  assert_eq!(
+    get_stepped_value(None, 1.0, f64::NEG_INFINITY, -3.0, 1.0),
+    -3.0
+  );
+  // home and end jump straight to their bound
+  assert_eq!(
+    get_stepped_value(None, f64::NEG_INFINITY, 1.0, 10.0, 1.0),
+    1.0
+  );
+  assert_eq!(get_stepped_value(None, f64::INFINITY, 1.0, 10.0, 1.0), 10.0);
+}
+
+#[test]
+fn snaps_from_zero_without_a_minimum() {
+  assert_eq!(
+    snap_value_to_step(7.4, f64::NEG_INFINITY, f64::INFINITY, 0.5),
+    7.5
+  );
+  assert_eq!(snap_value_to_step(-2.6, f64::NEG_INFINITY, 0.0, 1.0), -3.0);
+}
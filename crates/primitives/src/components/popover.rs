@@ -0,0 +1,477 @@
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, Event, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  aria_hidden::use_hide_others,
+  dismissable_layer::{get_original_event, DismissableLayer, FOCUS_OUTSIDE, POINTER_DOWN_OUTSIDE},
+  focus_guards::FocusGuards,
+  focus_scope::FocusScope,
+  popper::{PopperAnchor, PopperArrow, PopperContent, PopperRoot},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  scroll_lock::use_body_scroll_lock,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    Attributes,
+  },
+  Align, Side, Sticky,
+};
+
+#[derive(Clone)]
+struct PopoverContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  modal: Signal<bool>,
+  has_custom_anchor: ReadSignal<bool>,
+  set_has_custom_anchor: WriteSignal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+}
+
+#[derive(Clone)]
+struct PopoverPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn PopoverRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let (has_custom_anchor, set_has_custom_anchor) = create_signal(false);
+
+  provide_context(PopoverContextValue {
+    trigger_ref: NodeRef::new(),
+    content_id: create_id(),
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    modal: Signal::derive(move || modal.get()),
+    has_custom_anchor,
+    set_has_custom_anchor,
+    on_open_change: Callback::new(move |value| {
+      set_open.set(value);
+    }),
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn PopoverAnchor(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    set_has_custom_anchor,
+    ..
+  } = use_context().expect("PopoverAnchor must be used in a PopoverRoot component");
+
+  // set while rendering so the trigger doesn't briefly register itself as the anchor
+  set_has_custom_anchor.set(true);
+
+  on_cleanup(move || {
+    _ = set_has_custom_anchor.try_set(false);
+  });
+
+  view! {
+    <PopperAnchor
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn PopoverTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    has_custom_anchor,
+    on_open_toggle,
+    ..
+  } = use_context().expect("PopoverTrigger must be used in a PopoverRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  let trigger = move || {
+    view! {
+      <Primitive
+        {..attrs.get_value()}
+        attr:type="button"
+        attr:aria-haspopup="dialog"
+        attr:aria-expanded=open
+        attr:aria-controls=content_id
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        element=html::button
+        on:click=move |ev: MouseEvent| {
+          on_click.call(ev);
+          on_open_toggle.call(());
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    }
+  };
+
+  // the trigger doubles as the anchor unless a PopoverAnchor was rendered
+  view! {
+    <Show
+      when=move || has_custom_anchor.get()
+      fallback=move || view! {
+        <PopperAnchor as_child=true>
+          {trigger()}
+        </PopperAnchor>
+      }
+    >
+      {trigger()}
+    </Show>
+  }
+}
+
+#[component]
+pub fn PopoverPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(PopoverPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+#[component]
+pub fn PopoverContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<CustomEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    modal,
+    on_open_change,
+    ..
+  } = use_context().expect("PopoverContent must be used in a PopoverRoot component");
+
+  let portal_force_mount = use_context::<PopoverPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || open.get() || force_mount.get() || portal_force_mount.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let is_modal_present = Signal::derive(move || modal.get() && presence.get());
+
+  use_body_scroll_lock(is_modal_present);
+  use_hide_others(node_ref, is_modal_present);
+
+  let has_interacted_outside = StoredValue::new(false);
+  let has_pointer_down_outside = StoredValue::new(false);
+
+  let focus_trigger = move || {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+      _ = trigger.focus();
+    }
+  };
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-popover-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-popover-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-popover-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-popover-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-popover-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <FocusGuards>
+        <PopperContent
+          as_child=true
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          node_ref=node_ref
+        >
+          <FocusScope
+            as_child=true
+            should_loop=true
+            trapped=modal
+            on_mount_auto_focus=on_open_auto_focus
+            on_unmount_auto_focus=move |ev: Event| {
+              on_close_auto_focus.call(ev.clone());
+
+              if modal.get_untracked() {
+                if !ev.default_prevented() {
+                  ev.prevent_default();
+                  focus_trigger();
+                }
+              } else {
+                if !ev.default_prevented() {
+                  if !has_interacted_outside.get_value() {
+                    focus_trigger();
+                  }
+
+                  ev.prevent_default();
+                }
+
+                has_interacted_outside.set_value(false);
+                has_pointer_down_outside.set_value(false);
+              }
+            }
+          >
+            <DismissableLayer
+              as_child=true
+              disable_outside_pointer_events=modal
+              on_escape_key_down=on_escape_key_down
+              on_pointer_down_outside=move |ev: CustomEvent| {
+                on_pointer_down_outside.call(ev.clone());
+
+                if !modal.get_untracked() {
+                  return;
+                }
+
+                let Some(original_event) = get_original_event::<PointerEvent>(&ev) else {
+                  return;
+                };
+
+                let is_ctrl_left_click = original_event.button() == 0 && original_event.ctrl_key();
+                let is_right_click = original_event.button() == 2 || is_ctrl_left_click;
+
+                // right clicking outside of a modal popover shouldn't close it
+                if is_right_click {
+                  ev.prevent_default();
+                }
+              }
+              on_focus_outside=move |ev: CustomEvent| {
+                on_focus_outside.call(ev.clone());
+
+                // focus is trapped inside of modal popovers, so the popover shouldn't close when focus moves out
+                if modal.get_untracked() {
+                  ev.prevent_default();
+                }
+              }
+              on_interact_outside=move |ev: CustomEvent| {
+                on_interact_outside.call(ev.clone());
+
+                if modal.get_untracked() {
+                  return;
+                }
+
+                if !ev.default_prevented() {
+                  has_interacted_outside.set_value(true);
+
+                  if ev.type_() == POINTER_DOWN_OUTSIDE {
+                    has_pointer_down_outside.set_value(true);
+                  }
+                }
+
+                let target = get_original_event::<Event>(&ev)
+                  .and_then(|original_event| original_event.target())
+                  .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+                let is_target_trigger = trigger_ref
+                  .get_untracked()
+                  .zip(target)
+                  .map(|(trigger, target)| trigger.contains(Some(&target)))
+                  .unwrap_or(false);
+
+                // the trigger toggles the popover itself, so interacting with it shouldn't dismiss the popover first
+                if is_target_trigger {
+                  ev.prevent_default();
+                }
+
+                if ev.type_() == FOCUS_OUTSIDE && has_pointer_down_outside.get_value() {
+                  ev.prevent_default();
+                }
+              }
+              on_dismiss=move |_| on_open_change.call(false)
+            >
+              <Primitive
+                {..attrs.get_value()}
+                attr:role="dialog"
+                attr:id=content_id
+                attr:data-state=move || if open.get() { "open" } else { "closed" }
+                element=html::div
+                node_ref=node_ref
+                as_child=as_child
+              >
+                {children.with_value(|children| children())}
+              </Primitive>
+            </DismissableLayer>
+          </FocusScope>
+        </PopperContent>
+      </FocusGuards>
+    </Show>
+  }
+}
+
+#[component]
+pub fn PopoverClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue { on_open_change, .. } =
+    use_context().expect("PopoverClose must be used in a PopoverRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_change.call(false);
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn PopoverArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  match children {
+    Some(children) => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </PopperArrow>
+    },
+    None => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    },
+  }
+}
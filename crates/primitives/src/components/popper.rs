@@ -0,0 +1,1001 @@
+use leptos::{
+  ev::{resize, scroll},
+  html::{AnyElement, Div, Span},
+  *,
+};
+use leptos_use::{
+  use_element_size, use_event_listener, use_event_listener_with_options, use_resize_observer,
+  use_window, UseElementSizeReturn, UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::DomRect;
+
+use crate::{primitive::Primitive, util::Attributes, Align, Side, Sticky};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Rect {
+  pub(crate) x: f64,
+  pub(crate) y: f64,
+  pub(crate) width: f64,
+  pub(crate) height: f64,
+}
+
+impl Rect {
+  pub(crate) fn top(&self) -> f64 {
+    self.y
+  }
+
+  pub(crate) fn right(&self) -> f64 {
+    self.x + self.width
+  }
+
+  pub(crate) fn bottom(&self) -> f64 {
+    self.y + self.height
+  }
+
+  pub(crate) fn left(&self) -> f64 {
+    self.x
+  }
+
+  fn inset(&self, padding: f64) -> Self {
+    Self {
+      x: self.x + padding,
+      y: self.y + padding,
+      width: (self.width - padding * 2.0).max(0.0),
+      height: (self.height - padding * 2.0).max(0.0),
+    }
+  }
+
+  fn intersect(&self, other: &Self) -> Self {
+    let left = self.left().max(other.left());
+    let top = self.top().max(other.top());
+    let right = self.right().min(other.right());
+    let bottom = self.bottom().min(other.bottom());
+
+    Self {
+      x: left,
+      y: top,
+      width: (right - left).max(0.0),
+      height: (bottom - top).max(0.0),
+    }
+  }
+}
+
+impl From<DomRect> for Rect {
+  fn from(rect: DomRect) -> Self {
+    Self {
+      x: rect.x(),
+      y: rect.y(),
+      width: rect.width(),
+      height: rect.height(),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Size {
+  pub(crate) width: f64,
+  pub(crate) height: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct PlacementOptions {
+  pub(crate) side: Side,
+  pub(crate) align: Align,
+  pub(crate) side_offset: f64,
+  pub(crate) align_offset: f64,
+  pub(crate) avoid_collisions: bool,
+  pub(crate) collision_padding: f64,
+  pub(crate) sticky: Sticky,
+  pub(crate) arrow: Size,
+  pub(crate) arrow_padding: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Placement {
+  pub(crate) x: f64,
+  pub(crate) y: f64,
+  pub(crate) side: Side,
+  pub(crate) align: Align,
+  pub(crate) available_width: f64,
+  pub(crate) available_height: f64,
+  pub(crate) arrow_x: Option<f64>,
+  pub(crate) arrow_y: Option<f64>,
+  pub(crate) arrow_center_offset: f64,
+  pub(crate) reference_hidden: bool,
+}
+
+impl Side {
+  pub(crate) fn opposite(&self) -> Self {
+    match self {
+      Self::Top => Self::Bottom,
+      Self::Right => Self::Left,
+      Self::Bottom => Self::Top,
+      Self::Left => Self::Right,
+    }
+  }
+
+  pub(crate) fn is_vertical(&self) -> bool {
+    matches!(self, Self::Top | Self::Bottom)
+  }
+}
+
+fn get_overflow(
+  side: Side,
+  reference: &Rect,
+  floating: &Size,
+  boundary: &Rect,
+  offset: f64,
+) -> f64 {
+  match side {
+    Side::Top => boundary.top() - (reference.top() - offset - floating.height),
+    Side::Right => (reference.right() + offset + floating.width) - boundary.right(),
+    Side::Bottom => (reference.bottom() + offset + floating.height) - boundary.bottom(),
+    Side::Left => boundary.left() - (reference.left() - offset - floating.width),
+  }
+}
+
+fn clamp(min: f64, value: f64, max: f64) -> f64 {
+  min.max(value.min(max))
+}
+
+pub(crate) fn compute_placement(
+  reference: Rect,
+  floating: Size,
+  boundary: Rect,
+  options: &PlacementOptions,
+) -> Placement {
+  let boundary = boundary.inset(options.collision_padding);
+  let main_offset = options.side_offset + options.arrow.height;
+
+  let side = if options.avoid_collisions {
+    let overflow = get_overflow(options.side, &reference, &floating, &boundary, main_offset);
+    let opposite_overflow = get_overflow(
+      options.side.opposite(),
+      &reference,
+      &floating,
+      &boundary,
+      main_offset,
+    );
+
+    if overflow > 0.0 && opposite_overflow < overflow {
+      options.side.opposite()
+    } else {
+      options.side
+    }
+  } else {
+    options.side
+  };
+
+  let (mut x, mut y) = match side {
+    Side::Top => (0.0, reference.top() - floating.height - main_offset),
+    Side::Bottom => (0.0, reference.bottom() + main_offset),
+    Side::Left => (reference.left() - floating.width - main_offset, 0.0),
+    Side::Right => (reference.right() + main_offset, 0.0),
+  };
+
+  let align_offset = if options.align == Align::End {
+    -options.align_offset
+  } else {
+    options.align_offset
+  };
+
+  if side.is_vertical() {
+    x = match options.align {
+      Align::Start => reference.left(),
+      Align::Center => reference.left() + (reference.width - floating.width) / 2.0,
+      Align::End => reference.right() - floating.width,
+    } + align_offset;
+  } else {
+    y = match options.align {
+      Align::Start => reference.top(),
+      Align::Center => reference.top() + (reference.height - floating.height) / 2.0,
+      Align::End => reference.bottom() - floating.height,
+    } + align_offset;
+  }
+
+  if options.avoid_collisions {
+    if side.is_vertical() {
+      x = clamp(boundary.left(), x, boundary.right() - floating.width);
+
+      if options.sticky == Sticky::Partial {
+        x = clamp(reference.left() - floating.width, x, reference.right());
+      }
+    } else {
+      y = clamp(boundary.top(), y, boundary.bottom() - floating.height);
+
+      if options.sticky == Sticky::Partial {
+        y = clamp(reference.top() - floating.height, y, reference.bottom());
+      }
+    }
+  }
+
+  let (available_width, available_height) = match side {
+    Side::Top => (
+      boundary.width,
+      reference.top() - main_offset - boundary.top(),
+    ),
+    Side::Bottom => (
+      boundary.width,
+      boundary.bottom() - reference.bottom() - main_offset,
+    ),
+    Side::Left => (
+      reference.left() - main_offset - boundary.left(),
+      boundary.height,
+    ),
+    Side::Right => (
+      boundary.right() - reference.right() - main_offset,
+      boundary.height,
+    ),
+  };
+
+  let get_arrow_offset = |reference_start: f64,
+                          reference_length: f64,
+                          floating_start: f64,
+                          floating_length: f64,
+                          arrow_length: f64| {
+    let center = reference_start + reference_length / 2.0 - floating_start - arrow_length / 2.0;
+    let min = options.arrow_padding;
+    let max = (floating_length - arrow_length - options.arrow_padding).max(min);
+    let offset = clamp(min, center, max);
+
+    (offset, center - offset)
+  };
+
+  let (arrow_x, arrow_y, arrow_center_offset) = if side.is_vertical() {
+    let (offset, center_offset) = get_arrow_offset(
+      reference.left(),
+      reference.width,
+      x,
+      floating.width,
+      options.arrow.width,
+    );
+
+    (Some(offset), None, center_offset)
+  } else {
+    let (offset, center_offset) = get_arrow_offset(
+      reference.top(),
+      reference.height,
+      y,
+      floating.height,
+      options.arrow.width,
+    );
+
+    (None, Some(offset), center_offset)
+  };
+
+  let reference_hidden = reference.bottom() <= boundary.top()
+    || reference.top() >= boundary.bottom()
+    || reference.right() <= boundary.left()
+    || reference.left() >= boundary.right();
+
+  Placement {
+    x,
+    y,
+    side,
+    align: options.align,
+    available_width: available_width.max(0.0),
+    available_height: available_height.max(0.0),
+    arrow_x,
+    arrow_y,
+    arrow_center_offset,
+    reference_hidden,
+  }
+}
+
+pub(crate) fn get_transform_origin(placement: &Placement, floating: Size, arrow: Size) -> String {
+  let is_arrow_hidden = placement.arrow_center_offset != 0.0 || arrow == Size::default();
+  let (arrow_width, arrow_height) = if is_arrow_hidden {
+    (0.0, 0.0)
+  } else {
+    (arrow.width, arrow.height)
+  };
+
+  let no_arrow_align = match placement.align {
+    Align::Start => "0%",
+    Align::Center => "50%",
+    Align::End => "100%",
+  };
+
+  let arrow_x_center = if is_arrow_hidden {
+    no_arrow_align.to_string()
+  } else {
+    format!("{}px", placement.arrow_x.unwrap_or(0.0) + arrow_width / 2.0)
+  };
+
+  let arrow_y_center = if is_arrow_hidden {
+    no_arrow_align.to_string()
+  } else {
+    format!("{}px", placement.arrow_y.unwrap_or(0.0) + arrow_width / 2.0)
+  };
+
+  match placement.side {
+    Side::Bottom => format!("{arrow_x_center} {}px", 0.0 - arrow_height),
+    Side::Top => format!("{arrow_x_center} {}px", floating.height + arrow_height),
+    Side::Right => format!("{}px {arrow_y_center}", 0.0 - arrow_height),
+    Side::Left => format!("{}px {arrow_y_center}", floating.width + arrow_height),
+  }
+}
+
+#[derive(Clone)]
+struct PopperContextValue {
+  anchor_ref: NodeRef<AnyElement>,
+  virtual_anchor: RwSignal<Option<Rect>>,
+}
+
+#[derive(Clone)]
+struct PopperContentContextValue {
+  placed_side: Signal<Side>,
+  arrow_ref: NodeRef<Span>,
+  arrow_x: Signal<Option<f64>>,
+  arrow_y: Signal<Option<f64>>,
+  should_hide_arrow: Signal<bool>,
+}
+
+#[component]
+pub(crate) fn PopperRoot(children: ChildrenFn) -> impl IntoView {
+  provide_context(PopperContextValue {
+    anchor_ref: NodeRef::new(),
+    virtual_anchor: RwSignal::new(None),
+  });
+
+  children()
+}
+
+#[component]
+pub(crate) fn PopperAnchor(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopperContextValue { anchor_ref, .. } =
+    use_context().expect("PopperAnchor must be used in a PopperRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      anchor_ref.load(&node);
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+// anchors the content to a point that isn't backed by an element, like the pointer position
+#[component]
+pub(crate) fn PopperVirtualAnchor(#[prop(into)] rect: Signal<Rect>) -> impl IntoView {
+  let PopperContextValue { virtual_anchor, .. } =
+    use_context().expect("PopperVirtualAnchor must be used in a PopperRoot component");
+
+  Effect::new(move |_| virtual_anchor.set(Some(rect.get())));
+
+  on_cleanup(move || {
+    _ = virtual_anchor.try_set(None);
+  });
+}
+
+#[component]
+pub(crate) fn PopperContent(
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_placed: Callback<()>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopperContextValue {
+    anchor_ref,
+    virtual_anchor,
+  } = use_context().expect("PopperContent must be used in a PopperRoot component");
+
+  let wrapper_ref = NodeRef::<Div>::new();
+  let arrow_ref = NodeRef::<Span>::new();
+
+  let UseElementSizeReturn {
+    width: arrow_width,
+    height: arrow_height,
+  } = use_element_size(arrow_ref);
+
+  let (placement, set_placement) = create_signal::<Option<Placement>>(None);
+  let (anchor_size, set_anchor_size) = create_signal(Size::default());
+  let (floating_size, set_floating_size) = create_signal(Size::default());
+
+  let arrow_size = Signal::derive(move || Size {
+    width: arrow_width.get(),
+    height: arrow_height.get(),
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+
+  let update_position = move || {
+    let Some(content) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let Some(reference) = virtual_anchor.get_untracked().or_else(|| {
+      anchor_ref
+        .get_untracked()
+        .map(|anchor| Rect::from(anchor.get_bounding_client_rect()))
+    }) else {
+      return;
+    };
+
+    let Some(document_element) = document().document_element() else {
+      return;
+    };
+
+    let floating = content
+      .dyn_ref::<web_sys::HtmlElement>()
+      .map(|content| Size {
+        width: content.offset_width() as f64,
+        height: content.offset_height() as f64,
+      })
+      .unwrap_or_default();
+
+    let viewport = Rect {
+      x: 0.0,
+      y: 0.0,
+      width: document_element.client_width() as f64,
+      height: document_element.client_height() as f64,
+    };
+
+    let boundary = collision_boundary
+      .get_untracked()
+      .map(|boundary| viewport.intersect(&boundary.get_bounding_client_rect().into()))
+      .unwrap_or(viewport);
+
+    let next_placement = compute_placement(
+      reference,
+      floating,
+      boundary,
+      &PlacementOptions {
+        side: side.get_untracked(),
+        align: align.get_untracked(),
+        side_offset: side_offset.get_untracked(),
+        align_offset: align_offset.get_untracked(),
+        avoid_collisions: avoid_collisions.get_untracked(),
+        collision_padding: collision_padding.get_untracked(),
+        sticky: sticky.get_untracked(),
+        arrow: arrow_size.get_untracked(),
+        arrow_padding: arrow_padding.get_untracked(),
+      },
+    );
+
+    set_anchor_size.set(Size {
+      width: reference.width,
+      height: reference.height,
+    });
+    set_floating_size.set(floating);
+
+    if placement.get_untracked() != Some(next_placement) {
+      set_placement.set(Some(next_placement));
+    }
+  };
+
+  Effect::new(move |_| {
+    _ = anchor_ref.get();
+    _ = virtual_anchor.get();
+    _ = node_ref.get();
+    _ = side.get();
+    _ = align.get();
+    _ = side_offset.get();
+    _ = align_offset.get();
+    _ = avoid_collisions.get();
+    _ = collision_boundary.get();
+    _ = collision_padding.get();
+    _ = sticky.get();
+    _ = arrow_size.get();
+    _ = arrow_padding.get();
+
+    update_position();
+  });
+
+  _ = use_event_listener_with_options(
+    use_window(),
+    scroll,
+    move |_| update_position(),
+    UseEventListenerOptions::default()
+      .capture(true)
+      .passive(true),
+  );
+
+  _ = use_event_listener(use_window(), resize, move |_| update_position());
+
+  use_resize_observer(anchor_ref, move |_, _| update_position());
+  use_resize_observer(node_ref, move |_, _| update_position());
+
+  let is_placed = Signal::derive(move || placement.get().is_some());
+
+  Effect::new(move |was_placed: Option<bool>| {
+    let is_placed = is_placed.get();
+
+    if is_placed && !was_placed.unwrap_or(false) {
+      on_placed.call(());
+    }
+
+    is_placed
+  });
+
+  Effect::new(move |_| {
+    let (Some(wrapper), Some(content)) = (wrapper_ref.get(), node_ref.get()) else {
+      return;
+    };
+
+    if let Ok(Some(content_style)) = window().get_computed_style(&content) {
+      if let Ok(z_index) = content_style.get_property_value("z-index") {
+        _ = wrapper.style("z-index", z_index);
+      }
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(wrapper) = wrapper_ref.get() else {
+      return;
+    };
+
+    let placement = placement.get().unwrap_or_default();
+    let anchor_size = anchor_size.get();
+
+    _ = wrapper
+      .style(
+        "--leptix-popper-transform-origin",
+        get_transform_origin(&placement, floating_size.get(), arrow_size.get()),
+      )
+      .style(
+        "--leptix-popper-available-width",
+        format!("{}px", placement.available_width),
+      )
+      .style(
+        "--leptix-popper-available-height",
+        format!("{}px", placement.available_height),
+      )
+      .style(
+        "--leptix-popper-anchor-width",
+        format!("{}px", anchor_size.width),
+      )
+      .style(
+        "--leptix-popper-anchor-height",
+        format!("{}px", anchor_size.height),
+      );
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    // keeps the content from animating in from its unpositioned place
+    _ = node.style("animation", (!is_placed.get()).then_some("none"));
+  });
+
+  let placed_side = Signal::derive(move || {
+    placement
+      .get()
+      .map(|placement| placement.side)
+      .unwrap_or(side.get())
+  });
+  let placed_align = Signal::derive(move || {
+    placement
+      .get()
+      .map(|placement| placement.align)
+      .unwrap_or(align.get())
+  });
+
+  let is_hidden = Signal::derive(move || {
+    hide_when_detached.get()
+      && placement
+        .get()
+        .map(|placement| placement.reference_hidden)
+        .unwrap_or(false)
+  });
+
+  provide_context(PopperContentContextValue {
+    placed_side,
+    arrow_ref,
+    arrow_x: Signal::derive(move || placement.get().and_then(|placement| placement.arrow_x)),
+    arrow_y: Signal::derive(move || placement.get().and_then(|placement| placement.arrow_y)),
+    should_hide_arrow: Signal::derive(move || {
+      placement
+        .get()
+        .map(|placement| placement.arrow_center_offset != 0.0)
+        .unwrap_or(true)
+    }),
+  });
+
+  view! {
+    <div
+      node_ref=wrapper_ref
+      data-leptix-popper-content-wrapper=""
+      style:position="fixed"
+      style:left="0"
+      style:top="0"
+      style:min-width="max-content"
+      style:transform=move || {
+        placement
+          .get()
+          .map(|placement| format!("translate({}px, {}px)", placement.x.round(), placement.y.round()))
+          .unwrap_or("translate(0, -200%)".to_string())
+      }
+      style:visibility=move || is_hidden.get().then_some("hidden")
+      style:pointer-events=move || is_hidden.get().then_some("none")
+    >
+      <Primitive
+        {..attrs}
+        attr:data-side=move || placed_side.get().to_string()
+        attr:data-align=move || placed_align.get().to_string()
+        element=html::div
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children()}
+      </Primitive>
+    </div>
+  }
+}
+
+#[component]
+pub(crate) fn PopperArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopperContentContextValue {
+    placed_side,
+    arrow_ref,
+    arrow_x,
+    arrow_y,
+    should_hide_arrow,
+  } = use_context().expect("PopperArrow must be used in a PopperContent component");
+
+  let base_side = Signal::derive(move || placed_side.get().opposite());
+
+  let edge = move |edge: Side, offset: Signal<Option<f64>>| {
+    move || {
+      if base_side.get() == edge {
+        Some("0px".to_string())
+      } else {
+        offset.get().map(|offset| format!("{offset}px"))
+      }
+    }
+  };
+
+  let span_ref = NodeRef::<Span>::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = span_ref.get() {
+      arrow_ref.load(&node);
+    }
+  });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <span
+      style:position="absolute"
+      style:top=edge(Side::Top, arrow_y)
+      style:right=edge(Side::Right, Signal::derive(|| None))
+      style:bottom=edge(Side::Bottom, Signal::derive(|| None))
+      style:left=edge(Side::Left, arrow_x)
+      style:transform-origin=move || match placed_side.get() {
+        Side::Top => "",
+        Side::Right => "0 0",
+        Side::Bottom => "center 0",
+        Side::Left => "100% 0",
+      }
+      style:transform=move || match placed_side.get() {
+        Side::Top => "translateY(100%)",
+        Side::Right => "translateY(50%) rotate(90deg) translateX(-50%)",
+        Side::Bottom => "rotate(180deg)",
+        Side::Left => "translateY(50%) rotate(-90deg) translateX(50%)",
+      }
+      style:visibility=move || should_hide_arrow.get().then_some("hidden")
+      node_ref=span_ref
+    >
+      <Primitive
+        {..attrs}
+        attr:width=width
+        attr:height=height
+        attr:viewBox="0 0 30 10"
+        attr:preserveAspectRatio="none"
+        element=svg::svg
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children
+          .with_value(|children| children.as_ref().map(|children| children().into_view()))
+          .unwrap_or_else(|| view! { <polygon points="0,0 30,0 15,10"/> }.into_view())}
+      </Primitive>
+    </span>
+  }
+}
+
+#[test]
+fn places_content_on_the_preferred_side() {
+  let placement = compute_placement(
+    Rect {
+      x: 100.0,
+      y: 100.0,
+      width: 50.0,
+      height: 20.0,
+    },
+    Size {
+      width: 80.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &PlacementOptions {
+      side: Side::Bottom,
+      side_offset: 5.0,
+      avoid_collisions: true,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(placement.side, Side::Bottom);
+  assert_eq!((placement.x, placement.y), (85.0, 125.0));
+  assert_eq!(placement.available_height, 375.0);
+}
+
+#[test]
+fn aligns_content_with_offsets() {
+  let reference = Rect {
+    x: 100.0,
+    y: 100.0,
+    width: 50.0,
+    height: 20.0,
+  };
+  let floating = Size {
+    width: 80.0,
+    height: 40.0,
+  };
+  let boundary = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 500.0,
+    height: 500.0,
+  };
+
+  let start = compute_placement(
+    reference,
+    floating,
+    boundary,
+    &PlacementOptions {
+      align: Align::Start,
+      align_offset: 4.0,
+      ..Default::default()
+    },
+  );
+
+  let end = compute_placement(
+    reference,
+    floating,
+    boundary,
+    &PlacementOptions {
+      side: Side::Right,
+      align: Align::End,
+      align_offset: 4.0,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!((start.x, start.y), (104.0, 120.0));
+  assert_eq!((end.x, end.y), (150.0, 76.0));
+}
+
+#[test]
+fn flips_to_the_opposite_side_on_collision() {
+  let placement = compute_placement(
+    Rect {
+      x: 100.0,
+      y: 20.0,
+      width: 50.0,
+      height: 20.0,
+    },
+    Size {
+      width: 80.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &PlacementOptions {
+      side: Side::Top,
+      avoid_collisions: true,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(placement.side, Side::Bottom);
+  assert_eq!(placement.y, 40.0);
+
+  let placement = compute_placement(
+    Rect {
+      x: 100.0,
+      y: 20.0,
+      width: 50.0,
+      height: 20.0,
+    },
+    Size {
+      width: 80.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &PlacementOptions {
+      side: Side::Top,
+      avoid_collisions: false,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(placement.side, Side::Top);
+  assert_eq!(placement.y, -20.0);
+}
+
+#[test]
+fn shifts_content_into_the_boundary() {
+  let options = PlacementOptions {
+    avoid_collisions: true,
+    collision_padding: 10.0,
+    ..Default::default()
+  };
+
+  let partial = compute_placement(
+    Rect {
+      x: 0.0,
+      y: 100.0,
+      width: 20.0,
+      height: 20.0,
+    },
+    Size {
+      width: 100.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &options,
+  );
+
+  assert_eq!(partial.x, 10.0);
+
+  let detached = compute_placement(
+    Rect {
+      x: -200.0,
+      y: 100.0,
+      width: 20.0,
+      height: 20.0,
+    },
+    Size {
+      width: 100.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &options,
+  );
+
+  assert_eq!(detached.x, -180.0);
+  assert!(detached.reference_hidden);
+
+  let always = compute_placement(
+    Rect {
+      x: -200.0,
+      y: 100.0,
+      width: 20.0,
+      height: 20.0,
+    },
+    Size {
+      width: 100.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &PlacementOptions {
+      sticky: Sticky::Always,
+      ..options
+    },
+  );
+
+  assert_eq!(always.x, 10.0);
+}
+
+#[test]
+fn positions_the_arrow_towards_the_reference() {
+  let placement = compute_placement(
+    Rect {
+      x: 0.0,
+      y: 100.0,
+      width: 20.0,
+      height: 20.0,
+    },
+    Size {
+      width: 100.0,
+      height: 40.0,
+    },
+    Rect {
+      x: 0.0,
+      y: 0.0,
+      width: 500.0,
+      height: 500.0,
+    },
+    &PlacementOptions {
+      avoid_collisions: true,
+      arrow: Size {
+        width: 10.0,
+        height: 5.0,
+      },
+      arrow_padding: 8.0,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(placement.y, 125.0);
+  assert_eq!(placement.arrow_x, Some(8.0));
+  assert_eq!(placement.arrow_y, None);
+  assert_eq!(placement.arrow_center_offset, -3.0);
+  assert_eq!(
+    get_transform_origin(
+      &placement,
+      Size {
+        width: 100.0,
+        height: 40.0
+      },
+      Size {
+        width: 10.0,
+        height: 5.0
+      }
+    ),
+    "50% 0px"
+  );
+}
@@ -0,0 +1,32 @@
+use leptos::*;
+
+#[component]
+pub fn Portal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  // nothing is rendered on the server, the portal only mounts once the client has hydrated
+  let (is_mounted, set_is_mounted) = create_signal(false);
+
+  Effect::new(move |_| set_is_mounted.set(true));
+
+  let container = Signal::derive(move || container.get());
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_mounted.get()>
+      {move || {
+        let container = container
+          .get()
+          .unwrap_or_else(|| document().body().expect("body to exist").into());
+
+        view! {
+          <leptos::Portal mount=container>
+            {children.with_value(|children| children())}
+          </leptos::Portal>
+        }
+      }}
+    </Show>
+  }
+}
@@ -13,6 +13,7 @@ use crate::{
   presence::create_presence,
   primitive::Primitive,
   util::{create_previous::create_previous, Attributes},
+  visually_hidden::VisuallyHidden,
 };
 
 #[derive(Clone)]
@@ -197,24 +198,33 @@ fn BubbleInput(
     })();
   });
 
+  let attrs = StoredValue::new(attrs);
+  let name = Signal::derive(move || name.get());
+  let value = Signal::derive(move || value.get());
+
   view! {
-    <input
-      type="checkbox"
-      aria-hidden
-      name=name.into_attribute()
-      value=value.into_attribute()
-      required=required.into_attribute()
-      disabled=disabled.into_attribute()
-      checked=checked.into_attribute()
-      tabindex=(-1).into_attribute()
-      node_ref=node_ref
-      style:position="absolute"
-      style:pointer-events="none"
-      style:opacity="0"
-      style:margin="0"
-      style:width=move || width.get()
-      style:height=move || height.get()
-      {..attrs}
-    />
+    <VisuallyHidden
+      as_child=true
+      attr:style=move || {
+        format!(
+          "pointer-events: none; opacity: 0; margin: 0; width: {}px; height: {}px;",
+          width.get(),
+          height.get()
+        )
+      }
+    >
+      <input
+        {..attrs.get_value()}
+        type="checkbox"
+        aria-hidden
+        name=name
+        value=value
+        required=required.into_attribute()
+        disabled=disabled.into_attribute()
+        checked=checked.into_attribute()
+        tabindex=(-1).into_attribute()
+        node_ref=node_ref
+      />
+    </VisuallyHidden>
   }
 }
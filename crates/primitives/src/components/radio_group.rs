@@ -8,6 +8,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{FocusEvent, HtmlButtonElement, KeyboardEvent};
 
 use crate::{
+  direction::use_local_direction,
   primitive::Primitive,
   radio::{Radio, RadioIndicator},
   roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
@@ -36,7 +37,7 @@ pub fn RadioGroupRoot(
   #[prop(optional, into)] value: MaybeProp<String>,
   #[prop(optional, into)] default_value: MaybeProp<String>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
 
   #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
 
@@ -46,6 +47,8 @@ pub fn RadioGroupRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
     value: Signal::derive(move || value.get()),
     default_value: Signal::derive(move || default_value.get()),
@@ -9,6 +9,7 @@ use itertools::Either;
 
 use crate::{
   collection::{use_collection_context, CollectionContextValue},
+  direction::use_local_direction,
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
@@ -88,6 +89,8 @@ pub(crate) fn RovingFocusGroup(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   let collection_ref = NodeRef::<html::AnyElement>::new();
 
   provide_context(CollectionContextValue::<ItemData, _> {
@@ -115,7 +118,7 @@ pub(crate) fn RovingFocusGroup(
 
   provide_context(RovingContextValue {
     orientation: Signal::derive(move || orientation.get()),
-    direction: Signal::derive(move || direction.get()),
+    direction: Signal::derive(move || Some(direction.get())),
     should_loop: Signal::derive(move || should_loop.get()),
     current_tab_stop_id: Signal::derive(move || current_tab_stop_id.get()),
     on_item_focus: Callback::new(move |item| {
@@ -166,6 +169,8 @@ pub(crate) fn RovingFocusGroup(
             return;
           };
 
+          on_entry_focus.call(entry_focus_event.clone().into());
+
           if entry_focus_event.default_prevented() {
             return;
           }
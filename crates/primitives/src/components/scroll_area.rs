@@ -14,6 +14,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{CssStyleDeclaration, DomRect, PointerEvent, WheelEvent};
 
 use crate::{
+  direction::use_local_direction,
   presence::create_presence,
   primitive::Primitive,
   util::{
@@ -71,7 +72,7 @@ pub struct ScrollAreaContextValue {
 #[component]
 pub fn ScrollAreaRoot(
   #[prop(optional, into)] kind: MaybeSignal<ScrollAreaKind>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
   #[prop(default=600.into(), into)] scroll_hide_delay: MaybeSignal<u64>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
@@ -80,6 +81,8 @@ pub fn ScrollAreaRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   let viewport = NodeRef::<AnyElement>::new();
   let content = NodeRef::<Div>::new();
   let scrollbar_x = NodeRef::<AnyElement>::new();
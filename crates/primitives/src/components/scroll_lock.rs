@@ -0,0 +1,130 @@
+use std::cell::RefCell;
+
+use leptos::*;
+
+struct BodyScrollLock {
+  count: usize,
+  overflow: String,
+  padding_right: String,
+}
+
+thread_local! {
+  static BODY_SCROLL_LOCK: RefCell<Option<BodyScrollLock>> = const { RefCell::new(None) };
+}
+
+pub fn use_body_scroll_lock(enabled: impl Into<MaybeSignal<bool>>) {
+  let enabled = enabled.into();
+
+  Effect::new(move |_| {
+    if enabled.get() {
+      lock_body_scroll();
+      on_cleanup(unlock_body_scroll);
+    }
+  });
+}
+
+// locks are reference counted so the body is only restored once every modal has released it
+fn lock_body_scroll() {
+  BODY_SCROLL_LOCK.with_borrow_mut(|lock| {
+    if let Some(lock) = lock {
+      lock.count += 1;
+      return;
+    }
+
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    let style = body.style();
+
+    *lock = Some(BodyScrollLock {
+      count: 1,
+      overflow: style.get_property_value("overflow").unwrap_or_default(),
+      padding_right: style
+        .get_property_value("padding-right")
+        .unwrap_or_default(),
+    });
+
+    let window_width = window()
+      .inner_width()
+      .ok()
+      .and_then(|width| width.as_f64())
+      .unwrap_or_default();
+    let document_element = document().document_element();
+    let document_width = document_element
+      .as_ref()
+      .map(|element| element.client_width() as f64)
+      .unwrap_or(window_width);
+    let scrollbar_gutter = document_element
+      .and_then(|element| window().get_computed_style(&element).ok().flatten())
+      .and_then(|style| style.get_property_value("scrollbar-gutter").ok())
+      .unwrap_or_default();
+    let padding_right = window()
+      .get_computed_style(&body)
+      .ok()
+      .flatten()
+      .and_then(|style| style.get_property_value("padding-right").ok())
+      .and_then(|padding_right| padding_right.trim_end_matches("px").parse::<f64>().ok())
+      .unwrap_or_default();
+
+    let gap = get_scroll_bar_gap(window_width, document_width, &scrollbar_gutter);
+
+    _ = style.set_property("overflow", "hidden");
+    _ = style.set_property("--leptix-removed-body-scroll-bar-size", &format!("{gap}px"));
+
+    if gap > 0.0 {
+      _ = style.set_property("padding-right", &format!("{}px", padding_right + gap));
+    }
+  });
+}
+
+fn unlock_body_scroll() {
+  BODY_SCROLL_LOCK.with_borrow_mut(|lock| {
+    let Some(current_lock) = lock else {
+      return;
+    };
+
+    current_lock.count -= 1;
+
+    if current_lock.count > 0 {
+      return;
+    }
+
+    let Some(BodyScrollLock {
+      overflow,
+      padding_right,
+      ..
+    }) = lock.take()
+    else {
+      return;
+    };
+
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    let style = body.style();
+
+    _ = style.set_property("overflow", &overflow);
+    _ = style.set_property("padding-right", &padding_right);
+    _ = style.remove_property("--leptix-removed-body-scroll-bar-size");
+  });
+}
+
+// a stable scrollbar gutter keeps its space after the scrollbar is removed, so the layout doesn't
+// shift and nothing has to be compensated
+fn get_scroll_bar_gap(window_width: f64, document_width: f64, scrollbar_gutter: &str) -> f64 {
+  if scrollbar_gutter.contains("stable") {
+    return 0.0;
+  }
+
+  (window_width - document_width).max(0.0)
+}
+
+#[test]
+fn scroll_bar_gap_respects_stable_gutters() {
+  assert_eq!(get_scroll_bar_gap(1024.0, 1009.0, "auto"), 15.0);
+  assert_eq!(get_scroll_bar_gap(1024.0, 1024.0, "auto"), 0.0);
+  assert_eq!(get_scroll_bar_gap(1024.0, 1009.0, "stable"), 0.0);
+  assert_eq!(get_scroll_bar_gap(1024.0, 1009.0, "stable both-edges"), 0.0);
+}
@@ -0,0 +1,2020 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{blur, pointermove, pointerup, resize, scroll},
+  html::{AnyElement, Select},
+  leptos_dom::helpers::{IntervalHandle, TimeoutHandle},
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, use_window,
+  UseEventListenerOptions,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  js_sys::{JsString, Reflect},
+  CustomEvent, Event, EventInit, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent,
+};
+
+use crate::{
+  aria_hidden::use_hide_others,
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
+  dismissable_layer::DismissableLayer,
+  focus_guards::use_focus_guards,
+  focus_scope::{focus_first, FocusScope},
+  popper::{PopperAnchor, PopperContent, PopperRoot},
+  portal::Portal,
+  primitive::Primitive,
+  scroll_lock::use_body_scroll_lock,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    get_next_match, Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+  Align, Direction, Side, Sticky,
+};
+
+const OPEN_KEYS: [&str; 4] = [" ", "Enter", "ArrowUp", "ArrowDown"];
+const SELECTION_KEYS: [&str; 2] = [" ", "Enter"];
+
+const CONTENT_MARGIN: f64 = 10.0;
+
+#[derive(Default, Clone, Copy, PartialEq)]
+pub enum SelectPosition {
+  #[default]
+  ItemAligned,
+  Popper,
+}
+
+#[derive(Clone, PartialEq, Debug, Eq)]
+struct SelectItemData {
+  value: String,
+  disabled: Signal<bool>,
+  text_value: Signal<String>,
+}
+
+impl Ord for SelectItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for SelectItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct SelectContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  value_node_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  value: Signal<Option<String>>,
+  selected_text: RwSignal<Option<String>>,
+  native_options: RwSignal<Vec<(String, String)>>,
+  open: Signal<bool>,
+  required: Signal<bool>,
+  disabled: Signal<bool>,
+  direction: Signal<Direction>,
+  trigger_pointer_down_position: StoredValue<Option<(f64, f64)>>,
+  on_value_change: Callback<String>,
+  on_open_change: Callback<bool>,
+}
+
+#[derive(Clone)]
+struct SelectContentContextValue {
+  content_wrapper_ref: NodeRef<AnyElement>,
+  viewport_ref: NodeRef<AnyElement>,
+  position: Signal<SelectPosition>,
+  is_positioned: Signal<bool>,
+  selected_item: Signal<Option<web_sys::HtmlElement>>,
+  search: TypeaheadSearch,
+  should_expand_on_scroll: StoredValue<bool>,
+  on_item_change: Callback<(web_sys::HtmlElement, String, bool)>,
+  on_item_text_change: Callback<(web_sys::HtmlElement, String, bool)>,
+  on_item_leave: Callback<()>,
+  on_scroll_button_change: Callback<()>,
+}
+
+#[derive(Clone)]
+struct SelectItemContextValue {
+  value: Signal<String>,
+  disabled: Signal<bool>,
+  text_id: Signal<String>,
+  is_selected: Signal<bool>,
+  text_content: RwSignal<String>,
+}
+
+#[derive(Clone)]
+struct SelectGroupContextValue {
+  id: Signal<String>,
+}
+
+#[derive(Clone, Copy)]
+struct TypeaheadSearch {
+  search: StoredValue<String>,
+  timer: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl TypeaheadSearch {
+  fn new() -> Self {
+    let timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+    on_cleanup(move || {
+      if let Some(Some(handle)) = timer.try_get_value() {
+        handle.clear();
+      }
+    });
+
+    Self {
+      search: StoredValue::new(String::new()),
+      timer,
+    }
+  }
+
+  fn get(&self) -> String {
+    self.search.get_value()
+  }
+
+  fn push(&self, key: &str) -> String {
+    let next_search = self.search.get_value() + key;
+    self.set(next_search.clone());
+
+    next_search
+  }
+
+  fn set(&self, value: String) {
+    let search = self.search;
+
+    if let Some(handle) = self.timer.get_value() {
+      handle.clear();
+      self.timer.set_value(None);
+    }
+
+    search.set_value(value.clone());
+
+    // resets the search after a second of inactivity
+    if !value.is_empty() {
+      if let Ok(handle) = set_timeout_with_handle(
+        move || search.set_value(String::new()),
+        Duration::from_millis(1000),
+      ) {
+        self.timer.set_value(Some(handle));
+      }
+    }
+  }
+
+  fn reset(&self) {
+    self.set(String::new());
+  }
+}
+
+fn find_next_item(
+  items: Vec<(NodeRef<AnyElement>, SelectItemData)>,
+  search: &str,
+  current_value: Option<&str>,
+) -> Option<(NodeRef<AnyElement>, SelectItemData)> {
+  let items = items
+    .into_iter()
+    .filter(|(_, item)| !item.disabled.get_untracked())
+    .collect::<Vec<_>>();
+
+  let values = items
+    .iter()
+    .map(|(_, item)| item.text_value.get_untracked())
+    .collect::<Vec<_>>();
+
+  let current_match = current_value.and_then(|current_value| {
+    items
+      .iter()
+      .find(|(_, item)| item.value == current_value)
+      .map(|(_, item)| item.text_value.get_untracked())
+  });
+
+  let next_match = get_next_match(&values, search, current_match.as_deref())?;
+
+  items
+    .into_iter()
+    .find(|(_, item)| item.text_value.get_untracked() == next_match)
+}
+
+fn parse_pixels(value: Result<String, JsValue>) -> f64 {
+  value
+    .ok()
+    .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+    .unwrap_or_default()
+}
+
+// distances are measured from the window edge the content grows away from (left in ltr, right in
+// rtl), returning the content's min width and where its edge lands once kept inside the margins
+fn get_item_aligned_edge(
+  edge: f64,
+  trigger_edge: f64,
+  trigger_width: f64,
+  content_width: f64,
+  window_width: f64,
+) -> (f64, f64) {
+  let min_content_width = trigger_width + trigger_edge - edge;
+  let content_width = min_content_width.max(content_width);
+  let far_edge = window_width - CONTENT_MARGIN;
+  let clamped_edge = edge
+    .max(CONTENT_MARGIN)
+    .min(CONTENT_MARGIN.max(far_edge - content_width));
+
+  (min_content_width, clamped_edge)
+}
+
+#[component]
+pub fn SelectRoot(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] auto_complete: MaybeProp<String>,
+  #[prop(optional, into)] form: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let trigger_ref = NodeRef::<AnyElement>::new();
+  let native_options = RwSignal::new(vec![]);
+
+  provide_context(CollectionContextValue::<SelectItemData, AnyElement> {
+    collection_ref: NodeRef::new(),
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  provide_context(SelectContextValue {
+    trigger_ref,
+    value_node_ref: NodeRef::new(),
+    content_id: create_id(),
+    value,
+    selected_text: RwSignal::new(None),
+    native_options,
+    open,
+    required: Signal::derive(move || required.get()),
+    disabled: Signal::derive(move || disabled.get()),
+    direction: Signal::derive(move || direction.get()),
+    trigger_pointer_down_position: StoredValue::new(None),
+    on_value_change: Callback::new(move |value| set_value.set(value)),
+    on_open_change: Callback::new(move |open| set_open.set(open)),
+  });
+
+  let (is_form_control, set_is_form_control) = create_signal(true);
+
+  let name = Signal::derive(move || name.get());
+  let auto_complete = Signal::derive(move || auto_complete.get());
+  let form = Signal::derive(move || form.get());
+
+  Effect::new(move |_| {
+    set_is_form_control.set(if let Some(trigger) = trigger_ref.get() {
+      form.get().is_some() || trigger.closest("form").ok().flatten().is_some()
+    } else {
+      true
+    });
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+
+      <Show when=move || is_form_control.get()>
+        <BubbleSelect
+          value=value
+          options=native_options
+          name=name
+          auto_complete=auto_complete
+          form=form
+          disabled=Signal::derive(move || disabled.get())
+          required=Signal::derive(move || required.get())
+        />
+      </Show>
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn SelectTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    trigger_ref,
+    content_id,
+    value,
+    open,
+    required,
+    disabled: root_disabled,
+    direction,
+    trigger_pointer_down_position,
+    on_value_change,
+    on_open_change,
+    ..
+  } = use_context().expect("SelectTrigger must be used in a SelectRoot component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+
+  let is_disabled = Signal::derive(move || root_disabled.get() || disabled.get());
+  let pointer_type = StoredValue::new(String::from("touch"));
+  let search = TypeaheadSearch::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let handle_open = move |position: Option<(f64, f64)>| {
+    if !is_disabled.get_untracked() {
+      on_open_change.call(true);
+      search.reset();
+    }
+
+    if position.is_some() {
+      trigger_pointer_down_position.set_value(position);
+    }
+  };
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true>
+      <Primitive
+        {..attrs.get_value()}
+        attr:type="button"
+        attr:role="combobox"
+        attr:aria-controls=content_id
+        attr:aria-expanded=open
+        attr:aria-required=required
+        attr:aria-autocomplete="none"
+        attr:dir=move || direction.get().to_string()
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-disabled=move || is_disabled.get().then_some("")
+        attr:data-placeholder=move || value.get().is_none().then_some("")
+        attr:disabled=is_disabled
+        element=html::button
+        on:click=move |ev: MouseEvent| {
+          // touch and pen users open the content once they lift their finger so they can scroll the page
+          if pointer_type.get_value() != "mouse" {
+            handle_open(Some((ev.page_x() as f64, ev.page_y() as f64)));
+          }
+        }
+        on:pointerdown=move |ev: PointerEvent| {
+          pointer_type.set_value(ev.pointer_type());
+
+          // touch devices capture the pointer on the trigger, which would keep the items from
+          // receiving pointer events
+          if let Some(target) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          {
+            if target.has_pointer_capture(ev.pointer_id()) {
+              _ = target.release_pointer_capture(ev.pointer_id());
+            }
+          }
+
+          if ev.button() == 0 && !ev.ctrl_key() && ev.pointer_type() == "mouse" {
+            handle_open(Some((ev.page_x() as f64, ev.page_y() as f64)));
+
+            // keeps the trigger from taking focus away from the content as it opens
+            ev.prevent_default();
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          let is_typing_ahead = !search.get().is_empty();
+          let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+          let key = ev.key();
+
+          // typing while the content is closed selects the matching item directly
+          if !is_modifier_key && key.chars().count() == 1 {
+            let next_search = search.push(&key);
+
+            if let Some((_, item)) = find_next_item(
+              get_items.get_untracked(),
+              &next_search,
+              value.get_untracked().as_deref(),
+            ) {
+              on_value_change.call(item.value);
+            }
+          }
+
+          if is_typing_ahead && key == " " {
+            return;
+          }
+
+          if OPEN_KEYS.contains(&key.as_str()) {
+            handle_open(None);
+
+            // keeps the keydown from scrolling the page
+            ev.prevent_default();
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn SelectValue(
+  #[prop(optional, into)] placeholder: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    value_node_ref,
+    value,
+    selected_text,
+    ..
+  } = use_context().expect("SelectValue must be used in a SelectRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      value_node_ref.load(&node);
+    }
+  });
+
+  let placeholder = Signal::derive(move || placeholder.get());
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:style="pointer-events: none;"
+      element=html::span
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {move || {
+        if value.get().is_none() {
+          return placeholder.get().into_view();
+        }
+
+        // the selected item's text is shown unless the value is rendered manually
+        children.with_value(|children| match children {
+          Some(children) => children().into_view(),
+          None => selected_text.get().into_view(),
+        })
+      }}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectIcon(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      attr:aria-hidden="true"
+      element=html::span
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children
+        .as_ref()
+        .map(|children| children().into_view())
+        .unwrap_or_else(|| "▼".into_view())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+#[component]
+pub fn SelectContent(
+  #[prop(optional, into)] position: MaybeSignal<SelectPosition>,
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(default=CONTENT_MARGIN.into(), into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue { open, .. } =
+    use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show
+      when=move || open.get()
+      fallback=move || view! {
+        <SelectContentFragment>
+          {children.with_value(|children| children())}
+        </SelectContentFragment>
+      }
+    >
+      <SelectContentImpl
+        {..attrs.get_value()}
+        position=position
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </SelectContentImpl>
+    </Show>
+  }
+}
+
+// the items are still rendered while the content is closed, just outside of the document, so the
+// selected item's text and the native options are available before the content is first opened
+#[component]
+fn SelectContentFragment(children: ChildrenFn) -> impl IntoView {
+  let CollectionContextValue { collection_ref, .. } =
+    use_context::<CollectionContextValue<SelectItemData, AnyElement>>()
+      .expect("SelectContentFragment must be used in a SelectRoot component");
+
+  let (fragment, set_fragment) = create_signal::<Option<web_sys::Element>>(None);
+  let fragment_ref = NodeRef::<AnyElement>::new();
+
+  Effect::new(move |_| {
+    set_fragment.set(document().create_element("div").ok());
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = fragment_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  provide_context(SelectContentContextValue {
+    content_wrapper_ref: NodeRef::new(),
+    viewport_ref: NodeRef::new(),
+    position: Signal::derive(SelectPosition::default),
+    is_positioned: Signal::derive(|| false),
+    selected_item: Signal::derive(|| None),
+    search: TypeaheadSearch::new(),
+    should_expand_on_scroll: StoredValue::new(false),
+    on_item_change: Callback::new(|_| {}),
+    on_item_text_change: Callback::new(|_| {}),
+    on_item_leave: Callback::new(|_| {}),
+    on_scroll_button_change: Callback::new(|_| {}),
+  });
+
+  let children = StoredValue::new(children);
+
+  view! {
+    {move || fragment.get().map(|fragment| view! {
+      <Portal container=fragment>
+        <Primitive
+          element=html::div
+          node_ref=fragment_ref
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </Portal>
+    })}
+  }
+}
+
+#[component]
+fn SelectContentImpl(
+  #[prop(into)] position: MaybeSignal<SelectPosition>,
+  #[prop(into)] side: MaybeSignal<Side>,
+  #[prop(into)] side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  #[prop(into)] align_offset: MaybeSignal<f64>,
+  #[prop(into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(into)] collision_padding: MaybeSignal<f64>,
+  #[prop(into)] sticky: MaybeSignal<Sticky>,
+  #[prop(into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(into)] on_close_auto_focus: Callback<Event>,
+  #[prop(into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(into)] on_pointer_down_outside: Callback<CustomEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    trigger_ref,
+    value_node_ref,
+    content_id,
+    value,
+    open,
+    direction,
+    trigger_pointer_down_position,
+    on_open_change,
+    ..
+  } = use_context().expect("SelectContentImpl must be used in a SelectRoot component");
+  let CollectionContextValue { collection_ref, .. } =
+    use_context::<CollectionContextValue<SelectItemData, AnyElement>>()
+      .expect("SelectContentImpl must be used in a SelectRoot component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+
+  use_body_scroll_lock(open);
+  use_hide_others(node_ref, open);
+  use_focus_guards();
+
+  let content_wrapper_ref = NodeRef::<AnyElement>::new();
+  let viewport_ref = NodeRef::<AnyElement>::new();
+  let position = Signal::derive(move || position.get());
+
+  let (selected_item, set_selected_item) = create_signal::<Option<web_sys::HtmlElement>>(None);
+  let (selected_item_text, set_selected_item_text) =
+    create_signal::<Option<web_sys::HtmlElement>>(None);
+  let (is_positioned, set_is_positioned) = create_signal(false);
+
+  let first_valid_item_found = StoredValue::new(false);
+  let should_expand_on_scroll = StoredValue::new(false);
+  let should_reposition = StoredValue::new(true);
+  let search = TypeaheadSearch::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  let focus_selected_item = move || {
+    let candidates = [
+      selected_item.get_untracked(),
+      node_ref
+        .get_untracked()
+        .and_then(|content| content.dyn_ref::<web_sys::HtmlElement>().cloned()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    focus_first(&candidates, false);
+  };
+
+  // lines the selected item's text up with the value shown in the trigger, growing the content
+  // towards whichever edge of the window has more room
+  let position_item_aligned = move || {
+    let (
+      Some(trigger),
+      Some(value_node),
+      Some(content),
+      Some(content_wrapper),
+      Some(viewport),
+      Some(selected_item),
+      Some(selected_item_text),
+    ) = (
+      trigger_ref.get_untracked(),
+      value_node_ref.get_untracked(),
+      node_ref.get_untracked(),
+      content_wrapper_ref.get_untracked(),
+      viewport_ref.get_untracked(),
+      selected_item.get_untracked(),
+      selected_item_text.get_untracked(),
+    )
+    else {
+      return false;
+    };
+
+    let (Some(content_element), Some(viewport_element)) = (
+      content.dyn_ref::<web_sys::HtmlElement>().cloned(),
+      viewport.dyn_ref::<web_sys::HtmlElement>().cloned(),
+    ) else {
+      return false;
+    };
+
+    let window_width = window()
+      .inner_width()
+      .ok()
+      .and_then(|width| width.as_f64())
+      .unwrap_or_default();
+    let window_height = window()
+      .inner_height()
+      .ok()
+      .and_then(|height| height.as_f64())
+      .unwrap_or_default();
+
+    let trigger_rect = trigger.get_bounding_client_rect();
+    let content_rect = content.get_bounding_client_rect();
+    let value_node_rect = value_node.get_bounding_client_rect();
+    let item_text_rect = selected_item_text.get_bounding_client_rect();
+
+    match direction.get_untracked() {
+      Direction::LeftToRight => {
+        let item_text_offset = item_text_rect.left() - content_rect.left();
+        let (min_content_width, clamped_left) = get_item_aligned_edge(
+          value_node_rect.left() - item_text_offset,
+          trigger_rect.left(),
+          trigger_rect.width(),
+          content_rect.width(),
+          window_width,
+        );
+
+        _ = content_wrapper
+          .clone()
+          .style("min-width", format!("{min_content_width}px"))
+          .style("left", format!("{clamped_left}px"));
+      }
+      Direction::RightToLeft => {
+        let item_text_offset = content_rect.right() - item_text_rect.right();
+        let (min_content_width, clamped_right) = get_item_aligned_edge(
+          window_width - value_node_rect.right() - item_text_offset,
+          window_width - trigger_rect.right(),
+          trigger_rect.width(),
+          content_rect.width(),
+          window_width,
+        );
+
+        _ = content_wrapper
+          .clone()
+          .style("min-width", format!("{min_content_width}px"))
+          .style("right", format!("{clamped_right}px"));
+      }
+    }
+
+    let items = get_items
+      .get_untracked()
+      .into_iter()
+      .filter_map(|(node, _)| node.get_untracked())
+      .map(|node| {
+        let element: &web_sys::Element = &node;
+        element.clone()
+      })
+      .collect::<Vec<_>>();
+
+    let available_height = window_height - CONTENT_MARGIN * 2.0;
+    let items_height = viewport_element.scroll_height() as f64;
+
+    let Ok(Some(content_styles)) = window().get_computed_style(&content_element) else {
+      return false;
+    };
+    let Ok(Some(viewport_styles)) = window().get_computed_style(&viewport_element) else {
+      return false;
+    };
+
+    let content_border_top_width =
+      parse_pixels(content_styles.get_property_value("border-top-width"));
+    let content_padding_top = parse_pixels(content_styles.get_property_value("padding-top"));
+    let content_border_bottom_width =
+      parse_pixels(content_styles.get_property_value("border-bottom-width"));
+    let content_padding_bottom = parse_pixels(content_styles.get_property_value("padding-bottom"));
+    let viewport_padding_top = parse_pixels(viewport_styles.get_property_value("padding-top"));
+    let viewport_padding_bottom =
+      parse_pixels(viewport_styles.get_property_value("padding-bottom"));
+
+    let full_content_height = content_border_top_width
+      + content_padding_top
+      + items_height
+      + content_padding_bottom
+      + content_border_bottom_width;
+    let min_content_height = (selected_item.offset_height() as f64 * 5.0).min(full_content_height);
+
+    let top_edge_to_trigger_middle =
+      trigger_rect.top() + trigger_rect.height() / 2.0 - CONTENT_MARGIN;
+    let trigger_middle_to_bottom_edge = available_height - top_edge_to_trigger_middle;
+
+    let selected_item_half_height = selected_item.offset_height() as f64 / 2.0;
+    let item_offset_middle = selected_item.offset_top() as f64 + selected_item_half_height;
+    let content_top_to_item_middle =
+      content_border_top_width + content_padding_top + item_offset_middle;
+    let item_middle_to_content_bottom = full_content_height - content_top_to_item_middle;
+
+    let selected_element: &web_sys::Element = &selected_item;
+
+    if content_top_to_item_middle <= top_edge_to_trigger_middle {
+      let is_last_item = items.last() == Some(selected_element);
+      let viewport_offset_bottom = content_element.client_height() as f64
+        - viewport_element.offset_top() as f64
+        - viewport_element.offset_height() as f64;
+      let clamped_trigger_middle_to_bottom_edge = trigger_middle_to_bottom_edge.max(
+        selected_item_half_height
+          + if is_last_item {
+            viewport_padding_bottom
+          } else {
+            0.0
+          }
+          + viewport_offset_bottom
+          + content_border_bottom_width,
+      );
+
+      let height = content_top_to_item_middle + clamped_trigger_middle_to_bottom_edge;
+
+      _ = content_wrapper
+        .clone()
+        .style("bottom", "0px")
+        .style("height", format!("{height}px"));
+    } else {
+      let is_first_item = items.first() == Some(selected_element);
+      let clamped_top_edge_to_trigger_middle = top_edge_to_trigger_middle.max(
+        content_border_top_width
+          + viewport_element.offset_top() as f64
+          + if is_first_item {
+            viewport_padding_top
+          } else {
+            0.0
+          }
+          + selected_item_half_height,
+      );
+
+      let height = clamped_top_edge_to_trigger_middle + item_middle_to_content_bottom;
+
+      _ = content_wrapper
+        .clone()
+        .style("top", "0px")
+        .style("height", format!("{height}px"));
+
+      viewport_element.set_scroll_top(
+        (content_top_to_item_middle - top_edge_to_trigger_middle
+          + viewport_element.offset_top() as f64) as i32,
+      );
+    }
+
+    _ = content_wrapper
+      .style("margin", format!("{CONTENT_MARGIN}px 0"))
+      .style("min-height", format!("{min_content_height}px"))
+      .style("max-height", format!("{available_height}px"));
+
+    true
+  };
+
+  Effect::new(move |_| {
+    if position.get() != SelectPosition::ItemAligned || is_positioned.get() {
+      return;
+    }
+
+    // waits on every part of the select to be mounted before measuring
+    _ = (
+      content_wrapper_ref.get(),
+      viewport_ref.get(),
+      selected_item.get(),
+      selected_item_text.get(),
+      value_node_ref.get(),
+      get_items.get(),
+    );
+
+    if position_item_aligned() {
+      set_is_positioned.set(true);
+
+      // the content only expands while scrolling once it has been positioned
+      request_animation_frame(move || {
+        _ = should_expand_on_scroll.try_set_value(true);
+      });
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(content) = node_ref.get() else {
+      return;
+    };
+
+    if position.get() == SelectPosition::Popper {
+      _ = content
+        .style("box-sizing", "border-box")
+        .style(
+          "--leptix-select-content-transform-origin",
+          "var(--leptix-popper-transform-origin)",
+        )
+        .style(
+          "--leptix-select-content-available-width",
+          "var(--leptix-popper-available-width)",
+        )
+        .style(
+          "--leptix-select-content-available-height",
+          "var(--leptix-popper-available-height)",
+        )
+        .style(
+          "--leptix-select-trigger-width",
+          "var(--leptix-popper-anchor-width)",
+        )
+        .style(
+          "--leptix-select-trigger-height",
+          "var(--leptix-popper-anchor-height)",
+        );
+    } else {
+      _ = content
+        .clone()
+        .style("box-sizing", "border-box")
+        .style("max-height", "100%");
+
+      // the wrapper is what's positioned, so it has to sit on the same layer as the content
+      if let (Some(content_wrapper), Ok(Some(content_styles))) = (
+        content_wrapper_ref.get(),
+        window().get_computed_style(&content),
+      ) {
+        _ = content_wrapper.style("z-index", content_styles.get_property_value("z-index").ok());
+      }
+    }
+  });
+
+  Effect::new(move |_| {
+    if is_positioned.get() {
+      focus_selected_item();
+    }
+  });
+
+  // the content opens on pointer down, so the pointer up that follows shouldn't select the item
+  // that happens to open underneath it
+  let pointer_move_delta = StoredValue::new((0.0, 0.0));
+
+  _ = use_event_listener(use_document(), pointermove, move |ev: PointerEvent| {
+    let Some((x, y)) = trigger_pointer_down_position.get_value() else {
+      return;
+    };
+
+    pointer_move_delta.set_value((
+      (ev.page_x() as f64 - x).abs(),
+      (ev.page_y() as f64 - y).abs(),
+    ));
+  });
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointerup,
+    move |ev: PointerEvent| {
+      if trigger_pointer_down_position.get_value().is_none() {
+        return;
+      }
+
+      let (delta_x, delta_y) = pointer_move_delta.get_value();
+
+      if delta_x <= 10.0 && delta_y <= 10.0 {
+        ev.prevent_default();
+      } else {
+        let target = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        if !node_ref
+          .get_untracked()
+          .map(|content| content.contains(target.as_ref()))
+          .unwrap_or(false)
+        {
+          on_open_change.call(false);
+        }
+      }
+
+      pointer_move_delta.set_value((0.0, 0.0));
+      trigger_pointer_down_position.set_value(None);
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener(use_window(), blur, move |_| on_open_change.call(false));
+  _ = use_event_listener(use_window(), resize, move |_| on_open_change.call(false));
+
+  provide_context(SelectContentContextValue {
+    content_wrapper_ref,
+    viewport_ref,
+    position,
+    is_positioned: is_positioned.into(),
+    selected_item: selected_item.into(),
+    search,
+    should_expand_on_scroll,
+    on_item_change: Callback::new(
+      move |(node, item_value, disabled): (web_sys::HtmlElement, String, bool)| {
+        let is_first_valid_item = !first_valid_item_found.get_value() && !disabled;
+        let is_selected_item = value.get_untracked().as_deref() == Some(item_value.as_str());
+
+        // the first enabled item stands in for the selected item when nothing is selected
+        if is_selected_item || is_first_valid_item {
+          set_selected_item.set(Some(node));
+
+          if is_first_valid_item {
+            first_valid_item_found.set_value(true);
+          }
+        }
+      },
+    ),
+    on_item_text_change: Callback::new(
+      move |(node, item_value, disabled): (web_sys::HtmlElement, String, bool)| {
+        let is_first_valid_item = selected_item_text.get_untracked().is_none() && !disabled;
+        let is_selected_item = value.get_untracked().as_deref() == Some(item_value.as_str());
+
+        if is_selected_item || is_first_valid_item {
+          set_selected_item_text.set(Some(node));
+        }
+      },
+    ),
+    on_item_leave: Callback::new(move |_| {
+      if let Some(content) = node_ref.get_untracked() {
+        _ = content.focus();
+      }
+    }),
+    on_scroll_button_change: Callback::new(move |_| {
+      // the scroll buttons take up room in the content, so it has to be aligned again once they appear
+      if position.get_untracked() == SelectPosition::ItemAligned
+        && is_positioned.get_untracked()
+        && should_reposition.get_value()
+      {
+        position_item_aligned();
+        focus_selected_item();
+        should_reposition.set_value(false);
+      }
+    }),
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  let content = move || {
+    view! {
+      <FocusScope
+        as_child=true
+        trapped=open
+        on_mount_auto_focus=move |ev: Event| {
+          // focus moves to the selected item once the content has been positioned
+          ev.prevent_default();
+        }
+        on_unmount_auto_focus=move |ev: Event| {
+          on_close_auto_focus.call(ev.clone());
+
+          if !ev.default_prevented() {
+            if let Some(trigger) = trigger_ref.get_untracked() {
+              _ = trigger.focus();
+            }
+
+            ev.prevent_default();
+          }
+        }
+      >
+        <DismissableLayer
+          as_child=true
+          disable_outside_pointer_events=true
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=move |ev: CustomEvent| ev.prevent_default()
+          on_dismiss=move |_| on_open_change.call(false)
+        >
+          <Primitive
+            {..attrs.get_value()}
+            attr:role="listbox"
+            attr:id=content_id
+            attr:data-state=move || if open.get() { "open" } else { "closed" }
+            attr:dir=move || direction.get().to_string()
+            attr:style="display: flex; flex-direction: column; outline: none;"
+            element=html::div
+            on:contextmenu=move |ev: MouseEvent| ev.prevent_default()
+            on:keydown=move |ev: KeyboardEvent| {
+              let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+              let key = ev.key();
+
+              // selects don't participate in the tab order
+              if key == "Tab" {
+                ev.prevent_default();
+              }
+
+              if !is_modifier_key && key.chars().count() == 1 {
+                let next_search = search.push(&key);
+
+                let active_element = document().active_element();
+                let current_value = get_items
+                  .get_untracked()
+                  .into_iter()
+                  .find(|(node, _)| {
+                    node
+                      .get_untracked()
+                      .map(|node| {
+                        let node: &web_sys::Element = &node;
+                        Some(node) == active_element.as_ref()
+                      })
+                      .unwrap_or(false)
+                  })
+                  .map(|(_, item)| item.value);
+
+                if let Some(next_item) = find_next_item(
+                  get_items.get_untracked(),
+                  &next_search,
+                  current_value.as_deref(),
+                )
+                .and_then(|(node, _)| node.get_untracked())
+                {
+                  set_timeout(
+                    move || {
+                      _ = next_item.focus();
+                    },
+                    Duration::ZERO,
+                  );
+                }
+              }
+
+              if !["ArrowUp", "ArrowDown", "Home", "End"].contains(&key.as_str()) {
+                return;
+              }
+
+              let mut candidates = get_items
+                .get_untracked()
+                .into_iter()
+                .filter(|(_, item)| !item.disabled.get_untracked())
+                .filter_map(|(node, _)| node.get_untracked())
+                .filter_map(|node| {
+                  let element: &web_sys::Element = &node;
+                  element.clone().dyn_into::<web_sys::HtmlElement>().ok()
+                })
+                .collect::<Vec<_>>();
+
+              if ["ArrowUp", "End"].contains(&key.as_str()) {
+                candidates.reverse();
+              }
+
+              if ["ArrowUp", "ArrowDown"].contains(&key.as_str()) {
+                let target = ev
+                  .target()
+                  .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok());
+
+                if let Some(current_index) = candidates
+                  .iter()
+                  .position(|candidate| Some(candidate) == target.as_ref())
+                {
+                  candidates = candidates.split_off(current_index + 1);
+                }
+              }
+
+              set_timeout(
+                move || {
+                  focus_first(&candidates, false);
+                },
+                Duration::ZERO,
+              );
+
+              // keeps the arrow keys from scrolling the page
+              ev.prevent_default();
+            }
+            node_ref=node_ref
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+          </Primitive>
+        </DismissableLayer>
+      </FocusScope>
+    }
+  };
+
+  view! {
+    {move || match position.get() {
+      SelectPosition::ItemAligned => view! {
+        <Primitive
+          attr:style="display: flex; flex-direction: column; position: fixed;"
+          element=html::div
+          node_ref=content_wrapper_ref
+        >
+          {content()}
+        </Primitive>
+      }
+      .into_view(),
+      SelectPosition::Popper => view! {
+        <PopperContent
+          as_child=true
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          on_placed=move |_| set_is_positioned.set(true)
+          node_ref=node_ref
+        >
+          {content()}
+        </PopperContent>
+      }
+      .into_view(),
+    }}
+  }
+}
+
+#[component]
+pub fn SelectViewport(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    content_wrapper_ref,
+    viewport_ref,
+    position,
+    should_expand_on_scroll,
+    ..
+  } = use_context().expect("SelectViewport must be used in a SelectContent component");
+
+  let previous_scroll_top = StoredValue::new(0);
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      viewport_ref.load(&node);
+    }
+  });
+
+  view! {
+    // hides the native scrollbar since the scroll buttons take its place
+    <style>
+      "[data-leptix-select-viewport]{scrollbar-width:none;-ms-overflow-style:none;-webkit-overflow-scrolling:touch;}[data-leptix-select-viewport]::-webkit-scrollbar{display:none}"
+    </style>
+    <Primitive
+      {..attrs}
+      attr:data-leptix-select-viewport=""
+      attr:role="presentation"
+      attr:style="position: relative; flex: 1; overflow: hidden auto;"
+      element=html::div
+      on:scroll=move |ev: Event| {
+        let Some(viewport) = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+          return;
+        };
+
+        let scroll_top = viewport.scroll_top();
+
+        // item aligned content grows towards the edge of the window as it's scrolled
+        if let (SelectPosition::ItemAligned, true, Some(content_wrapper)) = (
+          position.get_untracked(),
+          should_expand_on_scroll.get_value(),
+          content_wrapper_ref
+            .get_untracked()
+            .and_then(|wrapper| wrapper.dyn_ref::<web_sys::HtmlElement>().cloned()),
+        ) {
+          let scrolled_by = (previous_scroll_top.get_value() - scroll_top).abs() as f64;
+
+          if scrolled_by > 0.0 {
+            let available_height = window()
+              .inner_height()
+              .ok()
+              .and_then(|height| height.as_f64())
+              .unwrap_or_default()
+              - CONTENT_MARGIN * 2.0;
+
+            let style = content_wrapper.style();
+            let previous_height = parse_pixels(style.get_property_value("min-height"))
+              .max(parse_pixels(style.get_property_value("height")));
+
+            if previous_height < available_height {
+              let next_height = previous_height + scrolled_by;
+              let clamped_next_height = available_height.min(next_height);
+              let height_diff = next_height - clamped_next_height;
+
+              _ = style.set_property("height", &format!("{clamped_next_height}px"));
+
+              if style.get_property_value("bottom").ok().as_deref() == Some("0px") {
+                viewport.set_scroll_top(height_diff.max(0.0) as i32);
+                _ = style.set_property("justify-content", "flex-end");
+              }
+            }
+          }
+        }
+
+        previous_scroll_top.set_value(viewport.scroll_top());
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let id = create_id();
+
+  provide_context(SelectGroupContextValue { id });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      attr:aria-labelledby=id
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let group = use_context::<SelectGroupContextValue>();
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=move || group.as_ref().map(|group| group.id.get())
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    value: selected_value,
+    on_value_change,
+    on_open_change,
+    ..
+  } = use_context().expect("SelectItem must be used in a SelectRoot component");
+  let SelectContentContextValue {
+    search,
+    on_item_change,
+    on_item_leave,
+    ..
+  } = use_context().expect("SelectItem must be used in a SelectContent component");
+
+  let value = Signal::derive(move || value.get());
+  let disabled = Signal::derive(move || disabled.get());
+  let is_selected = Signal::derive(move || selected_value.get() == Some(value.get()));
+  let text_content = RwSignal::new(String::new());
+  let text_id = create_id();
+
+  let (is_focused, set_is_focused) = create_signal(false);
+  let pointer_type = StoredValue::new(String::from("mouse"));
+
+  use_collection_item_ref::<AnyElement, SelectItemData>(
+    node_ref,
+    SelectItemData {
+      value: value.get_untracked(),
+      disabled,
+      text_value: Signal::derive(move || text_value.get().unwrap_or_else(|| text_content.get())),
+    },
+  );
+
+  provide_context(SelectItemContextValue {
+    value,
+    disabled,
+    text_id,
+    is_selected,
+    text_content,
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let element: &web_sys::Element = &node;
+
+    if let Ok(element) = element.clone().dyn_into::<web_sys::HtmlElement>() {
+      on_item_change.call((element, value.get_untracked(), disabled.get_untracked()));
+    }
+  });
+
+  let handle_select = move || {
+    if !disabled.get_untracked() {
+      on_value_change.call(value.get_untracked());
+      on_open_change.call(false);
+    }
+  };
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="option"
+      attr:aria-labelledby=text_id
+      attr:data-highlighted=move || is_focused.get().then_some("")
+      attr:aria-selected=move || is_selected.get() && is_focused.get()
+      attr:data-state=move || if is_selected.get() { "checked" } else { "unchecked" }
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:tabindex=move || (!disabled.get()).then_some(-1)
+      element=html::div
+      on:focus=move |_: FocusEvent| set_is_focused.set(true)
+      on:blur=move |_: FocusEvent| set_is_focused.set(false)
+      on:click=move |_: MouseEvent| {
+        // mouse users select on pointer up instead
+        if pointer_type.get_value() != "mouse" {
+          handle_select();
+        }
+      }
+      on:pointerup=move |ev: PointerEvent| {
+        if pointer_type.get_value() == "mouse" && !ev.default_prevented() {
+          handle_select();
+        }
+      }
+      on:pointerdown=move |ev: PointerEvent| pointer_type.set_value(ev.pointer_type())
+      on:pointermove=move |ev: PointerEvent| {
+        pointer_type.set_value(ev.pointer_type());
+
+        if disabled.get_untracked() {
+          on_item_leave.call(());
+        } else if ev.pointer_type() == "mouse" {
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+        }
+      }
+      on:pointerleave=move |ev: PointerEvent| {
+        let current_target = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+
+        if current_target.is_some() && document().active_element() == current_target {
+          on_item_leave.call(());
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        let key = ev.key();
+
+        // space is part of the search while typing ahead
+        if !search.get().is_empty() && key == " " {
+          return;
+        }
+
+        if SELECTION_KEYS.contains(&key.as_str()) {
+          handle_select();
+        }
+
+        // keeps space from scrolling the page
+        if key == " " {
+          ev.prevent_default();
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectItemText(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    selected_text,
+    native_options,
+    ..
+  } = use_context().expect("SelectItemText must be used in a SelectRoot component");
+  let SelectContentContextValue {
+    on_item_text_change,
+    ..
+  } = use_context().expect("SelectItemText must be used in a SelectContent component");
+  let SelectItemContextValue {
+    value,
+    disabled,
+    text_id,
+    is_selected,
+    text_content,
+  } = use_context().expect("SelectItemText must be used in a SelectItem component");
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let text = node.text_content().unwrap_or_default();
+    let item_value = value.get_untracked();
+
+    text_content.set(text.clone());
+
+    native_options.update(|options| {
+      match options
+        .iter_mut()
+        .find(|(option_value, _)| *option_value == item_value)
+      {
+        Some(option) => option.1 = text.clone(),
+        None => options.push((item_value.clone(), text.clone())),
+      }
+    });
+
+    on_cleanup(move || {
+      _ = native_options.try_update(|options| {
+        options.retain(|(option_value, _)| *option_value != item_value);
+      });
+    });
+
+    let element: &web_sys::Element = &node;
+
+    if let Ok(element) = element.clone().dyn_into::<web_sys::HtmlElement>() {
+      on_item_text_change.call((element, value.get_untracked(), disabled.get_untracked()));
+    }
+  });
+
+  Effect::new(move |_| {
+    if is_selected.get() {
+      selected_text.set(Some(text_content.get()));
+    }
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:id=text_id
+      element=html::span
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectItemIndicator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectItemContextValue { is_selected, .. } =
+    use_context().expect("SelectItemIndicator must be used in a SelectItem component");
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_selected.get()>
+      <Primitive
+        {..attrs.get_value()}
+        attr:aria-hidden="true"
+        element=html::span
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn SelectScrollUpButton(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    viewport_ref,
+    is_positioned,
+    ..
+  } = use_context().expect("SelectScrollUpButton must be used in a SelectContent component");
+
+  let (can_scroll_up, set_can_scroll_up) = create_signal(false);
+
+  let handle_scroll = move || {
+    if let Some(viewport) = viewport_ref.get_untracked() {
+      set_can_scroll_up.set(viewport.scroll_top() > 0);
+    }
+  };
+
+  Effect::new(move |_| {
+    if viewport_ref.get().is_some() && is_positioned.get() {
+      handle_scroll();
+    }
+  });
+
+  _ = use_event_listener(viewport_ref, scroll, move |_| handle_scroll());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || can_scroll_up.get()>
+      <SelectScrollButtonImpl
+        {..attrs.get_value()}
+        on_auto_scroll=Callback::new(move |item_height: i32| {
+          if let Some(viewport) = viewport_ref.get_untracked() {
+            viewport.set_scroll_top(viewport.scroll_top() - item_height);
+          }
+        })
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </SelectScrollButtonImpl>
+    </Show>
+  }
+}
+
+#[component]
+pub fn SelectScrollDownButton(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    viewport_ref,
+    is_positioned,
+    ..
+  } = use_context().expect("SelectScrollDownButton must be used in a SelectContent component");
+
+  let (can_scroll_down, set_can_scroll_down) = create_signal(false);
+
+  let handle_scroll = move || {
+    if let Some(viewport) = viewport_ref.get_untracked() {
+      let max_scroll = viewport.scroll_height() - viewport.client_height();
+
+      // the scroll position can be fractional, so it's rounded up to tell whether it reached the end
+      set_can_scroll_down.set((viewport.scroll_top() as f64).ceil() < max_scroll as f64);
+    }
+  };
+
+  Effect::new(move |_| {
+    if viewport_ref.get().is_some() && is_positioned.get() {
+      handle_scroll();
+    }
+  });
+
+  _ = use_event_listener(viewport_ref, scroll, move |_| handle_scroll());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || can_scroll_down.get()>
+      <SelectScrollButtonImpl
+        {..attrs.get_value()}
+        on_auto_scroll=Callback::new(move |item_height: i32| {
+          if let Some(viewport) = viewport_ref.get_untracked() {
+            viewport.set_scroll_top(viewport.scroll_top() + item_height);
+          }
+        })
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </SelectScrollButtonImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn SelectScrollButtonImpl(
+  on_auto_scroll: Callback<i32>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    selected_item,
+    on_scroll_button_change,
+    on_item_leave,
+    ..
+  } = use_context().expect("SelectScrollButtonImpl must be used in a SelectContent component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+  let auto_scroll_timer = StoredValue::<Option<IntervalHandle>>::new(None);
+
+  let clear_auto_scroll_timer = move || {
+    if let Some(Some(handle)) = auto_scroll_timer.try_get_value() {
+      handle.clear();
+      auto_scroll_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(clear_auto_scroll_timer);
+
+  Effect::new(move |_| {
+    if node_ref.get().is_some() {
+      on_scroll_button_change.call(());
+    }
+  });
+
+  // scrolls by one item at a time, using the selected item's height when items aren't uniform
+  let item_height = move || {
+    selected_item
+      .get_untracked()
+      .or_else(|| {
+        get_items
+          .get_untracked()
+          .into_iter()
+          .find_map(|(node, _)| node.get_untracked())
+          .and_then(|node| node.dyn_ref::<web_sys::HtmlElement>().cloned())
+      })
+      .map(|item| item.offset_height())
+      .unwrap_or_default()
+  };
+
+  let start_auto_scroll = move || {
+    if auto_scroll_timer.get_value().is_some() {
+      return;
+    }
+
+    if let Ok(handle) = set_interval_with_handle(
+      move || on_auto_scroll.call(item_height()),
+      Duration::from_millis(50),
+    ) {
+      auto_scroll_timer.set_value(Some(handle));
+    }
+  };
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:aria-hidden="true"
+      attr:style="flex-shrink: 0;"
+      element=html::div
+      on:pointerdown=move |_: PointerEvent| start_auto_scroll()
+      on:pointermove=move |_: PointerEvent| {
+        on_item_leave.call(());
+        start_auto_scroll();
+      }
+      on:pointerleave=move |_: PointerEvent| clear_auto_scroll_timer()
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      attr:aria-hidden="true"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+#[component]
+fn BubbleSelect(
+  value: Signal<Option<String>>,
+  options: RwSignal<Vec<(String, String)>>,
+  name: Signal<Option<String>>,
+  auto_complete: Signal<Option<String>>,
+  form: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
+) -> impl IntoView {
+  let node_ref = NodeRef::<Select>::new();
+
+  // forms listen for changes on their controls, so the native select has to announce them
+  Effect::new(move |previous_value: Option<Option<String>>| {
+    let value = value.get();
+
+    if previous_value.is_some_and(|previous_value| previous_value != value) {
+      if let Some(select) = node_ref.get_untracked() {
+        _ = Reflect::set(
+          &select,
+          &JsString::from("value"),
+          &JsValue::from_str(value.as_deref().unwrap_or_default()),
+        );
+
+        let init = EventInit::new();
+        init.set_bubbles(true);
+
+        if let Ok(ev) = Event::new_with_event_init_dict("change", &init) {
+          _ = select.dispatch_event(&ev);
+        }
+      }
+    }
+
+    value
+  });
+
+  view! {
+    <VisuallyHidden as_child=true>
+      <select
+        aria-hidden="true"
+        tabindex=(-1).into_attribute()
+        name=name
+        autocomplete=auto_complete
+        form=form
+        disabled=disabled
+        required=required
+        node_ref=node_ref
+      >
+        <Show when=move || value.get().is_none()>
+          <option value=""></option>
+        </Show>
+
+        // options keep their selectedness through the `selected` attribute since they're recreated
+        // whenever the items are remounted
+        {move || {
+          options
+            .get()
+            .into_iter()
+            .map(|(option_value, text)| {
+              let selected = value.get().as_deref() == Some(option_value.as_str());
+
+              view! {
+                <option value=option_value selected=selected>{text}</option>
+              }
+            })
+            .collect_view()
+        }}
+      </select>
+    </VisuallyHidden>
+  }
+}
+
+#[test]
+fn typeahead_skips_disabled_items_and_cycles_from_the_selection() {
+  let runtime = create_runtime();
+
+  let item = |value: &str, text: &str, disabled: bool| {
+    let text = text.to_string();
+    (
+      NodeRef::<AnyElement>::new(),
+      SelectItemData {
+        value: value.to_string(),
+        disabled: Signal::derive(move || disabled),
+        text_value: Signal::derive(move || text.clone()),
+      },
+    )
+  };
+  let items = vec![
+    item("apple", "Apple", false),
+    item("apricot", "Apricot", true),
+    item("avocado", "Avocado", false),
+    item("banana", "Banana", false),
+  ];
+  let next_value = |search: &str, current_value: Option<&str>| {
+    find_next_item(items.clone(), search, current_value).map(|(_, item)| item.value)
+  };
+
+  assert_eq!(next_value("a", None), Some(String::from("apple")));
+  assert_eq!(
+    next_value("a", Some("apple")),
+    Some(String::from("avocado"))
+  );
+  assert_eq!(
+    next_value("aa", Some("avocado")),
+    Some(String::from("apple"))
+  );
+  assert_eq!(
+    next_value("ban", Some("apple")),
+    Some(String::from("banana"))
+  );
+  assert_eq!(next_value("apr", None), None);
+  assert_eq!(next_value("c", None), None);
+
+  runtime.dispose();
+}
+
+#[test]
+fn parses_computed_pixel_values() {
+  assert_eq!(parse_pixels(Ok(String::from("12.5px"))), 12.5);
+  assert_eq!(parse_pixels(Ok(String::from("0"))), 0.0);
+  assert_eq!(parse_pixels(Ok(String::from("auto"))), 0.0);
+}
+
+#[test]
+fn item_aligned_content_lines_up_with_the_trigger_value() {
+  // the item text sits 20px into the content and the value 12px into the trigger
+  assert_eq!(
+    get_item_aligned_edge(92.0, 100.0, 200.0, 150.0, 1000.0),
+    (208.0, 92.0)
+  );
+  // content narrower than the trigger is kept inside the window as if it were as wide as the
+  // trigger, content wider than that is pushed back by its own width
+  assert_eq!(
+    get_item_aligned_edge(700.0, 708.0, 280.0, 150.0, 1000.0),
+    (288.0, 700.0)
+  );
+  assert_eq!(
+    get_item_aligned_edge(700.0, 708.0, 280.0, 400.0, 1000.0),
+    (288.0, 590.0)
+  );
+}
+
+#[test]
+fn item_aligned_content_stays_inside_the_window_margins() {
+  assert_eq!(
+    get_item_aligned_edge(2.0, 10.0, 100.0, 100.0, 1000.0).1,
+    CONTENT_MARGIN
+  );
+  assert_eq!(
+    get_item_aligned_edge(900.0, 908.0, 80.0, 200.0, 1000.0).1,
+    790.0
+  );
+  // a window too small for the content pins it to the near margin
+  assert_eq!(
+    get_item_aligned_edge(50.0, 58.0, 80.0, 400.0, 300.0).1,
+    CONTENT_MARGIN
+  );
+}
@@ -16,11 +16,12 @@ use web_sys::{
 
 use crate::{
   collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  direction::use_local_direction,
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
     create_previous::create_previous,
-    linear_scale, Attributes,
+    linear_scale, snap_value_to_step, Attributes,
   },
   Direction, Orientation,
 };
@@ -44,7 +45,7 @@ pub fn SliderRoot(
   #[prop(default=100.0f64.into(), into)] max: MaybeSignal<f64>,
   #[prop(default=1.0f64.into(), into)] step: MaybeSignal<f64>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(default=0.0f64.into(), into)] min_steps_between_thumbs: MaybeSignal<f64>,
   #[prop(optional, into)] value: MaybeProp<Vec<f64>>,
@@ -60,6 +61,8 @@ pub fn SliderRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   let thumbs = StoredValue::new(Vec::<HtmlElement<AnyElement>>::new());
   let value_index_to_change = StoredValue::new(Some(0usize));
 
@@ -84,12 +87,7 @@ pub fn SliderRoot(
   let values_before_slide_start = StoredValue::new(values.get_untracked());
 
   let update_values = move |value: f64, at_index: usize, commit: bool| {
-    let decimal_count = get_decimal_count(step.get());
-    let snap_to_step = round_value(
-      ((value - min.get()) / step.get()).round() * step.get() + min.get(),
-      decimal_count as u32,
-    );
-    let next_value = snap_to_step.clamp(min.get(), max.get());
+    let next_value = snap_value_to_step(value, min.get(), max.get(), step.get());
 
     set_values.update(move |values| {
       let previous_values = values.as_ref().cloned().unwrap_or_default();
@@ -1034,15 +1032,6 @@ fn has_min_steps_between_values(values: &[f64], min_steps_between_values: f64) -
     .unwrap_or(false)
 }
 
-fn get_decimal_count(value: f64) -> usize {
-  value.to_string().split('.').nth(1).unwrap_or("").len()
-}
-
-fn round_value(value: f64, decimal_count: u32) -> f64 {
-  let rounder = 10_f64.powi(decimal_count as i32);
-  (value * rounder).round() / rounder
-}
-
 fn get_thumb_in_bounds_offset(width: f64, left: f64, direction: f64) -> f64 {
   let half_width = width / 2.0;
   let half_percent = 50.0;
@@ -16,6 +16,7 @@ use crate::{
     create_previous::create_previous,
     Attributes,
   },
+  visually_hidden::VisuallyHidden,
 };
 
 #[derive(Clone)]
@@ -202,24 +203,33 @@ pub fn BubbleInput(
     })();
   });
 
+  let attrs = StoredValue::new(attrs);
+  let name = Signal::derive(move || name.get());
+  let value = Signal::derive(move || value.get().unwrap_or("on".into()));
+
   view! {
-    <input
-      type="checkbox"
-      aria-hidden
-      checked=Signal::derive(move || checked.get()).into_attribute()
-      tabindex=(-1).into_attribute()
-      node_ref=node_ref
-      name=name.into_attribute()
-      value=Signal::derive(move || value.get().unwrap_or("on".into())).into_attribute()
-      disabled=Signal::derive(move || disabled.get()).into_attribute()
-      required=Signal::derive(move || required.get()).into_attribute()
-      style:position="absolute"
-      style:pointer-events="none"
-      style:opacity="0"
-      style:margin="0"
-      style:width=move || width.get()
-      style:height=move || height.get()
-      {..attrs}
-    />
+    <VisuallyHidden
+      as_child=true
+      attr:style=move || {
+        format!(
+          "pointer-events: none; opacity: 0; margin: 0; width: {}px; height: {}px;",
+          width.get(),
+          height.get()
+        )
+      }
+    >
+      <input
+        {..attrs.get_value()}
+        type="checkbox"
+        aria-hidden
+        checked=Signal::derive(move || checked.get()).into_attribute()
+        tabindex=(-1).into_attribute()
+        node_ref=node_ref
+        name=name
+        value=value
+        disabled=Signal::derive(move || disabled.get()).into_attribute()
+        required=Signal::derive(move || required.get()).into_attribute()
+      />
+    </VisuallyHidden>
   }
 }
@@ -2,6 +2,7 @@ use leptos::{html::AnyElement, leptos_dom::helpers::AnimationFrameRequestHandle,
 use web_sys::{FocusEvent, KeyboardEvent, MouseEvent};
 
 use crate::{
+  direction::use_local_direction,
   presence::create_presence,
   primitive::Primitive,
   roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
@@ -35,7 +36,7 @@ pub fn TabsRoot(
   #[prop(optional, into)] value: MaybeProp<String>,
   #[prop(optional, into)] default_value: MaybeProp<String>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
   #[prop(optional, into)] activation_mode: MaybeSignal<ActivationMode>,
 
   #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
@@ -46,6 +47,8 @@ pub fn TabsRoot(
 
   #[prop(optional)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
     value: Signal::derive(move || value.get()),
     default_value: Signal::derive(move || default_value.get()),
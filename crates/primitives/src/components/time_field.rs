@@ -0,0 +1,449 @@
+use std::fmt;
+
+use leptos::{html::AnyElement, *};
+
+use crate::{
+  calendar::create_date_formatter,
+  date_field::{
+    create_field_segments, get_localized_parts, render_segments, BubbleInput, FieldPart,
+    SegmentValues,
+  },
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TimeValue {
+  pub hour: u32,
+  pub minute: u32,
+}
+
+impl TimeValue {
+  pub fn new(hour: u32, minute: u32) -> Option<Self> {
+    (hour < 24 && minute < 60).then_some(Self { hour, minute })
+  }
+
+  // seconds and fractions are accepted but dropped since the field doesn't edit them
+  pub fn from_iso(value: &str) -> Option<Self> {
+    let mut parts = value.trim().splitn(3, ':');
+
+    let hour = parts.next()?;
+    let minute = parts.next()?;
+
+    if hour.len() != 2 || minute.len() != 2 {
+      return None;
+    }
+
+    Self::new(hour.parse().ok()?, minute.parse().ok()?)
+  }
+}
+
+impl fmt::Display for TimeValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{:02}:{:02}", self.hour, self.minute)
+  }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum HourCycle {
+  #[default]
+  H12,
+  H24,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, strum_macros::Display)]
+pub enum TimeSegment {
+  #[strum(to_string = "hour")]
+  Hour,
+  #[strum(to_string = "minute")]
+  Minute,
+  #[strum(to_string = "dayPeriod")]
+  DayPeriod,
+}
+
+// hours are kept on a 24 hour clock, a 12 hour cycle only changes how the segment reads
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+struct TimeSegments {
+  hour: Option<u32>,
+  minute: Option<u32>,
+  day_period: Option<u32>,
+  hour_cycle: HourCycle,
+}
+
+impl TimeSegments {
+  fn from_time(time: Option<TimeValue>, hour_cycle: HourCycle) -> Self {
+    time.map_or(
+      Self {
+        hour_cycle,
+        ..Default::default()
+      },
+      |time| Self {
+        hour: Some(time.hour),
+        minute: Some(time.minute),
+        day_period: Some(time.hour / 12),
+        hour_cycle,
+      },
+    )
+  }
+
+  fn to_time(self) -> Option<TimeValue> {
+    if self.hour_cycle == HourCycle::H12 && self.day_period.is_none() {
+      return None;
+    }
+
+    TimeValue::new(self.hour?, self.minute?)
+  }
+}
+
+impl SegmentValues for TimeSegments {
+  type Segment = TimeSegment;
+
+  fn get(&self, segment: TimeSegment) -> Option<u32> {
+    match (segment, self.hour_cycle) {
+      (TimeSegment::Hour, HourCycle::H12) => self.hour.map(|hour| match hour % 12 {
+        0 => 12,
+        hour => hour,
+      }),
+      (TimeSegment::Hour, HourCycle::H24) => self.hour,
+      (TimeSegment::Minute, _) => self.minute,
+      (TimeSegment::DayPeriod, _) => self.day_period,
+    }
+  }
+
+  fn set(&mut self, segment: TimeSegment, value: Option<u32>) {
+    match (segment, self.hour_cycle) {
+      (TimeSegment::Hour, HourCycle::H12) => {
+        self.hour = value.map(|hour| hour % 12 + 12 * self.day_period.unwrap_or(0));
+      }
+      (TimeSegment::Hour, HourCycle::H24) => {
+        self.hour = value;
+        self.day_period = value.map(|hour| hour / 12);
+      }
+      (TimeSegment::Minute, _) => self.minute = value,
+      (TimeSegment::DayPeriod, _) => {
+        self.day_period = value;
+
+        if let (Some(hour), Some(day_period)) = (self.hour, value) {
+          self.hour = Some(hour % 12 + 12 * day_period);
+        }
+      }
+    }
+  }
+
+  fn range(&self, segment: TimeSegment) -> (u32, u32) {
+    match (segment, self.hour_cycle) {
+      (TimeSegment::Hour, HourCycle::H12) => (1, 12),
+      (TimeSegment::Hour, HourCycle::H24) => (0, 23),
+      (TimeSegment::Minute, _) => (0, 59),
+      (TimeSegment::DayPeriod, _) => (0, 1),
+    }
+  }
+
+  fn format(segment: TimeSegment, value: Option<u32>) -> String {
+    match (segment, value) {
+      (TimeSegment::DayPeriod, Some(0)) => "AM".into(),
+      (TimeSegment::DayPeriod, Some(_)) => "PM".into(),
+      (_, Some(value)) => format!("{value:02}"),
+      (_, None) => "--".into(),
+    }
+  }
+
+  fn label(segment: TimeSegment) -> &'static str {
+    match segment {
+      TimeSegment::Hour => "hour",
+      TimeSegment::Minute => "minute",
+      TimeSegment::DayPeriod => "AM/PM",
+    }
+  }
+
+  fn large_step(segment: TimeSegment) -> i32 {
+    match segment {
+      TimeSegment::Hour => 2,
+      TimeSegment::Minute => 15,
+      TimeSegment::DayPeriod => 1,
+    }
+  }
+
+  fn from_part_type(kind: &str) -> Option<TimeSegment> {
+    match kind {
+      "hour" => Some(TimeSegment::Hour),
+      "minute" => Some(TimeSegment::Minute),
+      "dayPeriod" => Some(TimeSegment::DayPeriod),
+      _ => None,
+    }
+  }
+
+  fn is_numeric(segment: TimeSegment) -> bool {
+    segment != TimeSegment::DayPeriod
+  }
+
+  fn parse_key(segment: TimeSegment, key: &str) -> Option<u32> {
+    match (segment, key) {
+      (TimeSegment::DayPeriod, "a" | "A") => Some(0),
+      (TimeSegment::DayPeriod, "p" | "P") => Some(1),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Clone)]
+struct TimeFieldContextValue {
+  segments: RwSignal<TimeSegments>,
+  typing_segment: RwSignal<Option<TimeSegment>>,
+  placeholder: Signal<TimeSegments>,
+  hour_cycle: Signal<HourCycle>,
+  locale: Signal<Option<String>>,
+  is_localized: Signal<bool>,
+  is_invalid: Signal<bool>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+}
+
+#[component]
+pub fn TimeFieldRoot(
+  #[prop(optional, into)] value: MaybeProp<TimeValue>,
+  #[prop(optional, into)] default_value: MaybeProp<TimeValue>,
+  #[prop(optional, into)] placeholder: MaybeProp<TimeValue>,
+  #[prop(optional, into)] min: MaybeProp<TimeValue>,
+  #[prop(optional, into)] max: MaybeProp<TimeValue>,
+  #[prop(optional, into)] hour_cycle: MaybeProp<HourCycle>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] form: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<TimeValue>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (is_localized, set_is_localized) = create_signal(false);
+
+  Effect::new(move |_| set_is_localized.set(true));
+
+  let locale = Signal::derive(move || locale.get());
+
+  let hour_cycle = Signal::derive(move || {
+    hour_cycle
+      .get()
+      .or_else(|| {
+        is_localized
+          .get()
+          .then(|| get_locale_hour_cycle(locale.get().as_deref()))
+          .flatten()
+      })
+      .unwrap_or_default()
+  });
+
+  let (segments, typing_segment) = create_field_segments(
+    value,
+    set_value,
+    TimeSegments::from_time(value.get_untracked(), hour_cycle.get_untracked()),
+    |segments, time| TimeSegments::from_time(Some(time), segments.hour_cycle),
+    TimeSegments::to_time,
+  );
+
+  Effect::new(move |_| {
+    let hour_cycle = hour_cycle.get();
+
+    if segments.get_untracked().hour_cycle != hour_cycle {
+      segments.update(|segments| segments.hour_cycle = hour_cycle);
+    }
+  });
+
+  let is_invalid = Signal::derive(move || {
+    value.get().is_some_and(|time| {
+      min.get().is_some_and(|min| time < min) || max.get().is_some_and(|max| time > max)
+    })
+  });
+
+  let disabled = Signal::derive(move || disabled.get());
+
+  provide_context(TimeFieldContextValue {
+    segments,
+    typing_segment,
+    placeholder: Signal::derive(move || {
+      TimeSegments::from_time(
+        Some(
+          placeholder
+            .get()
+            .unwrap_or(TimeValue { hour: 0, minute: 0 }),
+        ),
+        hour_cycle.get(),
+      )
+    }),
+    hour_cycle,
+    locale,
+    is_localized: is_localized.into(),
+    is_invalid,
+    disabled,
+    read_only: Signal::derive(move || read_only.get()),
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:role="group"
+      attr:aria-invalid=move || is_invalid.get().then_some("true")
+      attr:aria-disabled=move || disabled.get().then_some("true")
+      attr:data-invalid=move || is_invalid.get().then_some("")
+      attr:data-disabled=move || disabled.get().then_some("")
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+
+    <BubbleInput
+      value=Signal::derive(move || value.get().map(|time| time.to_string()))
+      field_ref=node_ref
+      name=Signal::derive(move || name.get())
+      form=Signal::derive(move || form.get())
+      disabled=disabled
+      required=Signal::derive(move || required.get())
+    />
+  }
+}
+
+#[component]
+pub fn TimeFieldInput(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TimeFieldContextValue {
+    segments,
+    typing_segment,
+    placeholder,
+    hour_cycle,
+    locale,
+    is_localized,
+    is_invalid,
+    disabled,
+    read_only,
+  } = use_context().expect("TimeFieldInput must be used in a TimeFieldRoot component");
+
+  let parts = Signal::derive(move || {
+    let hour_cycle = hour_cycle.get();
+
+    let localized_parts = is_localized
+      .get()
+      .then(|| {
+        let formatter = create_date_formatter(
+          locale.get().as_deref(),
+          &[
+            ("hour", "2-digit"),
+            ("minute", "2-digit"),
+            (
+              "hourCycle",
+              match hour_cycle {
+                HourCycle::H12 => "h12",
+                HourCycle::H24 => "h23",
+              },
+            ),
+          ],
+        )?;
+
+        get_localized_parts::<TimeSegments>(&formatter)
+      })
+      .flatten();
+
+    localized_parts.unwrap_or_else(|| {
+      let mut parts = vec![
+        FieldPart::Segment(TimeSegment::Hour),
+        FieldPart::Literal(":".into()),
+        FieldPart::Segment(TimeSegment::Minute),
+      ];
+
+      if hour_cycle == HourCycle::H12 {
+        parts.push(FieldPart::Literal(" ".into()));
+        parts.push(FieldPart::Segment(TimeSegment::DayPeriod));
+      }
+
+      parts
+    })
+  });
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:data-invalid=move || is_invalid.get().then_some("")
+      attr:data-disabled=move || disabled.get().then_some("")
+      attr:style="white-space: nowrap;"
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {render_segments(segments, typing_segment, placeholder, parts, disabled, read_only, node_ref)}
+    </Primitive>
+  }
+}
+
+// locales that format hours with a day period use a 12 hour clock
+fn get_locale_hour_cycle(locale: Option<&str>) -> Option<HourCycle> {
+  let formatter = create_date_formatter(locale, &[("hour", "numeric")])?;
+  let parts = get_localized_parts::<TimeSegments>(&formatter)?;
+
+  Some(
+    if parts.contains(&FieldPart::Segment(TimeSegment::DayPeriod)) {
+      HourCycle::H12
+    } else {
+      HourCycle::H24
+    },
+  )
+}
+
+#[test]
+fn parses_and_formats_iso_times() {
+  assert_eq!(TimeValue::from_iso("09:05"), TimeValue::new(9, 5));
+  assert_eq!(TimeValue::from_iso("23:59:30.5"), TimeValue::new(23, 59));
+  assert_eq!(TimeValue::from_iso("24:00"), None);
+  assert_eq!(TimeValue::from_iso("9:05"), None);
+  assert_eq!(TimeValue::new(7, 3).unwrap().to_string(), "07:03");
+}
+
+#[test]
+fn maps_hours_to_the_hour_cycle() {
+  let mut segments = TimeSegments::from_time(TimeValue::new(0, 30), HourCycle::H12);
+
+  assert_eq!(segments.get(TimeSegment::Hour), Some(12));
+  assert_eq!(segments.get(TimeSegment::DayPeriod), Some(0));
+
+  segments.set(TimeSegment::DayPeriod, Some(1));
+  assert_eq!(segments.to_time(), TimeValue::new(12, 30));
+
+  segments.set(TimeSegment::Hour, Some(3));
+  assert_eq!(segments.to_time(), TimeValue::new(15, 30));
+
+  segments.hour_cycle = HourCycle::H24;
+  assert_eq!(segments.get(TimeSegment::Hour), Some(15));
+  assert_eq!(segments.range(TimeSegment::Hour), (0, 23));
+}
+
+#[test]
+fn waits_for_the_day_period_on_a_12_hour_clock() {
+  let mut segments = TimeSegments::from_time(None, HourCycle::H12);
+
+  segments.set(TimeSegment::Hour, Some(8));
+  segments.set(TimeSegment::Minute, Some(15));
+  assert_eq!(segments.to_time(), None);
+
+  segments.set(TimeSegment::DayPeriod, Some(1));
+  assert_eq!(segments.to_time(), TimeValue::new(20, 15));
+}
@@ -0,0 +1,1193 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{blur, focus, keydown},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{use_document, use_event_listener, use_window};
+use wasm_bindgen::JsCast;
+use web_sys::{js_sys::Date, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dismissable_layer::DismissableLayer,
+  focus_scope::{focus_first, get_tabbable_candidates},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+};
+
+#[derive(Default, Clone, PartialEq, Copy, strum_macros::Display)]
+pub enum ToastSwipeDirection {
+  #[strum(to_string = "up")]
+  Up,
+  #[strum(to_string = "down")]
+  Down,
+  #[strum(to_string = "left")]
+  Left,
+  #[default]
+  #[strum(to_string = "right")]
+  Right,
+}
+
+#[derive(Default, Clone, PartialEq, Copy)]
+pub enum ToastType {
+  #[default]
+  Foreground,
+  Background,
+}
+
+#[derive(Clone)]
+pub struct ToastSwipeEvent {
+  pub original_event: PointerEvent,
+  pub delta: (f64, f64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ToastId(usize);
+
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord)]
+struct ToastItemData;
+
+// toasts pushed through `use_toast` wait here until there's room for them in the viewport
+struct ToastQueue<T> {
+  next_id: usize,
+  entries: Vec<ToastQueueEntry<T>>,
+}
+
+struct ToastQueueEntry<T> {
+  id: ToastId,
+  content: T,
+  is_dismissed: bool,
+}
+
+impl<T> Default for ToastQueue<T> {
+  fn default() -> Self {
+    Self {
+      next_id: 0,
+      entries: vec![],
+    }
+  }
+}
+
+impl<T: Clone> ToastQueue<T> {
+  fn push(&mut self, content: T) -> ToastId {
+    let id = ToastId(self.next_id);
+    self.next_id += 1;
+
+    self.entries.push(ToastQueueEntry {
+      id,
+      content,
+      is_dismissed: false,
+    });
+
+    id
+  }
+
+  // visible toasts keep their place until they've closed, queued ones can simply be dropped
+  fn dismiss(&mut self, id: ToastId, max_visible: usize) {
+    let Some(index) = self.entries.iter().position(|entry| entry.id == id) else {
+      return;
+    };
+
+    if index < max_visible {
+      self.entries[index].is_dismissed = true;
+    } else {
+      self.entries.remove(index);
+    }
+  }
+
+  fn remove(&mut self, id: ToastId) {
+    self.entries.retain(|entry| entry.id != id);
+  }
+
+  fn is_dismissed(&self, id: ToastId) -> bool {
+    self
+      .entries
+      .iter()
+      .any(|entry| entry.id == id && entry.is_dismissed)
+  }
+
+  fn visible(&self, max_visible: usize) -> Vec<(ToastId, T)> {
+    self
+      .entries
+      .iter()
+      .take(max_visible)
+      .map(|entry| (entry.id, entry.content.clone()))
+      .collect()
+  }
+}
+
+#[derive(Clone)]
+struct ToastProviderContextValue {
+  label: Signal<String>,
+  duration: Signal<u64>,
+  swipe_direction: Signal<ToastSwipeDirection>,
+  swipe_threshold: Signal<f64>,
+  toast_count: RwSignal<usize>,
+  viewport_ref: NodeRef<AnyElement>,
+  is_close_paused: RwSignal<bool>,
+  is_focused_toast_escape_key_down: StoredValue<bool>,
+  queue: RwSignal<ToastQueue<ViewFn>>,
+  max_visible: Signal<usize>,
+}
+
+#[derive(Clone)]
+struct ToastQueueItemContextValue {
+  id: ToastId,
+}
+
+#[derive(Clone)]
+struct ToastContextValue {
+  on_close: Callback<()>,
+}
+
+fn is_delta_in_direction(
+  (x, y): (f64, f64),
+  direction: ToastSwipeDirection,
+  threshold: f64,
+) -> bool {
+  let delta_x = x.abs();
+  let delta_y = y.abs();
+
+  let is_delta_x = delta_x > delta_y;
+
+  match direction {
+    ToastSwipeDirection::Left | ToastSwipeDirection::Right => is_delta_x && delta_x > threshold,
+    ToastSwipeDirection::Up | ToastSwipeDirection::Down => !is_delta_x && delta_y > threshold,
+  }
+}
+
+// announcements read the toast's text, substituting the alternative text of anything that can't be
+// read out on its own, like actions
+fn get_announce_text_content(container: &web_sys::Node) -> Vec<String> {
+  let mut text_content = vec![];
+  let mut child = container.first_child();
+
+  while let Some(node) = child {
+    if node.node_type() == web_sys::Node::TEXT_NODE {
+      if let Some(text) = node.text_content().filter(|text| !text.trim().is_empty()) {
+        text_content.push(text);
+      }
+    } else if let Some(element) = node.dyn_ref::<web_sys::HtmlElement>() {
+      let is_hidden = element.hidden()
+        || element
+          .style()
+          .get_property_value("display")
+          .ok()
+          .as_deref()
+          == Some("none");
+      let is_excluded = element
+        .get_attribute("data-leptix-toast-announce-exclude")
+        .is_some();
+
+      if !is_hidden {
+        if is_excluded {
+          if let Some(alt_text) = element
+            .get_attribute("data-leptix-toast-announce-alt")
+            .filter(|alt_text| !alt_text.is_empty())
+          {
+            text_content.push(alt_text);
+          }
+        } else {
+          text_content.extend(get_announce_text_content(&node));
+        }
+      }
+    }
+
+    child = node.next_sibling();
+  }
+
+  text_content
+}
+
+#[derive(Clone, Copy)]
+pub struct ToastHandle {
+  queue: RwSignal<ToastQueue<ViewFn>>,
+  max_visible: Signal<usize>,
+}
+
+impl ToastHandle {
+  pub fn push<IV: IntoView>(&self, content: impl Fn() -> IV + 'static) -> ToastId {
+    self
+      .queue
+      .try_update(|queue| queue.push(ViewFn::from(content)))
+      .expect("the ToastProvider was disposed")
+  }
+
+  pub fn dismiss(&self, id: ToastId) {
+    let max_visible = self.max_visible.get_untracked();
+
+    self.queue.update(|queue| queue.dismiss(id, max_visible));
+  }
+}
+
+pub fn use_toast() -> ToastHandle {
+  let ToastProviderContextValue {
+    queue, max_visible, ..
+  } = use_context().expect("use_toast must be used in a ToastProvider component");
+
+  ToastHandle { queue, max_visible }
+}
+
+fn get_hotkey_label(hotkey: &[String]) -> String {
+  hotkey
+    .iter()
+    .map(|key| key.replace("Key", "").replace("Digit", ""))
+    .collect::<Vec<_>>()
+    .join("+")
+}
+
+#[component]
+pub fn ToastProvider(
+  #[prop(default="Notification".into(), into)] label: MaybeSignal<String>,
+  #[prop(default=5000.into(), into)] duration: MaybeSignal<u64>,
+  #[prop(optional, into)] swipe_direction: MaybeSignal<ToastSwipeDirection>,
+  #[prop(default=50.0.into(), into)] swipe_threshold: MaybeSignal<f64>,
+  #[prop(optional, into)] max_visible: MaybeProp<usize>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let viewport_ref = NodeRef::<AnyElement>::new();
+
+  provide_context(CollectionContextValue::<ToastItemData, AnyElement> {
+    collection_ref: viewport_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  provide_context(ToastProviderContextValue {
+    label: Signal::derive(move || label.get()),
+    duration: Signal::derive(move || duration.get()),
+    swipe_direction: Signal::derive(move || swipe_direction.get()),
+    swipe_threshold: Signal::derive(move || swipe_threshold.get()),
+    toast_count: RwSignal::new(0),
+    viewport_ref,
+    is_close_paused: RwSignal::new(false),
+    is_focused_toast_escape_key_down: StoredValue::new(false),
+    queue: RwSignal::new(ToastQueue::default()),
+    max_visible: Signal::derive(move || max_visible.get().unwrap_or(usize::MAX)),
+  });
+
+  children()
+}
+
+#[component]
+pub fn ToastViewport(
+  #[prop(default=vec!["F8".to_string()].into(), into)] hotkey: MaybeSignal<Vec<String>>,
+  #[prop(optional, into)] label: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastProviderContextValue {
+    toast_count,
+    viewport_ref,
+    is_close_paused,
+    queue,
+    max_visible,
+    ..
+  } = use_context().expect("ToastViewport must be used in a ToastProvider component");
+
+  let get_items = use_collection_context::<ToastItemData, AnyElement>();
+
+  let wrapper_ref = NodeRef::<AnyElement>::new();
+  let head_focus_proxy_ref = NodeRef::<AnyElement>::new();
+  let tail_focus_proxy_ref = NodeRef::<AnyElement>::new();
+
+  let hotkey = Signal::derive(move || hotkey.get());
+  let has_toasts = Signal::derive(move || toast_count.get() > 0);
+  let label = Signal::derive(move || {
+    label
+      .get()
+      .unwrap_or_else(|| format!("Notifications ({})", get_hotkey_label(&hotkey.get())))
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      viewport_ref.load(&node);
+    }
+  });
+
+  _ = use_event_listener(use_document(), keydown, move |ev: KeyboardEvent| {
+    let is_hotkey_pressed = hotkey.with_untracked(|hotkey| {
+      !hotkey.is_empty()
+        && hotkey.iter().all(|key| match key.as_str() {
+          "altKey" => ev.alt_key(),
+          "ctrlKey" => ev.ctrl_key(),
+          "metaKey" => ev.meta_key(),
+          "shiftKey" => ev.shift_key(),
+          key => ev.code() == key,
+        })
+    });
+
+    if is_hotkey_pressed {
+      if let Some(viewport) = node_ref.get_untracked() {
+        _ = viewport.focus();
+      }
+    }
+  });
+
+  let is_focus_inside = move |target: Option<web_sys::Node>| {
+    wrapper_ref
+      .get_untracked()
+      .map(|wrapper| wrapper.contains(target.as_ref()))
+      .unwrap_or(false)
+  };
+
+  let handle_pause = move || {
+    if has_toasts.get_untracked() && !is_close_paused.get_untracked() {
+      is_close_paused.set(true);
+    }
+  };
+
+  let handle_resume = move || {
+    if is_close_paused.get_untracked() {
+      is_close_paused.set(false);
+    }
+  };
+
+  // toasts shouldn't disappear while the user is away from the page
+  _ = use_event_listener(use_window(), blur, move |_| handle_pause());
+  _ = use_event_listener(use_window(), focus, move |_| handle_resume());
+
+  // toasts are ordered from most to least recent when tabbing
+  let get_sorted_tabbable_candidates = move |forwards: bool| {
+    let mut candidates = get_items
+      .get_untracked()
+      .into_iter()
+      .filter_map(|(node, _)| node.get_untracked())
+      .filter_map(|node| {
+        let element: &web_sys::Element = &node;
+        let toast = element.clone().dyn_into::<web_sys::HtmlElement>().ok()?;
+
+        let mut toast_candidates = vec![toast];
+        toast_candidates.extend(get_tabbable_candidates(element));
+
+        if !forwards {
+          toast_candidates.reverse();
+        }
+
+        Some(toast_candidates)
+      })
+      .collect::<Vec<_>>();
+
+    if forwards {
+      candidates.reverse();
+    }
+
+    candidates.into_iter().flatten().collect::<Vec<_>>()
+  };
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      attr:role="region"
+      attr:aria-label=label
+      attr:tabindex=-1
+      attr:style=move || (!has_toasts.get()).then_some("pointer-events: none;")
+      on:pointermove=move |_: PointerEvent| handle_pause()
+      on:pointerleave=move |_: PointerEvent| {
+        let active_element = document()
+          .active_element()
+          .map(|element| element.unchecked_into::<web_sys::Node>());
+
+        if !is_focus_inside(active_element) {
+          handle_resume();
+        }
+      }
+      on:focusin=move |_: FocusEvent| handle_pause()
+      on:focusout=move |ev: FocusEvent| {
+        let related_target = ev
+          .related_target()
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        if !is_focus_inside(related_target) {
+          handle_resume();
+        }
+      }
+      node_ref=wrapper_ref
+    >
+      <Show when=move || has_toasts.get()>
+        <ToastFocusProxy
+          on_focus_from_outside_viewport=move |_| {
+            focus_first(&get_sorted_tabbable_candidates(true), false);
+          }
+          node_ref=head_focus_proxy_ref
+        />
+      </Show>
+
+      <Primitive
+        {..attrs.get_value()}
+        attr:tabindex=-1
+        element=html::ol
+        on:keydown=move |ev: KeyboardEvent| {
+          let is_meta_key = ev.alt_key() || ev.ctrl_key() || ev.meta_key();
+
+          if ev.key() != "Tab" || is_meta_key {
+            return;
+          }
+
+          let active_element = document().active_element();
+          let is_tabbing_backwards = ev.shift_key();
+          let target_is_viewport = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .zip(node_ref.get_untracked())
+            .map(|(target, viewport)| {
+              let viewport: &web_sys::Element = &viewport;
+              &target == viewport
+            })
+            .unwrap_or(false);
+
+          // tabbing backwards from the viewport leaves the toasts
+          if target_is_viewport && is_tabbing_backwards {
+            if let Some(head_focus_proxy) = head_focus_proxy_ref.get_untracked() {
+              _ = head_focus_proxy.focus();
+            }
+
+            return;
+          }
+
+          let candidates = get_sorted_tabbable_candidates(!is_tabbing_backwards);
+          let index = candidates.iter().position(|candidate| {
+            let candidate: &web_sys::Element = candidate;
+            Some(candidate) == active_element.as_ref()
+          });
+
+          let next_candidates = match index {
+            Some(index) => &candidates[index + 1..],
+            None => &candidates[..],
+          };
+
+          if focus_first(next_candidates, false) {
+            ev.prevent_default();
+          } else {
+            // the focus proxies let the browser continue on with its tab order outside of the viewport
+            let focus_proxy = if is_tabbing_backwards {
+              head_focus_proxy_ref
+            } else {
+              tail_focus_proxy_ref
+            };
+
+            if let Some(focus_proxy) = focus_proxy.get_untracked() {
+              _ = focus_proxy.focus();
+            }
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+
+        <For
+          each=move || queue.with(|queue| queue.visible(max_visible.get()))
+          key=|(id, _)| *id
+          children=move |(id, content)| {
+            provide_context(ToastQueueItemContextValue { id });
+
+            content.run()
+          }
+        />
+      </Primitive>
+
+      <Show when=move || has_toasts.get()>
+        <ToastFocusProxy
+          on_focus_from_outside_viewport=move |_| {
+            focus_first(&get_sorted_tabbable_candidates(false), false);
+          }
+          node_ref=tail_focus_proxy_ref
+        />
+      </Show>
+    </Primitive>
+  }
+}
+
+#[component]
+fn ToastFocusProxy(
+  #[prop(into)] on_focus_from_outside_viewport: Callback<()>,
+
+  node_ref: NodeRef<AnyElement>,
+) -> impl IntoView {
+  let ToastProviderContextValue { viewport_ref, .. } =
+    use_context().expect("ToastFocusProxy must be used in a ToastProvider component");
+
+  view! {
+    <VisuallyHidden
+      attr:aria-hidden="true"
+      attr:tabindex=0
+      attr:style="position: fixed;"
+      on:focus=move |ev: FocusEvent| {
+        let previously_focused = ev
+          .related_target()
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        let is_focus_from_outside_viewport = !viewport_ref
+          .get_untracked()
+          .map(|viewport| viewport.contains(previously_focused.as_ref()))
+          .unwrap_or(false);
+
+        if is_focus_from_outside_viewport {
+          on_focus_from_outside_viewport.call(());
+        }
+      }
+      node_ref=node_ref
+    >
+      {None::<View>}
+    </VisuallyHidden>
+  }
+}
+
+#[component]
+pub fn ToastRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(default=true.into(), into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] r#type: MaybeSignal<ToastType>,
+  #[prop(optional, into)] duration: MaybeProp<u64>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pause: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_resume: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_start: Callback<ToastSwipeEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_move: Callback<ToastSwipeEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_cancel: Callback<ToastSwipeEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_end: Callback<ToastSwipeEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastProviderContextValue {
+    viewport_ref,
+    queue,
+    ..
+  } = use_context().expect("ToastRoot must be used in a ToastProvider component");
+  let queue_item = use_context::<ToastQueueItemContextValue>();
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let is_present = Signal::derive(move || force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  // queued toasts close when they're dismissed and leave the queue once they've unmounted, making
+  // room for the next one
+  if let Some(ToastQueueItemContextValue { id }) = queue_item {
+    Effect::new(move |_| {
+      if queue.with(|queue| queue.is_dismissed(id)) {
+        set_open.set(false);
+      }
+    });
+
+    Effect::new(move |was_present: Option<bool>| {
+      let is_present = presence.get();
+
+      // removing the entry disposes this toast, so it waits until the effect has finished
+      if was_present == Some(true) && !is_present {
+        set_timeout(
+          move || {
+            _ = queue.try_update(|queue| queue.remove(id));
+          },
+          Duration::ZERO,
+        );
+      }
+
+      is_present
+    });
+  }
+
+  let duration = Signal::derive(move || duration.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  // toasts are rendered into the viewport no matter where they're declared
+  view! {
+    <Show when=move || presence.get()>
+      {move || viewport_ref.get().map(|viewport| {
+        let viewport: &web_sys::Element = &viewport;
+
+        view! {
+          <Portal container=viewport.clone()>
+            <ToastImpl
+              {..attrs.get_value()}
+              open=open
+              r#type=r#type
+              duration=duration
+              on_close=move |_| set_open.set(false)
+              on_escape_key_down=on_escape_key_down
+              on_pause=on_pause
+              on_resume=on_resume
+              on_swipe_start=on_swipe_start
+              on_swipe_move=on_swipe_move
+              on_swipe_cancel=on_swipe_cancel
+              on_swipe_end=on_swipe_end
+              node_ref=node_ref
+              as_child=as_child
+            >
+              {children.with_value(|children| children())}
+            </ToastImpl>
+          </Portal>
+        }
+      })}
+    </Show>
+  }
+}
+
+#[component]
+fn ToastImpl(
+  #[prop(into)] open: Signal<bool>,
+  #[prop(into)] r#type: MaybeSignal<ToastType>,
+  #[prop(into)] duration: Signal<Option<u64>>,
+
+  #[prop(into)] on_close: Callback<()>,
+  #[prop(into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(into)] on_pause: Callback<()>,
+  #[prop(into)] on_resume: Callback<()>,
+  #[prop(into)] on_swipe_start: Callback<ToastSwipeEvent>,
+  #[prop(into)] on_swipe_move: Callback<ToastSwipeEvent>,
+  #[prop(into)] on_swipe_cancel: Callback<ToastSwipeEvent>,
+  #[prop(into)] on_swipe_end: Callback<ToastSwipeEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastProviderContextValue {
+    label,
+    duration: provider_duration,
+    swipe_direction,
+    swipe_threshold,
+    toast_count,
+    viewport_ref,
+    is_close_paused,
+    is_focused_toast_escape_key_down,
+    ..
+  } = use_context().expect("ToastImpl must be used in a ToastProvider component");
+
+  use_collection_item_ref::<AnyElement, ToastItemData>(node_ref, ToastItemData);
+
+  let duration = Signal::derive(move || duration.get().unwrap_or(provider_duration.get()));
+
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer_start_time = StoredValue::new(0.0);
+  let close_timer_remaining_time = StoredValue::new(duration.get_untracked());
+
+  let pointer_start = StoredValue::<Option<(f64, f64)>>::new(None);
+  let swipe_delta = StoredValue::<Option<(f64, f64)>>::new(None);
+  let (swipe_state, set_swipe_state) = create_signal::<Option<&'static str>>(None);
+  let (swipe_move, set_swipe_move) = create_signal::<Option<(f64, f64)>>(None);
+  let (swipe_end, set_swipe_end) = create_signal::<Option<(f64, f64)>>(None);
+
+  toast_count.update(|count| *count += 1);
+
+  on_cleanup(move || {
+    _ = toast_count.try_update(|count| *count = count.saturating_sub(1));
+
+    if let Some(Some(handle)) = close_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  let clear_close_timer = move || {
+    if let Some(handle) = close_timer.get_value() {
+      handle.clear();
+      close_timer.set_value(None);
+    }
+  };
+
+  let handle_close = move || {
+    let active_element = document()
+      .active_element()
+      .map(|element| element.unchecked_into::<web_sys::Node>());
+
+    // focus would otherwise be lost with the toast, so it's kept within the viewport
+    let is_focus_in_toast = node_ref
+      .get_untracked()
+      .map(|node| node.contains(active_element.as_ref()))
+      .unwrap_or(false);
+
+    if is_focus_in_toast {
+      if let Some(viewport) = viewport_ref.get_untracked() {
+        _ = viewport.focus();
+      }
+    }
+
+    on_close.call(());
+  };
+
+  // u64::MAX keeps the toast open until it's dismissed
+  let start_timer = move |duration: u64| {
+    if duration == u64::MAX {
+      return;
+    }
+
+    clear_close_timer();
+    close_timer_start_time.set_value(Date::now());
+
+    if let Ok(handle) = set_timeout_with_handle(handle_close, Duration::from_millis(duration)) {
+      close_timer.set_value(Some(handle));
+    }
+  };
+
+  Effect::new(move |was_paused: Option<bool>| {
+    let is_paused = is_close_paused.get();
+
+    match (was_paused, is_paused) {
+      (Some(false), true) => {
+        let elapsed_time = (Date::now() - close_timer_start_time.get_value()) as u64;
+
+        close_timer_remaining_time.update_value(|remaining_time| {
+          *remaining_time = remaining_time.saturating_sub(elapsed_time)
+        });
+        clear_close_timer();
+        on_pause.call(());
+      }
+      (Some(true), false) => {
+        start_timer(close_timer_remaining_time.get_value());
+        on_resume.call(());
+      }
+      _ => {}
+    }
+
+    is_paused
+  });
+
+  Effect::new(move |_| {
+    let duration = duration.get();
+
+    if open.get() && !is_close_paused.get_untracked() {
+      close_timer_remaining_time.set_value(duration);
+      start_timer(duration);
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("--leptix-toast-swipe-move-x", move || {
+        swipe_move.get().map(|(x, _)| format!("{x}px"))
+      })
+      .style("--leptix-toast-swipe-move-y", move || {
+        swipe_move.get().map(|(_, y)| format!("{y}px"))
+      })
+      .style("--leptix-toast-swipe-end-x", move || {
+        swipe_end.get().map(|(x, _)| format!("{x}px"))
+      })
+      .style("--leptix-toast-swipe-end-y", move || {
+        swipe_end.get().map(|(_, y)| format!("{y}px"))
+      });
+  });
+
+  let (announce_text, set_announce_text) = create_signal::<Option<String>>(None);
+
+  // the announcement is only rendered briefly, after the toast's content is in place
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    request_animation_frame(move || {
+      let text = get_announce_text_content(&node).join(" ");
+
+      if !text.is_empty() {
+        _ = set_announce_text.try_set(Some(text));
+      }
+    });
+
+    set_timeout(
+      move || {
+        _ = set_announce_text.try_set(None);
+      },
+      Duration::from_millis(1000),
+    );
+  });
+
+  provide_context(ToastContextValue {
+    on_close: Callback::new(move |_| handle_close()),
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || announce_text.get().is_some()>
+      <Portal>
+        <VisuallyHidden
+          attr:role="status"
+          attr:aria-live=move || match r#type.get() {
+            ToastType::Foreground => "assertive",
+            ToastType::Background => "polite",
+          }
+          attr:aria-atomic="true"
+        >
+          {move || format!("{} {}", label.get(), announce_text.get().unwrap_or_default())}
+        </VisuallyHidden>
+      </Portal>
+    </Show>
+
+    <DismissableLayer
+      as_child=true
+      on_escape_key_down=move |ev: KeyboardEvent| {
+        on_escape_key_down.call(ev);
+
+        // escape pressed on the toast itself already closed it
+        if !is_focused_toast_escape_key_down.get_value() {
+          handle_close();
+        }
+
+        is_focused_toast_escape_key_down.set_value(false);
+      }
+    >
+      <Primitive
+        {..attrs.get_value()}
+        attr:role="status"
+        attr:aria-live="off"
+        attr:aria-atomic="true"
+        attr:tabindex=0
+        attr:data-state=move || if open.get() { "open" } else { "closed" }
+        attr:data-swipe=swipe_state
+        attr:data-swipe-direction=move || swipe_direction.get().to_string()
+        attr:style="user-select: none; touch-action: none;"
+        element=html::li
+        on:keydown=move |ev: KeyboardEvent| {
+          if ev.key() != "Escape" {
+            return;
+          }
+
+          on_escape_key_down.call(ev.clone());
+
+          if !ev.default_prevented() {
+            is_focused_toast_escape_key_down.set_value(true);
+            handle_close();
+          }
+        }
+        on:pointerdown=move |ev: PointerEvent| {
+          if ev.button() != 0 {
+            return;
+          }
+
+          pointer_start.set_value(Some((ev.client_x() as f64, ev.client_y() as f64)));
+        }
+        on:pointermove=move |ev: PointerEvent| {
+          let Some((start_x, start_y)) = pointer_start.get_value() else {
+            return;
+          };
+
+          let x = ev.client_x() as f64 - start_x;
+          let y = ev.client_y() as f64 - start_y;
+
+          let direction = swipe_direction.get_untracked();
+          let is_horizontal_swipe =
+            matches!(direction, ToastSwipeDirection::Left | ToastSwipeDirection::Right);
+
+          // swiping is limited to the swipe direction
+          let clamp = |value: f64| match direction {
+            ToastSwipeDirection::Left | ToastSwipeDirection::Up => value.min(0.0),
+            ToastSwipeDirection::Right | ToastSwipeDirection::Down => value.max(0.0),
+          };
+
+          let delta = (
+            if is_horizontal_swipe { clamp(x) } else { 0.0 },
+            if is_horizontal_swipe { 0.0 } else { clamp(y) },
+          );
+
+          let move_start_buffer = if ev.pointer_type() == "touch" { 10.0 } else { 2.0 };
+
+          let swipe_event = ToastSwipeEvent {
+            original_event: ev.clone(),
+            delta,
+          };
+
+          if swipe_delta.get_value().is_some() {
+            swipe_delta.set_value(Some(delta));
+            on_swipe_move.call(swipe_event);
+
+            if !ev.default_prevented() {
+              set_swipe_state.set(Some("move"));
+              set_swipe_move.set(Some(delta));
+            }
+          } else if is_delta_in_direction(delta, direction, move_start_buffer) {
+            swipe_delta.set_value(Some(delta));
+            on_swipe_start.call(swipe_event);
+
+            if !ev.default_prevented() {
+              set_swipe_state.set(Some("start"));
+            }
+
+            if let Some(target) = ev
+              .target()
+              .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            {
+              _ = target.set_pointer_capture(ev.pointer_id());
+            }
+          } else if x.abs() > move_start_buffer || y.abs() > move_start_buffer {
+            // moving in the wrong direction first means the user is scrolling rather than swiping
+            pointer_start.set_value(None);
+          }
+        }
+        on:pointerup=move |ev: PointerEvent| {
+          let delta = swipe_delta.get_value();
+
+          if let Some(target) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          {
+            if target.has_pointer_capture(ev.pointer_id()) {
+              _ = target.release_pointer_capture(ev.pointer_id());
+            }
+          }
+
+          swipe_delta.set_value(None);
+          pointer_start.set_value(None);
+
+          let Some(delta) = delta else {
+            return;
+          };
+
+          let swipe_event = ToastSwipeEvent {
+            original_event: ev.clone(),
+            delta,
+          };
+
+          if is_delta_in_direction(delta, swipe_direction.get_untracked(), swipe_threshold.get_untracked()) {
+            on_swipe_end.call(swipe_event);
+
+            if !ev.default_prevented() {
+              set_swipe_state.set(Some("end"));
+              set_swipe_move.set(None);
+              set_swipe_end.set(Some(delta));
+              handle_close();
+            }
+          } else {
+            on_swipe_cancel.call(swipe_event);
+
+            if !ev.default_prevented() {
+              set_swipe_state.set(Some("cancel"));
+              set_swipe_move.set(None);
+              set_swipe_end.set(None);
+            }
+          }
+
+          // the click that follows a swipe shouldn't activate anything in the toast
+          if let Some(toast) = ev.current_target() {
+            let prevent_click = wasm_bindgen::closure::Closure::once_into_js(|ev: MouseEvent| {
+              ev.prevent_default();
+            });
+
+            let options = web_sys::AddEventListenerOptions::new();
+            options.set_once(true);
+
+            _ = toast.add_event_listener_with_callback_and_add_event_listener_options(
+              "click",
+              prevent_click.unchecked_ref(),
+              &options,
+            );
+          }
+        }
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </DismissableLayer>
+  }
+}
+
+#[component]
+pub fn ToastTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ToastDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      {..attrs}
+      element=html::div
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ToastAction(
+  #[prop(into)] alt_text: MaybeSignal<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  // actions can't be reached from the announcement, so they're described by their alternative text
+  view! {
+    <ToastClose
+      {..attrs}
+      attr:data-leptix-toast-announce-exclude=""
+      attr:data-leptix-toast-announce-alt=alt_text
+      on_click=on_click
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </ToastClose>
+  }
+}
+
+#[component]
+pub fn ToastClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastContextValue { on_close } =
+    use_context().expect("ToastClose must be used in a ToastRoot component");
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:type="button"
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        if !ev.default_prevented() {
+          on_close.call(());
+        }
+      }
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[test]
+fn swipes_past_the_threshold_in_the_swipe_direction() {
+  assert!(is_delta_in_direction(
+    (60.0, 0.0),
+    ToastSwipeDirection::Right,
+    50.0
+  ));
+  assert!(is_delta_in_direction(
+    (0.0, -60.0),
+    ToastSwipeDirection::Up,
+    50.0
+  ));
+  assert!(!is_delta_in_direction(
+    (40.0, 0.0),
+    ToastSwipeDirection::Right,
+    50.0
+  ));
+  assert!(!is_delta_in_direction(
+    (60.0, 70.0),
+    ToastSwipeDirection::Right,
+    50.0
+  ));
+}
+
+#[test]
+fn hotkey_labels_drop_key_code_prefixes() {
+  assert_eq!(get_hotkey_label(&["F8".to_string()]), "F8");
+  assert_eq!(
+    get_hotkey_label(&["altKey".to_string(), "KeyT".to_string()]),
+    "alt+T"
+  );
+}
+
+#[test]
+fn queued_toasts_wait_for_room_in_the_viewport() {
+  let mut queue = ToastQueue::default();
+  let first = queue.push("first");
+  let second = queue.push("second");
+  queue.push("third");
+
+  let visible = |queue: &ToastQueue<&'static str>| {
+    queue
+      .visible(2)
+      .into_iter()
+      .map(|(_, content)| content)
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(visible(&queue), vec!["first", "second"]);
+
+  // dismissed toasts hold their place while they close
+  queue.dismiss(first, 2);
+  assert!(queue.is_dismissed(first));
+  assert_eq!(visible(&queue), vec!["first", "second"]);
+
+  queue.remove(first);
+  assert_eq!(visible(&queue), vec!["second", "third"]);
+  assert!(!queue.is_dismissed(second));
+}
+
+#[test]
+fn dismissing_a_queued_toast_drops_it() {
+  let mut queue = ToastQueue::default();
+  queue.push("first");
+  let second = queue.push("second");
+  queue.push("third");
+
+  queue.dismiss(second, 1);
+
+  assert!(!queue.is_dismissed(second));
+  assert_eq!(queue.visible(usize::MAX).len(), 2);
+  assert_eq!(queue.visible(usize::MAX)[1].1, "third");
+}
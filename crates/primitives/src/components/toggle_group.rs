@@ -6,6 +6,7 @@ use crate::{
     roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
     toggle::ToggleRoot,
   },
+  direction::use_local_direction,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
     Attributes,
@@ -49,7 +50,7 @@ pub fn ToggleGroupRoot(
   #[prop(default=true.into(), into)] roving_focus: MaybeSignal<bool>,
   #[prop(default=true.into(), into)] should_loop: MaybeSignal<bool>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
   #[prop(attrs)] attrs: Attributes,
@@ -57,6 +58,8 @@ pub fn ToggleGroupRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   match kind {
     ToggleGroupKind::Single {
       value,
@@ -123,7 +126,7 @@ fn ToggleGroupSingleImpl(
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
   orientation: MaybeSignal<Orientation>,
-  direction: MaybeSignal<Direction>,
+  direction: Signal<Direction>,
   #[prop(optional, into)] value: MaybeProp<String>,
   #[prop(optional, into)] default_value: MaybeProp<String>,
 
@@ -174,7 +177,7 @@ fn ToggleGroupMultipleImpl(
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
   orientation: MaybeSignal<Orientation>,
-  direction: MaybeSignal<Direction>,
+  direction: Signal<Direction>,
   #[prop(optional, into)] value: MaybeProp<Vec<String>>,
   #[prop(optional, into)] default_value: MaybeProp<Vec<String>>,
 
@@ -248,7 +251,7 @@ fn ToggleGroup(
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
   orientation: MaybeSignal<Orientation>,
-  direction: MaybeSignal<Direction>,
+  direction: Signal<Direction>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
   #[prop(attrs)] attrs: Attributes,
@@ -3,6 +3,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{HtmlAnchorElement, KeyboardEvent};
 
 use crate::{
+  direction::use_local_direction,
   primitive::Primitive,
   roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
   separator::SeparatorRoot,
@@ -22,7 +23,7 @@ struct ToolbarContextValue {
 #[component]
 pub fn ToolbarRoot(
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
   #[prop(default=true.into(), into)] should_loop: MaybeSignal<bool>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
@@ -31,6 +32,8 @@ pub fn ToolbarRoot(
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
+  let direction = use_local_direction(direction);
+
   provide_context(ToolbarContextValue {
     orientation: Signal::derive(move || orientation.get()),
     direction: Signal::derive(move || direction.get()),
@@ -165,7 +168,7 @@ pub fn ToolbarToggleGroup(
 
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
-  #[prop(optional, into)] direction: MaybeSignal<Direction>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
 
   #[prop(optional)] node_ref: NodeRef<AnyElement>,
   #[prop(attrs)] attrs: Attributes,
@@ -184,7 +187,7 @@ pub fn ToolbarToggleGroup(
       kind=kind
       disabled=Signal::derive(move || disabled.get())
       orientation=Signal::derive(move || orientation.get())
-      direction=Signal::derive(move || direction.get())
+      direction=Signal::derive(move || direction.get().unwrap_or_else(|| context.direction.get()))
       roving_focus=false
       node_ref=node_ref
       as_child=as_child
@@ -0,0 +1,670 @@
+use std::time::Duration;
+
+use leptos::{
+  ev::{pointerleave, pointermove, pointerup, scroll, Custom},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, use_window,
+  UseEventListenerOptions,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CustomEvent, CustomEventInit, Event, FocusEvent, KeyboardEvent, PointerEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  popper::{PopperAnchor, PopperArrow, PopperContent, PopperRoot},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    create_id::create_id,
+    polygon::{
+      get_exit_side_from_rect, get_hull, get_padded_exit_points, get_points_from_rect,
+      is_point_in_polygon, Point, Polygon,
+    },
+    Attributes,
+  },
+  visually_hidden::VisuallyHidden,
+  Align, Side, Sticky,
+};
+
+const TOOLTIP_OPEN: &str = "tooltip.open";
+
+// the open event reaches every tooltip, including the one that just opened and sent it
+fn is_other_tooltip(opened_id: Option<&str>, content_id: &str) -> bool {
+  opened_id != Some(content_id)
+}
+
+#[derive(Clone)]
+struct TooltipProviderContextValue {
+  is_open_delayed: ReadSignal<bool>,
+  delay_duration: Signal<u64>,
+  is_pointer_in_transit: StoredValue<bool>,
+  disable_hoverable_content: Signal<bool>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+}
+
+#[component]
+pub fn TooltipProvider(
+  #[prop(default=700.into(), into)] delay_duration: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] skip_delay_duration: MaybeSignal<u64>,
+  #[prop(optional, into)] disable_hoverable_content: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (is_open_delayed, set_is_open_delayed) = create_signal(true);
+  let skip_delay_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let clear_skip_delay_timer = move || {
+    if let Some(handle) = skip_delay_timer.get_value() {
+      handle.clear();
+      skip_delay_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = skip_delay_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  provide_context(TooltipProviderContextValue {
+    is_open_delayed,
+    delay_duration: Signal::derive(move || delay_duration.get()),
+    is_pointer_in_transit: StoredValue::new(false),
+    disable_hoverable_content: Signal::derive(move || disable_hoverable_content.get()),
+    on_open: Callback::new(move |_| {
+      clear_skip_delay_timer();
+      set_is_open_delayed.set(false);
+    }),
+    on_close: Callback::new(move |_| {
+      clear_skip_delay_timer();
+
+      // tooltips opened shortly after another one closed skip their delay, so moving between
+      // neighbouring triggers (e.g. toolbar buttons) feels instant
+      let Ok(handle) = set_timeout_with_handle(
+        move || set_is_open_delayed.set(true),
+        Duration::from_millis(skip_delay_duration.get_untracked()),
+      ) else {
+        return;
+      };
+
+      skip_delay_timer.set_value(Some(handle));
+    }),
+  });
+
+  children()
+}
+
+#[derive(Clone)]
+struct TooltipContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  state_attribute: Signal<&'static str>,
+  disable_hoverable_content: Signal<bool>,
+  on_trigger_enter: Callback<()>,
+  on_trigger_leave: Callback<()>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+}
+
+#[derive(Clone)]
+struct TooltipPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn TooltipRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] delay_duration: MaybeProp<u64>,
+  #[prop(optional, into)] disable_hoverable_content: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let provider = use_context::<TooltipProviderContextValue>()
+    .expect("TooltipRoot must be used in a TooltipProvider component");
+
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let was_open_delayed = StoredValue::new(false);
+  let content_id = create_id();
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: Callback::new(move |open| {
+      if open {
+        provider.on_open.call(());
+
+        // only one tooltip can be open at a time
+        let init = CustomEventInit::new();
+        init.set_bubbles(false);
+        init.set_detail(&JsValue::from_str(&content_id.get_untracked()));
+
+        if let Ok(ev) = CustomEvent::new_with_event_init_dict(TOOLTIP_OPEN, &init) {
+          _ = document().dispatch_event(&ev);
+        }
+      } else {
+        provider.on_close.call(());
+      }
+
+      on_open_change.call(open);
+    }),
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+
+  let delay_duration = Signal::derive(move || {
+    delay_duration
+      .get()
+      .unwrap_or(provider.delay_duration.get())
+  });
+  let disable_hoverable_content = Signal::derive(move || {
+    disable_hoverable_content
+      .get()
+      .unwrap_or(provider.disable_hoverable_content.get())
+  });
+
+  let clear_open_timer = move || {
+    if let Some(handle) = open_timer.get_value() {
+      handle.clear();
+      open_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = open_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  let handle_open = move || {
+    clear_open_timer();
+    was_open_delayed.set_value(false);
+    set_open.set(true);
+  };
+
+  let handle_close = move || {
+    clear_open_timer();
+    set_open.set(false);
+  };
+
+  let handle_delayed_open = move || {
+    clear_open_timer();
+
+    let Ok(handle) = set_timeout_with_handle(
+      move || {
+        open_timer.set_value(None);
+        was_open_delayed.set_value(true);
+        set_open.set(true);
+      },
+      Duration::from_millis(delay_duration.get_untracked()),
+    ) else {
+      return;
+    };
+
+    open_timer.set_value(Some(handle));
+  };
+
+  provide_context(TooltipContextValue {
+    trigger_ref: NodeRef::new(),
+    content_id,
+    open,
+    state_attribute: Signal::derive(move || {
+      if open.get() {
+        if was_open_delayed.get_value() {
+          "delayed-open"
+        } else {
+          "instant-open"
+        }
+      } else {
+        "closed"
+      }
+    }),
+    disable_hoverable_content,
+    on_trigger_enter: Callback::new(move |_| {
+      if provider.is_open_delayed.get_untracked() {
+        handle_delayed_open();
+      } else {
+        handle_open();
+      }
+    }),
+    on_trigger_leave: Callback::new(move |_| {
+      if disable_hoverable_content.get_untracked() {
+        handle_close();
+      } else {
+        // hoverable content closes itself once the pointer leaves the grace area
+        clear_open_timer();
+      }
+    }),
+    on_open: Callback::new(move |_| handle_open()),
+    on_close: Callback::new(move |_| handle_close()),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn TooltipTrigger(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TooltipContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    state_attribute,
+    on_trigger_enter,
+    on_trigger_leave,
+    on_open,
+    on_close,
+    ..
+  } = use_context().expect("TooltipTrigger must be used in a TooltipRoot component");
+
+  let TooltipProviderContextValue {
+    is_pointer_in_transit,
+    ..
+  } = use_context().expect("TooltipTrigger must be used in a TooltipProvider component");
+
+  let is_pointer_down = StoredValue::new(false);
+  let has_pointer_move_opened = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerup, move |_| {
+    is_pointer_down.set_value(false);
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true>
+      <Primitive
+        {..attrs.get_value()}
+        attr:aria-describedby=move || open.get().then(|| content_id.get())
+        attr:data-state=state_attribute
+        element=html::button
+        on:pointermove=move |ev: PointerEvent| {
+          if ev.pointer_type() == "touch" {
+            return;
+          }
+
+          if !has_pointer_move_opened.get_value() && !is_pointer_in_transit.get_value() {
+            on_trigger_enter.call(());
+            has_pointer_move_opened.set_value(true);
+          }
+        }
+        on:pointerleave=move |_: PointerEvent| {
+          on_trigger_leave.call(());
+          has_pointer_move_opened.set_value(false);
+        }
+        on:pointerdown=move |_: PointerEvent| {
+          is_pointer_down.set_value(true);
+        }
+        on:focus=move |_: FocusEvent| {
+          if !is_pointer_down.get_value() {
+            on_open.call(());
+          }
+        }
+        on:blur=move |_: FocusEvent| on_close.call(())
+        on:click=move |_| on_close.call(())
+        node_ref=node_ref
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn TooltipPortal(
+  #[prop(optional, into)] container: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(TooltipPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  view! {
+    <Portal container=container>
+      {children()}
+    </Portal>
+  }
+}
+
+#[component]
+pub fn TooltipContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] aria_label: MaybeProp<String>,
+  #[prop(default=Side::Top.into(), into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: MaybeProp<web_sys::Element>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<CustomEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TooltipContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    state_attribute,
+    disable_hoverable_content,
+    on_close,
+    ..
+  } = use_context().expect("TooltipContent must be used in a TooltipRoot component");
+
+  let TooltipProviderContextValue {
+    is_pointer_in_transit,
+    ..
+  } = use_context().expect("TooltipContent must be used in a TooltipProvider component");
+
+  let portal_force_mount = use_context::<TooltipPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || open.get() || force_mount.get() || portal_force_mount.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let (pointer_grace_area, set_pointer_grace_area) = create_signal::<Option<Polygon>>(None);
+
+  let remove_grace_area = move || {
+    set_pointer_grace_area.set(None);
+    is_pointer_in_transit.set_value(false);
+  };
+
+  let create_grace_area = move |ev: &PointerEvent, hover_target: &web_sys::Element| {
+    let Some(current_target) = ev
+      .current_target()
+      .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+    else {
+      return;
+    };
+
+    let exit_point = Point {
+      x: ev.client_x() as f64,
+      y: ev.client_y() as f64,
+    };
+    let exit_side = get_exit_side_from_rect(exit_point, &current_target.get_bounding_client_rect());
+
+    let points = get_padded_exit_points(exit_point, exit_side, 5.0)
+      .into_iter()
+      .chain(get_points_from_rect(
+        &hover_target.get_bounding_client_rect(),
+      ))
+      .collect::<Polygon>();
+
+    set_pointer_grace_area.set(Some(get_hull(&points)));
+    is_pointer_in_transit.set_value(true);
+  };
+
+  _ = use_event_listener(trigger_ref, pointerleave, move |ev: PointerEvent| {
+    if disable_hoverable_content.get_untracked() {
+      return;
+    }
+
+    if let Some(content) = node_ref.get_untracked() {
+      create_grace_area(&ev, &content);
+    }
+  });
+
+  _ = use_event_listener(node_ref, pointerleave, move |ev: PointerEvent| {
+    if disable_hoverable_content.get_untracked() {
+      return;
+    }
+
+    if let Some(trigger) = trigger_ref.get_untracked() {
+      create_grace_area(&ev, &trigger);
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointermove, move |ev: PointerEvent| {
+    let Some(grace_area) = pointer_grace_area.get_untracked() else {
+      return;
+    };
+
+    let (Some(trigger), Some(content)) = (trigger_ref.get_untracked(), node_ref.get_untracked())
+    else {
+      return;
+    };
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+    let has_entered_target = target
+      .map(|target| trigger.contains(Some(&target)) || content.contains(Some(&target)))
+      .unwrap_or(false);
+
+    let pointer_position = Point {
+      x: ev.client_x() as f64,
+      y: ev.client_y() as f64,
+    };
+
+    if has_entered_target {
+      remove_grace_area();
+    } else if !is_point_in_polygon(pointer_position, &grace_area) {
+      remove_grace_area();
+      on_close.call(());
+    }
+  });
+
+  _ = use_event_listener(
+    use_document(),
+    Custom::<CustomEvent>::new(TOOLTIP_OPEN),
+    move |ev: CustomEvent| {
+      let opened_id = ev.detail().as_string();
+
+      if open.get_untracked() && is_other_tooltip(opened_id.as_deref(), &content_id.get_untracked())
+      {
+        on_close.call(());
+      }
+    },
+  );
+
+  // scrolling anything that contains the trigger would leave the tooltip floating on its own
+  _ = use_event_listener_with_options(
+    use_window(),
+    scroll,
+    move |ev: Event| {
+      let Some(trigger) = trigger_ref.get_untracked() else {
+        return;
+      };
+
+      let target = ev
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+      if open.get_untracked()
+        && target
+          .map(|target| target.contains(Some(&trigger)))
+          .unwrap_or(false)
+      {
+        on_close.call(());
+      }
+    },
+    UseEventListenerOptions::default()
+      .capture(true)
+      .passive(true),
+  );
+
+  on_cleanup(move || {
+    _ = is_pointer_in_transit.try_set_value(false);
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-tooltip-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-tooltip-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-tooltip-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-tooltip-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-tooltip-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let collision_boundary = Signal::derive(move || collision_boundary.get());
+  let aria_label = Signal::derive(move || aria_label.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <PopperContent
+        as_child=true
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        node_ref=node_ref
+      >
+        <DismissableLayer
+          as_child=true
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=|ev: CustomEvent| ev.prevent_default()
+          on_dismiss=move |_| on_close.call(())
+        >
+          <Primitive
+            {..attrs.get_value()}
+            attr:data-state=state_attribute
+            element=html::div
+            node_ref=node_ref
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+            <TooltipVisuallyHiddenContent content_id=content_id>
+              {move || match aria_label.get() {
+                Some(aria_label) => aria_label.into_view(),
+                None => children.with_value(|children| children()).into_view(),
+              }}
+            </TooltipVisuallyHiddenContent>
+          </Primitive>
+        </DismissableLayer>
+      </PopperContent>
+    </Show>
+  }
+}
+
+#[derive(Clone)]
+struct TooltipVisuallyHiddenContentContextValue;
+
+#[component]
+fn TooltipVisuallyHiddenContent(content_id: Signal<String>, children: ChildrenFn) -> impl IntoView {
+  provide_context(TooltipVisuallyHiddenContentContextValue);
+
+  // announced through the trigger's aria-describedby while staying out of sight
+  view! {
+    <VisuallyHidden attr:id=content_id attr:role="tooltip">
+      {children()}
+    </VisuallyHidden>
+  }
+}
+
+#[component]
+pub fn TooltipArrow(
+  #[prop(default=10.0.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  // the hidden copy of the content shouldn't render a second arrow for the popper to measure
+  if use_context::<TooltipVisuallyHiddenContentContextValue>().is_some() {
+    return ().into_view();
+  }
+
+  match children {
+    Some(children) => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      >
+        {children()}
+      </PopperArrow>
+    }
+    .into_view(),
+    None => view! {
+      <PopperArrow
+        width=width
+        height=height
+        node_ref=node_ref
+        attrs=attrs
+        as_child=as_child
+      />
+    }
+    .into_view(),
+  }
+}
+
+#[test]
+fn opening_a_tooltip_only_closes_the_others() {
+  assert!(!is_other_tooltip(Some("tooltip-1"), "tooltip-1"));
+  assert!(is_other_tooltip(Some("tooltip-2"), "tooltip-1"));
+  assert!(is_other_tooltip(None, "tooltip-1"));
+}
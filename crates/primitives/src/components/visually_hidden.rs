@@ -0,0 +1,36 @@
+use leptos::{html::AnyElement, *};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+pub(crate) const VISUALLY_HIDDEN_STYLES: &str = "position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;";
+
+#[component]
+pub fn VisuallyHidden(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  // a consumer's own styles are appended so they can adjust the hidden styles, e.g. with
+  // `position: fixed`
+  let (style, attrs): (Vec<_>, Vec<_>) = attrs.into_iter().partition(|(name, _)| *name == "style");
+  let style = style.into_iter().next().map(|(_, style)| style);
+
+  view! {
+    <Primitive
+      {..attrs}
+      attr:style=move || {
+        match style.as_ref().and_then(|style| style.as_nameless_value_string()) {
+          Some(style) => format!("{VISUALLY_HIDDEN_STYLES} {style}"),
+          None => VISUALLY_HIDDEN_STYLES.to_string(),
+        }
+      }
+      element=html::span
+      node_ref=node_ref
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
@@ -2,6 +2,7 @@ pub(crate) mod create_controllable_signal;
 pub(crate) mod create_id;
 pub(crate) mod create_previous;
 pub(crate) mod create_state_machine;
+pub(crate) mod polygon;
 
 pub(crate) fn linear_scale(
   (input_start, input_end): (f64, f64),
@@ -17,5 +18,90 @@ pub(crate) fn linear_scale(
   }
 }
 
+pub(crate) fn get_next_match(
+  values: &[String],
+  search: &str,
+  current_match: Option<&str>,
+) -> Option<String> {
+  let first_char = search.chars().next()?;
+
+  // typing the same character repeatedly cycles through the values starting with it
+  let is_repeated = search.chars().count() > 1 && search.chars().all(|char| char == first_char);
+  let normalized_search = if is_repeated {
+    first_char.to_string()
+  } else {
+    search.to_string()
+  }
+  .to_lowercase();
+
+  let current_match_index = current_match
+    .and_then(|current_match| values.iter().position(|value| value == current_match))
+    .unwrap_or(0);
+
+  let exclude_current_match = normalized_search.chars().count() == 1;
+
+  let next_match = values[current_match_index..]
+    .iter()
+    .chain(values[..current_match_index].iter())
+    .filter(|value| !exclude_current_match || Some(value.as_str()) != current_match)
+    .find(|value| value.to_lowercase().starts_with(&normalized_search))?;
+
+  (Some(next_match.as_str()) != current_match).then(|| next_match.clone())
+}
+
+pub(crate) fn get_decimal_count(value: f64) -> usize {
+  value.to_string().split('.').nth(1).unwrap_or("").len()
+}
+
+pub(crate) fn round_value(value: f64, decimal_count: u32) -> f64 {
+  let rounder = 10_f64.powi(decimal_count as i32);
+  (value * rounder).round() / rounder
+}
+
+// steps count from `min` when it's bounded and from zero otherwise, rounding to the step's precision
+// keeps floating point noise out of the values
+pub(crate) fn snap_value_to_step(value: f64, min: f64, max: f64, step: f64) -> f64 {
+  let base = if min.is_finite() { min } else { 0.0 };
+  let decimal_count = get_decimal_count(step);
+  let snap_to_step = round_value(
+    ((value - base) / step).round() * step + base,
+    decimal_count as u32,
+  );
+
+  snap_to_step.clamp(min, max)
+}
+
 pub(crate) type AttributePair = (&'static str, leptos::Attribute);
 pub(crate) type Attributes = Vec<AttributePair>;
+
+#[test]
+fn next_match_finds_values_by_prefix() {
+  let values = ["Apple", "Banana", "Blueberry", "Cherry"].map(String::from);
+
+  assert_eq!(
+    get_next_match(&values, "b", None),
+    Some(String::from("Banana"))
+  );
+  assert_eq!(
+    get_next_match(&values, "BLU", None),
+    Some(String::from("Blueberry"))
+  );
+  assert_eq!(get_next_match(&values, "d", None), None);
+  assert_eq!(get_next_match(&values, "", None), None);
+}
+
+#[test]
+fn next_match_cycles_on_repeated_characters() {
+  let values = ["Apple", "Banana", "Blueberry", "Cherry"].map(String::from);
+
+  assert_eq!(
+    get_next_match(&values, "b", Some("Banana")),
+    Some(String::from("Blueberry"))
+  );
+  assert_eq!(
+    get_next_match(&values, "bb", Some("Blueberry")),
+    Some(String::from("Banana"))
+  );
+  // a longer search keeps the current match rather than moving off it
+  assert_eq!(get_next_match(&values, "ban", Some("Banana")), None);
+}
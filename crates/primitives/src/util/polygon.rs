@@ -0,0 +1,174 @@
+use web_sys::DomRect;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Point {
+  pub(crate) x: f64,
+  pub(crate) y: f64,
+}
+
+pub(crate) type Polygon = Vec<Point>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum ExitSide {
+  Top,
+  Right,
+  Bottom,
+  Left,
+}
+
+pub(crate) fn get_exit_side_from_rect(point: Point, rect: &DomRect) -> ExitSide {
+  let top = (rect.top() - point.y).abs();
+  let bottom = (rect.bottom() - point.y).abs();
+  let right = (rect.right() - point.x).abs();
+  let left = (rect.left() - point.x).abs();
+
+  let min = top.min(bottom).min(right).min(left);
+
+  if min == left {
+    ExitSide::Left
+  } else if min == right {
+    ExitSide::Right
+  } else if min == top {
+    ExitSide::Top
+  } else {
+    ExitSide::Bottom
+  }
+}
+
+pub(crate) fn get_padded_exit_points(
+  exit_point: Point,
+  exit_side: ExitSide,
+  padding: f64,
+) -> Polygon {
+  let Point { x, y } = exit_point;
+
+  match exit_side {
+    ExitSide::Top => vec![
+      Point {
+        x: x - padding,
+        y: y + padding,
+      },
+      Point {
+        x: x + padding,
+        y: y + padding,
+      },
+    ],
+    ExitSide::Bottom => vec![
+      Point {
+        x: x - padding,
+        y: y - padding,
+      },
+      Point {
+        x: x + padding,
+        y: y - padding,
+      },
+    ],
+    ExitSide::Left => vec![
+      Point {
+        x: x + padding,
+        y: y - padding,
+      },
+      Point {
+        x: x + padding,
+        y: y + padding,
+      },
+    ],
+    ExitSide::Right => vec![
+      Point {
+        x: x - padding,
+        y: y - padding,
+      },
+      Point {
+        x: x - padding,
+        y: y + padding,
+      },
+    ],
+  }
+}
+
+pub(crate) fn get_points_from_rect(rect: &DomRect) -> Polygon {
+  vec![
+    Point {
+      x: rect.left(),
+      y: rect.top(),
+    },
+    Point {
+      x: rect.right(),
+      y: rect.top(),
+    },
+    Point {
+      x: rect.right(),
+      y: rect.bottom(),
+    },
+    Point {
+      x: rect.left(),
+      y: rect.bottom(),
+    },
+  ]
+}
+
+pub(crate) fn is_point_in_polygon(point: Point, polygon: &[Point]) -> bool {
+  let mut inside = false;
+  let mut j = polygon.len().wrapping_sub(1);
+
+  for i in 0..polygon.len() {
+    let (a, b) = (polygon[i], polygon[j]);
+
+    let intersect = (a.y > point.y) != (b.y > point.y)
+      && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x;
+
+    if intersect {
+      inside = !inside;
+    }
+
+    j = i;
+  }
+
+  inside
+}
+
+// andrew's monotone chain algorithm
+pub(crate) fn get_hull(points: &[Point]) -> Polygon {
+  let mut points = points.to_vec();
+
+  points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+
+  if points.len() <= 1 {
+    return points;
+  }
+
+  let cross =
+    |o: &Point, a: &Point, b: &Point| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+
+  let mut upper: Polygon = vec![];
+
+  for point in &points {
+    while upper.len() >= 2 && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], point) >= 0.0
+    {
+      upper.pop();
+    }
+
+    upper.push(*point);
+  }
+
+  upper.pop();
+
+  let mut lower: Polygon = vec![];
+
+  for point in points.iter().rev() {
+    while lower.len() >= 2 && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], point) >= 0.0
+    {
+      lower.pop();
+    }
+
+    lower.push(*point);
+  }
+
+  lower.pop();
+
+  if upper.len() == 1 && lower.len() == 1 && upper[0] == lower[0] {
+    upper
+  } else {
+    upper.into_iter().chain(lower).collect()
+  }
+}